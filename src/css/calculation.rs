@@ -0,0 +1,423 @@
+//! Css calculations, like `calc(100% - 2em)` or `min(1px, 2vw)`.
+
+use super::Value;
+use crate::error::Error;
+use crate::output::{Format, Formatted};
+use crate::value::{Number, Operator, Quotes, Unit, UnitSet};
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Write};
+
+/// The css functions that are handled as calculations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CalcName {
+    Calc,
+    Min,
+    Max,
+    Clamp,
+}
+
+impl Display for CalcName {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.write_str(match *self {
+            CalcName::Calc => "calc",
+            CalcName::Min => "min",
+            CalcName::Max => "max",
+            CalcName::Clamp => "clamp",
+        })
+    }
+}
+
+/// A calculation that could not be simplified to a single number.
+///
+/// Values that are statically known are simplified when the
+/// calculation is created, so e.g. `calc(10px + 5px)` is the number
+/// `15px`, while `calc(100% - 2em)` is kept as a calculation.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Calculation {
+    name: CalcName,
+    args: Vec<CalcValue>,
+}
+
+/// A part of a calculation.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CalcValue {
+    Number(Number, UnitSet),
+    /// An unquoted string, like the result of an interpolation or a
+    /// `var(...)` reference, that is kept as is.
+    Raw(String),
+    Calculation(Calculation),
+    /// An operation that could not be simplified.
+    /// The operator is `+`, `-`, `*` or `/`.
+    Operation(Box<CalcValue>, Operator, Box<CalcValue>),
+}
+
+impl Calculation {
+    /// Create a calculation of `name` with `args`, without trying to
+    /// simplify it to a single number.
+    pub fn new(name: CalcName, args: Vec<CalcValue>) -> Self {
+        Calculation { name, args }
+    }
+
+    /// Create a calculation, simplified as far as possible.
+    ///
+    /// If the calculation can be resolved to a single number, that
+    /// number is returned rather than a calculation.
+    /// As with other css functions, the number is not considered
+    /// calculated, so `16px/calc(16px * 1.4)` is not a division.
+    ///
+    /// It is an error to compare numbers with units that can never be
+    /// compatible, like `min(1px, 1s)`.
+    /// A top-level `min()` or `max()` is also the sass function, so
+    /// there a unitless number is compatible with any unit, as in
+    /// `max($width, 1)`.
+    pub fn create(
+        name: CalcName,
+        args: Vec<CalcValue>,
+    ) -> Result<Value, Error> {
+        Self::simplify(name, args, true)
+    }
+
+    /// Create a calculation nested in another calculation.
+    ///
+    /// As [`create`](Self::create), but a unitless number is never
+    /// compatible with a number with units, so `calc(min(1, 2px))` is
+    /// an error.
+    pub fn create_nested(
+        name: CalcName,
+        args: Vec<CalcValue>,
+    ) -> Result<Value, Error> {
+        Self::simplify(name, args, false)
+    }
+
+    fn simplify(
+        name: CalcName,
+        args: Vec<CalcValue>,
+        top_level: bool,
+    ) -> Result<Value, Error> {
+        let simplified = match name {
+            CalcName::Calc if args.len() == 1 => Some(args[0].clone()),
+            CalcName::Min => extreme(&args, Ordering::Less, top_level)?,
+            CalcName::Max => extreme(&args, Ordering::Greater, top_level)?,
+            CalcName::Clamp if args.len() == 3 => {
+                match extreme(&args, Ordering::Less, false)? {
+                    Some(_) => Some(clamp(&args)?),
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+        Ok(match simplified {
+            Some(CalcValue::Number(num, unit)) => {
+                Value::Numeric(num, unit, false)
+            }
+            Some(CalcValue::Calculation(calc)) => Value::Calculation(calc),
+            _ => Value::Calculation(Calculation { name, args }),
+        })
+    }
+
+    /// Returns true if all numbers in this calculation can be written
+    /// as css.
+    pub fn is_valid_css(&self) -> bool {
+        self.args.iter().all(CalcValue::is_valid_css)
+    }
+
+    pub fn format(&self, format: Format) -> Formatted<'_, Self> {
+        Formatted {
+            value: self,
+            format,
+        }
+    }
+}
+
+/// Get the smallest or largest of `args`, if they are all numbers
+/// with compatible units.
+///
+/// If `unitless_any` is true, a unitless number is compatible with
+/// any unit, as in the sass `min` and `max` functions.
+fn extreme(
+    args: &[CalcValue],
+    pref: Ordering,
+    unitless_any: bool,
+) -> Result<Option<CalcValue>, Error> {
+    check_compatible(args, unitless_any)?;
+    let mut best: Option<(&Number, &UnitSet)> = None;
+    for arg in args {
+        if let CalcValue::Number(num, unit) = arg {
+            best = match best {
+                Some((bnum, bunit)) => {
+                    let scale = if unitless_any
+                        && (unit.is_none() || bunit.is_none())
+                    {
+                        Number::from(1)
+                    } else if let Some(scale) = unit.scale_to(bunit) {
+                        scale
+                    } else {
+                        return Ok(None);
+                    };
+                    let scaled = num * &scale;
+                    if scaled.value.fuzzy_cmp(&bnum.value) == Some(pref) {
+                        Some((num, unit))
+                    } else {
+                        Some((bnum, bunit))
+                    }
+                }
+                None => Some((num, unit)),
+            };
+        } else {
+            return Ok(None);
+        }
+    }
+    Ok(best.map(|(num, unit)| CalcValue::Number(num.clone(), unit.clone())))
+}
+
+/// Clamp the second of three compatible numbers between the others.
+fn clamp(args: &[CalcValue]) -> Result<CalcValue, Error> {
+    let upper = extreme(&args[1..], Ordering::Less, false)?.unwrap();
+    let lower = [args[0].clone(), upper];
+    Ok(extreme(&lower, Ordering::Greater, false)?.unwrap())
+}
+
+/// Check that no two numbers in `args` have units that can never be
+/// compatible.
+fn check_compatible(
+    args: &[CalcValue],
+    unitless_any: bool,
+) -> Result<(), Error> {
+    for (i, a) in args.iter().enumerate() {
+        for b in &args[i + 1..] {
+            if let (CalcValue::Number(_, au), CalcValue::Number(_, bu)) =
+                (a, b)
+            {
+                if unitless_any && (au.is_none() || bu.is_none()) {
+                    continue;
+                }
+                if bu.scale_to(au).is_none() && !possibly_compatible(au, bu) {
+                    return Err(incompatible(a, b));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn incompatible(a: &CalcValue, b: &CalcValue) -> Error {
+    Error::S(format!(
+        "{} and {} are incompatible.",
+        a.format(Format::default()),
+        b.format(Format::default()),
+    ))
+}
+
+impl CalcValue {
+    /// Convert an evaluated value to a part of a calculation.
+    ///
+    /// Numbers, unquoted strings and calculations are allowed.
+    pub fn from_value(value: Value) -> Result<Self, Error> {
+        match value {
+            Value::Numeric(num, unit, _) => Ok(CalcValue::Number(num, unit)),
+            Value::Literal(s, Quotes::None) => Ok(CalcValue::Raw(s)),
+            Value::Calculation(calc) => Ok(CalcValue::Calculation(calc)),
+            Value::Paren(v) => CalcValue::from_value(*v),
+            v @ Value::Call(..) => {
+                Ok(CalcValue::Raw(v.format(Default::default()).to_string()))
+            }
+            v => Err(Error::S(format!(
+                "Value {} can't be used in a calculation.",
+                v.format(Default::default())
+            ))),
+        }
+    }
+
+    /// Create an operation, simplified if possible.
+    ///
+    /// Sums and differences are simplified if both operands are
+    /// numbers with compatible units.
+    /// It is an error to add or subtract numbers with units that can
+    /// never be compatible, like `1px + 1s` or `1 + 1px`.
+    /// Products and quotients of numbers are simplified even if the
+    /// result gets units that can't be written as css, since later
+    /// operations may cancel them out, as in `2px * 3px / 4px`.
+    /// Units that remain invalid are reported when the value is
+    /// written.
+    /// The exception is a percentage, that the browser may resolve to
+    /// something else, so `1px * 1%` is kept as is.
+    pub fn operation(
+        a: CalcValue,
+        op: Operator,
+        b: CalcValue,
+    ) -> Result<Self, Error> {
+        if let (CalcValue::Number(an, au), CalcValue::Number(bn, bu)) =
+            (&a, &b)
+        {
+            match op {
+                Operator::Plus | Operator::Minus => {
+                    if let Some(scale) = bu.scale_to(au) {
+                        let bn = bn * &scale;
+                        let value = if op == Operator::Plus {
+                            an.clone() + bn
+                        } else {
+                            an - &bn
+                        };
+                        return Ok(CalcValue::Number(value, au.clone()));
+                    }
+                    if !possibly_compatible(au, bu) {
+                        return Err(incompatible(&a, &b));
+                    }
+                }
+                Operator::Multiply | Operator::Div => {
+                    let (unit, scale) = if op == Operator::Multiply {
+                        au.multiply(bu)
+                    } else {
+                        au.divide(bu)
+                    };
+                    if unit.is_valid_css()
+                        || !(has_percent(au) || has_percent(bu))
+                    {
+                        let value = if op == Operator::Multiply {
+                            an * bn
+                        } else {
+                            an / bn
+                        };
+                        return Ok(CalcValue::Number(&value * &scale, unit));
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(CalcValue::Operation(Box::new(a), op, Box::new(b)))
+    }
+
+    fn is_valid_css(&self) -> bool {
+        match self {
+            CalcValue::Number(_, unit) => unit.is_valid_css(),
+            CalcValue::Raw(_) => true,
+            CalcValue::Calculation(calc) => calc.is_valid_css(),
+            CalcValue::Operation(a, _, b) => {
+                a.is_valid_css() && b.is_valid_css()
+            }
+        }
+    }
+
+    fn format(&self, format: Format) -> Formatted<'_, Self> {
+        Formatted {
+            value: self,
+            format,
+        }
+    }
+}
+
+/// Returns false if numbers with units `a` and `b` can never be added,
+/// even when relative units are resolved by the browser.
+///
+/// Numbers with a single known unit are possibly compatible if the
+/// units are of the same kind, so `1em + 1px` is kept for the browser
+/// to compute, while `1px + 1s` is not.  A percentage or an unknown
+/// unit may be compatible with anything.
+fn possibly_compatible(a: &UnitSet, b: &UnitSet) -> bool {
+    match (single_unit(a), single_unit(b)) {
+        (Some(Unit::None), Some(Unit::None)) => true,
+        (Some(Unit::None), _) | (_, Some(Unit::None)) => false,
+        (Some(a), Some(b)) => {
+            let (a, b) = (a.dimension(), b.dimension());
+            a == b
+                || a == "unknown"
+                || b == "unknown"
+                || a == "none"
+                || b == "none"
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if `units` includes a percentage.
+fn has_percent(units: &UnitSet) -> bool {
+    units
+        .numerators()
+        .iter()
+        .chain(units.denominators())
+        .any(|u| *u == Unit::Percent)
+}
+
+/// Get the only unit of `units`, or `Unit::None` for a unitless set.
+fn single_unit(units: &UnitSet) -> Option<Unit> {
+    match (units.numerators(), units.denominators()) {
+        ([], []) => Some(Unit::None),
+        ([unit], []) => Some(unit.clone()),
+        _ => None,
+    }
+}
+
+/// The precedence of a calculation operator; higher binds tighter.
+fn precedence(op: &Operator) -> u8 {
+    match op {
+        Operator::Multiply | Operator::Div => 2,
+        _ => 1,
+    }
+}
+
+impl<'a> Display for Formatted<'a, Calculation> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "{}(", self.value.name)?;
+        for (i, arg) in self.value.args.iter().enumerate() {
+            if i > 0 {
+                out.write_str(if self.format.is_compressed() {
+                    ","
+                } else {
+                    ", "
+                })?;
+            }
+            arg.format(self.format).fmt(out)?;
+        }
+        out.write_char(')')
+    }
+}
+
+impl<'a> Display for Formatted<'a, CalcValue> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self.value {
+            CalcValue::Number(num, unit) => {
+                write!(out, "{}{}", num.format(self.format), unit)
+            }
+            CalcValue::Raw(s) => out.write_str(s),
+            CalcValue::Calculation(calc) => calc.format(self.format).fmt(out),
+            CalcValue::Operation(a, op, b) => {
+                let a_paren = match **a {
+                    CalcValue::Operation(_, ref aop, _) => {
+                        precedence(aop) < precedence(op)
+                    }
+                    _ => false,
+                };
+                let b_paren = match **b {
+                    CalcValue::Operation(_, ref bop, _) => {
+                        precedence(bop) < precedence(op)
+                            || (precedence(bop) == precedence(op)
+                                && (*op == Operator::Minus
+                                    || *op == Operator::Div))
+                    }
+                    _ => false,
+                };
+                write_operand(out, a, a_paren, self.format)?;
+                // Whitespace is required around + and - in css.
+                if self.format.is_compressed() && precedence(op) == 2 {
+                    write!(out, "{}", op)?;
+                } else {
+                    write!(out, " {} ", op)?;
+                }
+                write_operand(out, b, b_paren, self.format)
+            }
+        }
+    }
+}
+
+fn write_operand(
+    out: &mut fmt::Formatter,
+    value: &CalcValue,
+    paren: bool,
+    format: Format,
+) -> fmt::Result {
+    if paren {
+        write!(out, "({})", value.format(format))
+    } else {
+        value.format(format).fmt(out)
+    }
+}
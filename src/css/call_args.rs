@@ -19,10 +19,27 @@ impl CallArgs {
             Value::List(v, _, false) => {
                 CallArgs(v.into_iter().map(|v| (None, v)).collect())
             }
+            Value::ArgList(args) => args,
             v => CallArgs(vec![(None, v)]),
         }
     }
 
+    /// Get the values of the positional (unnamed) arguments.
+    pub fn positional(&self) -> Vec<Value> {
+        self.0
+            .iter()
+            .filter(|(name, _)| name.is_none())
+            .map(|(_, v)| v.clone())
+            .collect()
+    }
+
+    /// Get the named arguments.
+    pub fn named(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.0
+            .iter()
+            .filter_map(|(name, v)| name.as_ref().map(|n| (n.as_ref(), v)))
+    }
+
     pub fn iter(&self) -> ::std::slice::Iter<(Option<String>, Value)> {
         self.0.iter()
     }
@@ -1,6 +1,8 @@
+mod calculation;
 mod call_args;
 mod value;
 mod valueformat;
 
+pub use self::calculation::{CalcName, CalcValue, Calculation};
 pub use self::call_args::CallArgs;
 pub use self::value::Value;
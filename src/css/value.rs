@@ -1,9 +1,11 @@
-use crate::css::CallArgs;
+use crate::css::{Calculation, CallArgs};
 use crate::error::Error;
 use crate::functions::SassFunction;
 use crate::ordermap::OrderMap;
 use crate::output::{Format, Formatted};
-use crate::value::{ListSeparator, Number, Operator, Quotes, Rgba, Unit};
+use crate::value::{
+    Color, ListSeparator, Number, Operator, Quotes, Rgba, UnitSet,
+};
 use num_rational::Rational;
 use std::convert::TryFrom;
 
@@ -19,13 +21,13 @@ pub enum Value {
     Literal(String, Quotes),
     /// A comma- or space separated list of values, with or without brackets.
     List(Vec<Value>, ListSeparator, bool),
-    /// A Numeric value is a rational value with a Unit (which may be
-    /// Unit::None) and flags.
+    /// A Numeric value is a number with units (which may be none)
+    /// and flags.
     ///
     /// The boolean flag is true for calculated values and false for
     /// literal values.
-    Numeric(Number, Unit, bool),
-    Color(Rgba, Option<String>),
+    Numeric(Number, UnitSet, bool),
+    Color(Color, Option<String>),
     Null,
     True,
     False,
@@ -38,11 +40,19 @@ pub enum Value {
     /// The string is the entire value, including the "U+" tag.
     UnicodeRange(String),
     Paren(Box<Value>),
+    /// A calculation, like `calc(100% - 2em)`, that could not be
+    /// simplified to a number.
+    Calculation(Calculation),
+    /// The rest argument of a variadic function or mixin.
+    ///
+    /// The positional arguments are the items of a comma separated
+    /// list, the named arguments are available through `keywords()`.
+    ArgList(CallArgs),
 }
 
 impl Value {
     pub fn scalar<T: Into<Number>>(v: T) -> Self {
-        Value::Numeric(v.into(), Unit::None, false)
+        Value::Numeric(v.into(), UnitSet::scalar(), false)
     }
     pub fn bool(v: bool) -> Self {
         if v {
@@ -52,13 +62,13 @@ impl Value {
         }
     }
     pub fn black() -> Self {
-        Value::Color(Rgba::from_rgb(0, 0, 0), Some("black".into()))
+        Value::Color(Rgba::from_rgb(0, 0, 0).into(), Some("black".into()))
     }
     pub fn rgba(r: Rational, g: Rational, b: Rational, a: Rational) -> Self {
-        Value::Color(Rgba::new(r, g, b, a), None)
+        Value::Color(Rgba::new(r, g, b, a).into(), None)
     }
     pub fn hsla(h: Rational, s: Rational, l: Rational, a: Rational) -> Self {
-        Value::Color(Rgba::from_hsla(h / 360, s, l, a), None)
+        Value::Color(Rgba::from_hsla(h / 360, s, l, a).into(), None)
     }
 
     pub fn type_name(&self) -> &'static str {
@@ -68,6 +78,8 @@ impl Value {
             Value::Numeric(..) => "number",
             Value::List(..) => "list",
             Value::Function(..) => "function",
+            Value::Calculation(..) => "calculation",
+            Value::ArgList(..) => "arglist",
             Value::True | Value::False => "bool",
             Value::Null => "null",
             _ => "unknown",
@@ -110,14 +122,51 @@ impl Value {
             }
             Value::Literal(ref s, Quotes::None) if s.is_empty() => true,
             Value::Paren(ref v) => v.is_null(),
+            Value::ArgList(ref args) => {
+                args.positional().iter().all(|v| v.is_null())
+            }
             _ => false,
         }
     }
 
+    /// Check that this value can be written as css.
+    ///
+    /// Numbers with compound units, like `px*px`, can not.
+    pub fn valid_css(self) -> Result<Self, Error> {
+        match self {
+            Value::Numeric(_, ref unit, _) if !unit.is_valid_css() => {
+                Err(Error::S(format!(
+                    "{} isn't a valid CSS value.",
+                    self.format(Default::default())
+                )))
+            }
+            Value::List(v, sep, bracketed) => Ok(Value::List(
+                v.into_iter()
+                    .map(|v| v.valid_css())
+                    .collect::<Result<_, _>>()?,
+                sep,
+                bracketed,
+            )),
+            Value::Paren(v) => Ok(Value::Paren(Box::new(v.valid_css()?))),
+            Value::Calculation(ref calc) if !calc.is_valid_css() => {
+                Err(Error::S(format!(
+                    "{} isn't a valid CSS value.",
+                    self.format(Default::default())
+                )))
+            }
+            v => Ok(v),
+        }
+    }
+
     pub fn integer_value(&self) -> Result<isize, Error> {
         match self {
             &Value::Numeric(ref num, ..) if num.is_integer() => {
-                Ok(num.to_integer())
+                num.to_isize().ok_or_else(|| {
+                    Error::S(format!(
+                        "{} is not an integer in a usable range.",
+                        self.format(Default::default())
+                    ))
+                })
             }
             v => Err(Error::bad_value("integer", v)),
         }
@@ -309,6 +358,7 @@ impl Value {
                 })
                 .collect(),
             Value::Paren(v) => v.iter_items(),
+            Value::ArgList(args) => args.positional(),
             v => vec![v],
         }
     }
@@ -43,13 +43,29 @@ impl<'a> Display for Formatted<'a, Value> {
             Value::Numeric(ref num, ref unit, _) => {
                 write!(out, "{}{}", num.format(self.format), unit)
             }
-            Value::Color(ref rgba, ref name) => {
-                if let Some(ref name) = *name {
-                    name.fmt(out)
+            Value::Calculation(ref calc) => calc.format(self.format).fmt(out),
+            Value::ArgList(ref args) => {
+                let t = args
+                    .positional()
+                    .iter()
+                    .filter(|v| !v.is_null())
+                    .map(|v| v.format(self.format).to_string())
+                    .collect::<Vec<_>>();
+                out.write_str(&t.join(if self.format.is_compressed() {
+                    ","
                 } else {
-                    rgba.format(self.format).fmt(out)
-                }
+                    ", "
+                }))
             }
+            Value::Color(ref color, ref name) => match *name {
+                Some(ref name)
+                    if self.format.colors.keep_original
+                        && !self.format.colors.shortest =>
+                {
+                    name.fmt(out)
+                }
+                _ => color.format(self.format).fmt(out),
+            },
             Value::List(ref v, ref sep, brackets) => {
                 let t = v
                     .iter()
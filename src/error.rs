@@ -1,4 +1,6 @@
 use crate::css::Value;
+use crate::logger;
+use crate::SourcePos;
 use nom;
 use std::convert::From;
 use std::path::PathBuf;
@@ -20,6 +22,25 @@ pub enum Error {
     },
     S(String),
     UndefinedVariable(String),
+    /// An error at a known position in the source.
+    ///
+    /// The trace is innermost first, each frame being a position in a
+    /// mixin, function, imported file or the root stylesheet.
+    Located {
+        error: Box<Error>,
+        trace: Vec<Frame>,
+    },
+}
+
+/// A step in the trace of a located error.
+#[derive(Clone, Debug)]
+pub struct Frame {
+    /// The position, if it is known yet.
+    pub pos: Option<SourcePos>,
+    /// What the position is in, e.g. `"foo()"` for a mixin or
+    /// function, `"@import"` for an imported file, or `None` for the
+    /// root stylesheet.
+    pub name: Option<String>,
 }
 
 impl std::error::Error for Error {}
@@ -48,16 +69,54 @@ impl Error {
 
     /// Multiple-argument variant of `badarg`.
     pub fn badargs(expected: &[&str], actual: &[&Value]) -> Error {
-        // TODO Better message!
+        let actual = actual
+            .iter()
+            .map(|v| {
+                format!(
+                    "{} = {}",
+                    v.type_name(),
+                    v.format(Default::default())
+                )
+            })
+            .collect::<Vec<_>>();
         Error::BadArguments(format!(
-            "expected {:?}, got {:?}",
-            expected, actual
+            "expected {}, got {}",
+            join_and(expected),
+            join_and(&actual),
         ))
     }
 
     pub fn undefined_variable(name: &str) -> Self {
         Error::UndefinedVariable(name.to_string())
     }
+
+    /// Get the error itself, without any source location.
+    pub fn inner(&self) -> &Error {
+        match *self {
+            Error::Located { ref error, .. } => error,
+            ref e => e,
+        }
+    }
+
+    /// Locate this error at `pos`, unless it already has a position.
+    ///
+    /// The trace is the current stack of mixins, functions and files
+    /// being evaluated, with `pos` as the innermost position.
+    /// Parse errors are never wrapped, as they have a position of
+    /// their own.
+    pub(crate) fn at(self, pos: Option<&SourcePos>) -> Self {
+        let pos = match pos {
+            Some(pos) if !pos.file().name().is_empty() => pos,
+            _ => return self,
+        };
+        match self {
+            e @ Error::Located { .. } | e @ Error::ParseError { .. } => e,
+            e => Error::Located {
+                error: Box::new(e),
+                trace: logger::trace_at(pos),
+            },
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -65,7 +124,12 @@ impl fmt::Display for Error {
         match *self {
             Error::S(ref s) => write!(out, "{}", s),
             Error::Input(ref p, ref e) => {
-                write!(out, "Failed to read {:?}: {}", p, e)
+                write!(out, "Failed to read {}: {}", p.display(), e)
+            }
+            Error::IoError(ref e) => e.fmt(out),
+            Error::Encoding(ref e) => write!(out, "Invalid UTF-8: {}", e),
+            Error::BadValue(ref s) | Error::BadArguments(ref s) => {
+                out.write_str(s)
             }
             Error::UndefinedVariable(ref name) => {
                 write!(out, "Undefined variable: \"${}\"", name)
@@ -83,12 +147,104 @@ impl fmt::Display for Error {
                     .map(|k| k.description())
                     .unwrap_or("something else"),
             ),
-            // fallback
-            ref x => write!(out, "{:?}", x),
+            Error::Located {
+                ref error,
+                ref trace,
+            } => {
+                let msg = error.to_string();
+                if msg.starts_with("Error: ") {
+                    writeln!(out, "{}", msg)?;
+                } else {
+                    writeln!(out, "Error: {}", msg)?;
+                }
+                if let Some(pos) = trace.iter().find_map(|f| f.pos.as_ref()) {
+                    write_snippet(out, pos)?;
+                }
+                write_trace(out, trace, "  ")
+            }
         }
     }
 }
 
+/// Write the frames of `trace` that have a known position, one per
+/// line, each line starting with a newline and `indent`.
+pub(crate) fn write_trace(
+    out: &mut dyn fmt::Write,
+    trace: &[Frame],
+    indent: &str,
+) -> fmt::Result {
+    let trace = trace
+        .iter()
+        .filter_map(|f| f.pos.as_ref().map(|p| (p, &f.name)))
+        .collect::<Vec<_>>();
+    let places = trace
+        .iter()
+        .map(|&(pos, _)| {
+            let (line, col) = pos.line_col();
+            format!("{} {}:{}", pos.file().name(), line, col)
+        })
+        .collect::<Vec<_>>();
+    let width = places.iter().map(|p| p.len()).max().unwrap_or(0);
+    for (place, &(_, name)) in places.iter().zip(&trace) {
+        write!(
+            out,
+            "\n{}{:w$}  {}",
+            indent,
+            place,
+            name.as_ref()
+                .map(|n| n.as_str())
+                .unwrap_or("root stylesheet"),
+            w = width,
+        )?;
+    }
+    Ok(())
+}
+
+/// Write the source line of `pos`, with the statement starting there
+/// underlined.
+///
+/// The underline ends where the head of the statement ends, or at
+/// the end of the line if the head spans several lines.
+fn write_snippet(out: &mut fmt::Formatter, pos: &SourcePos) -> fmt::Result {
+    let data = pos.file().data();
+    let start = data[..pos.offset()]
+        .iter()
+        .rposition(|c| *c == b'\n')
+        .map(|p| p + 1)
+        .unwrap_or(0);
+    let end = data[pos.offset()..]
+        .iter()
+        .position(|c| *c == b'\n')
+        .map(|p| pos.offset() + p)
+        .unwrap_or_else(|| data.len());
+    let line = String::from_utf8_lossy(&data[start..end]);
+    let line = line.trim_end();
+    let before = String::from_utf8_lossy(&data[start..pos.offset()]);
+    let col = before.chars().count();
+    let head =
+        String::from_utf8_lossy(&data[pos.offset()..pos.end().min(end)]);
+    let head = head.trim_end();
+    let head = head
+        .strip_suffix(|c| c == ';' || c == '{')
+        .unwrap_or(head)
+        .trim_end();
+    let len = head.chars().count().max(1);
+    let (lineno, _) = pos.line_col();
+    let w = lineno.to_string().len();
+    writeln!(out, "{:w$} \u{2577}", "", w = w)?;
+    writeln!(out, "{} \u{2502} {}", lineno, line)?;
+    writeln!(
+        out,
+        "{:w$} \u{2502} {:col$}{}",
+        "",
+        "",
+        "^".repeat(len),
+        w = w,
+        col = col,
+    )?;
+    write!(out, "{:w$} \u{2575}", "", w = w)
+}
+
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self {
         Error::IoError(e)
@@ -103,7 +259,29 @@ impl From<FromUtf8Error> for Error {
 
 impl<'a> From<nom::Err<(&[u8], nom::error::ErrorKind)>> for Error {
     fn from(e: nom::Err<(&[u8], nom::error::ErrorKind)>) -> Self {
-        Error::S(format!("Parse error: {:?}", e))
+        match e {
+            nom::Err::Incomplete(_) => {
+                Error::S("Parse error: unexpected end of input".into())
+            }
+            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
+                Error::S(format!(
+                    "Parse error, expected {}",
+                    kind.description()
+                ))
+            }
+        }
+    }
+}
+
+/// Join `items` with commas, and "and" before the last one.
+fn join_and<T: AsRef<str>>(items: &[T]) -> String {
+    match items.split_last() {
+        Some((last, init)) if !init.is_empty() => {
+            let init = init.iter().map(|s| s.as_ref()).collect::<Vec<_>>();
+            format!("{} and {}", init.join(", "), last.as_ref())
+        }
+        Some((last, _)) => last.as_ref().to_string(),
+        None => String::new(),
     }
 }
 
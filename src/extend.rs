@@ -0,0 +1,1244 @@
+//! Support for the `@extend` directive.
+//!
+//! An `@extend` may affect rules both before and after it in the
+//! document, so extensions are collected while the document is
+//! evaluated, and applied to the selectors of all rules when the css
+//! output is finalized.
+//!
+//! The same selector logic is used by the selector functions, like
+//! `selector-extend`, `selector-unify` and `is-superselector`.
+use crate::error::Error;
+use crate::sass::SassString;
+use crate::selectors::{Selector, SelectorPart, Selectors};
+
+/// A single `@extend`, as seen from one selector of the extending rule.
+#[derive(Clone, Debug)]
+pub struct Extension {
+    extender: Complex,
+    target: Compound,
+    optional: bool,
+    media: Option<String>,
+}
+
+impl Extension {
+    /// Create extensions for `extenders` extending each of `targets`.
+    ///
+    /// The `media` is the media query context of the `@extend`
+    /// directive, if any.
+    pub fn create(
+        extenders: &Selectors,
+        targets: &Selectors,
+        optional: bool,
+        media: Option<&str>,
+    ) -> Result<Vec<Extension>, Error> {
+        let mut result = Vec::new();
+        for target in &targets.s {
+            let target = Complex::from_selector(target);
+            if target.leading.is_some() || !target.steps.is_empty() {
+                return Err(Error::S(
+                    "complex selectors may not be extended.".into(),
+                ));
+            }
+            for extender in &extenders.s {
+                result.push(Extension {
+                    extender: Complex::from_selector(extender),
+                    target: target.last.clone(),
+                    optional,
+                    media: media.map(String::from),
+                });
+            }
+        }
+        Ok(result)
+    }
+
+    fn applies_in(&self, media: Option<&str>) -> bool {
+        self.media.is_none()
+            || self.media.as_ref().map(|m| m.as_ref()) == media
+    }
+
+    fn target_string(&self) -> String {
+        Complex {
+            leading: None,
+            steps: vec![],
+            last: self.target.clone(),
+        }
+        .to_selector()
+        .to_string()
+    }
+}
+
+/// Applies a set of extensions to the selectors of rules.
+pub struct Extender {
+    extensions: Vec<Extension>,
+    /// The index of the first extension of each `@extend`.
+    groups: Vec<usize>,
+    /// For each extension, is it applied to any rule?
+    matched: Vec<bool>,
+    /// For each extension, is its target found in another media context?
+    elsewhere: Vec<bool>,
+    mode: Mode,
+}
+
+/// How the selectors are extended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    /// As by `@extend`, where selectors with placeholders are removed
+    /// from the result.
+    Normal,
+    /// As by `selector-extend`, where placeholders are kept.
+    Function,
+    /// As by `selector-replace`, where an extended selector is
+    /// replaced by the new selectors.
+    Replace,
+}
+
+/// Extending is iterated for chained extends, so an upper bound on
+/// the number of selectors is needed to avoid infinite recursion.
+const MAX_SELECTORS: usize = 1000;
+
+impl Extender {
+    /// Create an extender for the extensions of each `@extend`.
+    pub fn new(extensions: Vec<Vec<Extension>>) -> Self {
+        let mut groups = Vec::with_capacity(extensions.len());
+        let mut all = Vec::new();
+        for group in extensions {
+            groups.push(all.len());
+            all.extend(group);
+        }
+        let n = all.len();
+        Extender {
+            extensions: all,
+            groups,
+            matched: vec![false; n],
+            elsewhere: vec![false; n],
+            mode: Mode::Normal,
+        }
+    }
+
+    /// Extend the selectors of a rule in the given media context.
+    ///
+    /// The rule is preceded by the first `before` `@extend` directives,
+    /// which affects the order of the resulting selectors.
+    ///
+    /// Returns the selectors to actually write, or `None` if nothing
+    /// remains to be written, i.e. if all selectors contains
+    /// placeholders.
+    pub fn extend(
+        &mut self,
+        selectors: &Selectors,
+        media: Option<&str>,
+        before: usize,
+    ) -> Option<Selectors> {
+        let steps = self.steps(before);
+        let mut changed = false;
+        let mut result: Vec<Complex> = Vec::new();
+        for selector in &selectors.s {
+            let mut original = Complex::from_selector(selector);
+            if let Some(extended) =
+                self.extend_pseudos(&original, media, before)
+            {
+                original = extended;
+                changed = true;
+            }
+            // Each selector found, with the extensions used to create it
+            // and the extensions tried on it.
+            // The selectors created by extending a selector are placed
+            // directly after it.
+            // This is repeated until nothing changes, so that chained
+            // extensions apply in any order.
+            let mut found = vec![(original.clone(), vec![], vec![])];
+            let mut added = true;
+            while added && found.len() < MAX_SELECTORS {
+                added = false;
+                for step in &steps {
+                    let mut n = 0;
+                    while n < found.len() && found.len() < MAX_SELECTORS {
+                        let (current, used, tried) = &mut found[n];
+                        n += 1;
+                        let mut used = used.clone();
+                        let mut active = Vec::new();
+                        for &e in step {
+                            if tried.contains(&e) {
+                                continue;
+                            }
+                            tried.push(e);
+                            let ext = &self.extensions[e];
+                            if !current.matches(&ext.target) {
+                                continue;
+                            }
+                            if !ext.applies_in(media) {
+                                self.elsewhere[e] = true;
+                                continue;
+                            }
+                            self.matched[e] = true;
+                            used.push(e);
+                            active.push(ext);
+                        }
+                        if active.is_empty() {
+                            continue;
+                        }
+                        for new in current.extend_with(&active) {
+                            if found.iter().all(|(f, _, _)| *f != new) {
+                                found.insert(
+                                    n,
+                                    (new, used.clone(), used.clone()),
+                                );
+                                n += 1;
+                                added = true;
+                            }
+                        }
+                    }
+                }
+            }
+            let found =
+                found.into_iter().map(|(c, _, _)| c).collect::<Vec<_>>();
+            changed |= found.len() > 1;
+            let replaced = self.mode == Mode::Replace && found.len() > 1;
+            for (i, complex) in found.iter().enumerate() {
+                // A generated selector is redundant if another selector
+                // matches all it does, unless it would reduce specificity.
+                let redundant = i > 0
+                    && found.iter().enumerate().any(|(j, other)| {
+                        j != i
+                            && !(replaced && j == 0)
+                            && other.is_superselector(complex)
+                            && !(complex.is_superselector(other) && j > i)
+                            && other.specificity()
+                                >= self.source_specificity(complex)
+                    });
+                if redundant
+                    || (replaced && i == 0)
+                    || (self.mode == Mode::Normal
+                        && complex.has_placeholder())
+                {
+                    changed = true;
+                } else if !result.contains(complex) {
+                    result.push(complex.clone());
+                }
+            }
+        }
+        if !changed {
+            Some(selectors.clone())
+        } else if result.is_empty() {
+            None
+        } else {
+            Some(Selectors::new(
+                result.iter().map(Complex::to_selector).collect(),
+            ))
+        }
+    }
+
+    /// The extensions to apply together, in turn, for a rule after
+    /// the first `before` `@extend` directives.
+    ///
+    /// Extensions from before the rule are all applied at once.  Each
+    /// later `@extend` is applied in turn, so the selectors from the
+    /// last one ends up closest to the original.
+    fn steps(&self, before: usize) -> Vec<Vec<usize>> {
+        let end = |g: usize| {
+            self.groups.get(g).copied().unwrap_or(self.extensions.len())
+        };
+        let mut steps = vec![(0..end(before)).collect::<Vec<_>>()];
+        for g in before..self.groups.len() {
+            steps.push((self.groups[g]..end(g + 1)).collect());
+        }
+        steps
+    }
+
+    /// Extend the selector arguments of pseudo selectors like `:not()`
+    /// and `:is()` in `complex`.
+    ///
+    /// Returns `None` if no argument was changed.
+    fn extend_pseudos(
+        &mut self,
+        complex: &Complex,
+        media: Option<&str>,
+        before: usize,
+    ) -> Option<Complex> {
+        let mut changed = false;
+        let mut extend_compound = |compound: &Compound| {
+            let mut result = Vec::with_capacity(compound.0.len());
+            for part in &compound.0 {
+                match self.extend_pseudo(part, media, before) {
+                    Some(parts) => {
+                        changed = true;
+                        result.extend(parts);
+                    }
+                    None => result.push(part.clone()),
+                }
+            }
+            Compound(result)
+        };
+        let result = Complex {
+            leading: complex.leading.clone(),
+            steps: complex
+                .steps
+                .iter()
+                .map(|(c, comb)| (extend_compound(c), comb.clone()))
+                .collect(),
+            last: extend_compound(&complex.last),
+        };
+        if changed {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Extend the selector argument of a single pseudo selector.
+    ///
+    /// Returns the pseudo selectors to use instead of `part`, or
+    /// `None` if `part` is not changed.
+    fn extend_pseudo(
+        &mut self,
+        part: &SelectorPart,
+        media: Option<&str>,
+        before: usize,
+    ) -> Option<Vec<SelectorPart>> {
+        let (name, arg) = match *part {
+            SelectorPart::Pseudo {
+                ref name,
+                arg: Some(ref arg),
+            } if is_selector_pseudo(name) => (name, arg),
+            _ => return None,
+        };
+        // Placeholders are kept in the argument, and removed below.
+        let mode = self.mode;
+        if mode == Mode::Normal {
+            self.mode = Mode::Function;
+        }
+        let extended = self.extend(arg, media, before);
+        self.mode = mode;
+        let extended = extended.filter(|e| e != arg)?;
+        let mut complexes = extended.s;
+        if mode == Mode::Normal {
+            complexes
+                .retain(|s| !Complex::from_selector(s).has_placeholder());
+        }
+        let is_not = raw(name) == "not";
+        // Complex selectors in `:not()` are not widely supported, so
+        // don't add them unless the original already had them.
+        if is_not && !arg.s.iter().any(is_complex) {
+            complexes.retain(|s| !is_complex(s));
+        }
+        if complexes.is_empty() {
+            return None;
+        }
+        let with_arg = |s: Vec<Selector>| SelectorPart::Pseudo {
+            name: name.clone(),
+            arg: Some(Selectors::new(s)),
+        };
+        // Older browsers only support a single selector in `:not()`,
+        // so it is split unless it originally had a list.
+        if is_not && arg.s.len() == 1 {
+            Some(complexes.into_iter().map(|s| with_arg(vec![s])).collect())
+        } else {
+            Some(vec![with_arg(complexes)])
+        }
+    }
+
+    /// The highest specificity of any extender that contributes
+    /// to `complex`.
+    fn source_specificity(&self, complex: &Complex) -> u32 {
+        self.extensions
+            .iter()
+            .filter(|ext| complex.parts().any(|p| ext.extender.contains(p)))
+            .map(|ext| ext.extender.specificity())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Check that every non-optional extension was applied somewhere.
+    pub fn check(&self) -> Result<(), Error> {
+        for (e, ext) in self.extensions.iter().enumerate() {
+            if self.matched[e] || ext.optional {
+                continue;
+            }
+            if self.elsewhere[e] {
+                return Err(Error::S(
+                    "You may not @extend selectors across media queries."
+                        .into(),
+                ));
+            }
+            return Err(Error::S(format!(
+                "The target selector was not found.\
+                 \nUse \"@extend {} !optional\" to avoid this error.",
+                ext.target_string(),
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Extend `selectors` with `extender` wherever `extendee` matches,
+/// as done by the `selector-extend` function.
+///
+/// If `replace` is true, the matching selectors are replaced rather
+/// than kept, as done by `selector-replace`.
+pub fn extend_selectors(
+    selectors: &Selectors,
+    extendee: &Selectors,
+    extender: &Selectors,
+    replace: bool,
+) -> Result<Selectors, Error> {
+    let extensions = Extension::create(extender, extendee, true, None)?;
+    let mut extender = Extender::new(vec![extensions]);
+    extender.mode = if replace {
+        Mode::Replace
+    } else {
+        Mode::Function
+    };
+    Ok(extender
+        .extend(selectors, None, 1)
+        .unwrap_or_else(|| selectors.clone()))
+}
+
+/// Get selectors that match only elements matched by both `a` and `b`.
+///
+/// Returns `None` if no element can be matched by both.
+pub fn unify_selectors(a: &Selectors, b: &Selectors) -> Option<Selectors> {
+    let mut result = Vec::new();
+    for sa in &a.s {
+        let sa = Complex::from_selector(sa);
+        for sb in &b.s {
+            for unified in sa.unify(&Complex::from_selector(sb)) {
+                if !result.contains(&unified) {
+                    result.push(unified);
+                }
+            }
+        }
+    }
+    if result.is_empty() {
+        None
+    } else {
+        Some(Selectors::new(
+            result.iter().map(Complex::to_selector).collect(),
+        ))
+    }
+}
+
+/// True if `sup` matches every element that `sub` matches.
+pub fn is_superselector(sup: &Selectors, sub: &Selectors) -> bool {
+    let sup = sup.s.iter().map(Complex::from_selector).collect::<Vec<_>>();
+    sub.s
+        .iter()
+        .map(Complex::from_selector)
+        .all(|sub| sup.iter().any(|sup| sup.is_superselector(&sub)))
+}
+
+/// Get the simple selectors of a compound selector.
+///
+/// Returns `None` if `selector` is not a compound selector.
+pub fn simple_selectors(selector: &Selector) -> Option<Vec<SelectorPart>> {
+    let complex = Complex::from_selector(selector);
+    if complex.leading.is_some() || !complex.steps.is_empty() {
+        None
+    } else {
+        Some(complex.last.0)
+    }
+}
+
+/// True for pseudo selectors that take a selector list as argument
+/// that may be extended.
+fn is_selector_pseudo(name: &SassString) -> bool {
+    matches!(
+        raw(name),
+        "not" | "is" | "matches" | "where" | "any" | "current" | "has"
+    )
+}
+
+/// True for pseudo selectors where an element is matched if any
+/// selector in the argument matches it.
+fn is_matches_pseudo(name: &SassString) -> bool {
+    matches!(raw(name), "is" | "matches" | "where" | "any")
+}
+
+/// True if `selector` contains any combinator.
+fn is_complex(selector: &Selector) -> bool {
+    selector.0.iter().any(SelectorPart::is_operator)
+}
+
+/// A complex selector, split into compound selectors and combinators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Complex {
+    /// A combinator before the first compound selector, if any.
+    leading: Option<SelectorPart>,
+    /// Each compound selector but the last, with the combinator after it.
+    steps: Vec<(Compound, SelectorPart)>,
+    last: Compound,
+}
+
+impl Complex {
+    fn from_selector(s: &Selector) -> Self {
+        let mut leading = None;
+        let mut steps = Vec::new();
+        let mut current = Vec::new();
+        for part in &s.0 {
+            if part.is_operator() {
+                if current.is_empty() && steps.is_empty() {
+                    leading = Some(part.clone());
+                } else {
+                    steps.push((Compound(current), part.clone()));
+                    current = Vec::new();
+                }
+            } else {
+                current.push(part.clone());
+            }
+        }
+        Complex {
+            leading,
+            steps,
+            last: Compound(current),
+        }
+    }
+
+    fn to_selector(&self) -> Selector {
+        let mut result = Vec::new();
+        result.extend(self.leading.iter().cloned());
+        for (compound, combinator) in &self.steps {
+            result.extend(compound.0.iter().cloned());
+            result.push(combinator.clone());
+        }
+        result.extend(self.last.0.iter().cloned());
+        Selector(result)
+    }
+
+    fn compound(&self, i: usize) -> &Compound {
+        self.steps.get(i).map(|s| &s.0).unwrap_or(&self.last)
+    }
+
+    /// True if this matches every element that `other` matches.
+    fn is_superselector(&self, other: &Complex) -> bool {
+        if self.leading != other.leading
+            || !self.last.is_superselector(&other.last)
+        {
+            return false;
+        }
+        // The index in other of the last matched compound.
+        let mut prev = other.steps.len();
+        for (compound, comb) in self.steps.iter().rev() {
+            if is_descendant(comb) {
+                loop {
+                    if prev == 0 {
+                        return false;
+                    }
+                    prev -= 1;
+                    let (ocompound, ocomb) = &other.steps[prev];
+                    if !is_descendant(ocomb)
+                        && *ocomb != SelectorPart::RelOp(b'>')
+                    {
+                        return false;
+                    }
+                    if compound.is_superselector(ocompound) {
+                        break;
+                    }
+                }
+            } else {
+                if prev == 0 {
+                    return false;
+                }
+                prev -= 1;
+                let (ocompound, ocomb) = &other.steps[prev];
+                if comb != ocomb || !compound.is_superselector(ocompound) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn has_placeholder(&self) -> bool {
+        self.parts().any(|p| kind(p) == Kind::Placeholder)
+    }
+
+    fn contains(&self, part: &SelectorPart) -> bool {
+        self.parts().any(|p| p == part)
+    }
+
+    /// All simple selectors of this complex selector.
+    fn parts(&self) -> impl Iterator<Item = &SelectorPart> {
+        self.steps
+            .iter()
+            .map(|s| &s.0)
+            .chain(Some(&self.last))
+            .flat_map(|c| c.0.iter())
+    }
+
+    fn specificity(&self) -> u32 {
+        self.parts().map(SelectorPart::specificity).sum()
+    }
+
+    /// Unify this complex selector with `other`.
+    ///
+    /// Returns all selectors that match elements matched by both.
+    fn unify(&self, other: &Complex) -> Vec<Complex> {
+        let last = match other.last.unify(self.last.clone()) {
+            Some(last) => last,
+            None => return vec![],
+        };
+        let leading = match merge_leading(&self.leading, &other.leading) {
+            Some(leading) => leading,
+            None => return vec![],
+        };
+        weave_parents(&self.steps, &other.steps)
+            .into_iter()
+            .map(|steps| Complex {
+                leading: leading.clone(),
+                steps,
+                last: last.clone(),
+            })
+            .collect()
+    }
+
+    /// True if any compound selector in this matches `target`.
+    fn matches(&self, target: &Compound) -> bool {
+        (0..=self.steps.len()).any(|i| self.compound(i).matches(target))
+    }
+
+    /// Apply the extensions `exts` at once on the compound selectors
+    /// they match.
+    ///
+    /// Each compound is either kept or extended in each possible way,
+    /// and all combinations but the original are returned, with the
+    /// first compound varying fastest.
+    fn extend_with(&self, exts: &[&Extension]) -> Vec<Complex> {
+        let options = (0..=self.steps.len())
+            .map(|i| self.compound(i).extend_with(exts))
+            .collect::<Vec<_>>();
+        let mut result = Vec::new();
+        for path in paths(&options).into_iter().skip(1) {
+            // The leading combinator and the steps of each partial result.
+            let mut partial = vec![(self.leading.clone(), Vec::new())];
+            for (i, piece) in path.into_iter().enumerate() {
+                let mut next = Vec::new();
+                for (leading, steps) in partial {
+                    let leading =
+                        match merge_leading(&leading, &piece.leading) {
+                            Some(leading) => leading,
+                            None => continue,
+                        };
+                    for mut steps in weave_parents(&steps, &piece.parents) {
+                        if let Some((_, combinator)) = self.steps.get(i) {
+                            steps.push((
+                                piece.compound.clone(),
+                                combinator.clone(),
+                            ));
+                            next.push((leading.clone(), steps));
+                        } else {
+                            result.push(Complex {
+                                leading: leading.clone(),
+                                steps,
+                                last: piece.compound.clone(),
+                            });
+                        }
+                    }
+                }
+                partial = next;
+            }
+        }
+        result
+    }
+}
+
+/// A compound selector as extended, with the leading combinator and
+/// the parents of the extenders, to weave into the result.
+#[derive(Clone)]
+struct Piece {
+    leading: Option<SelectorPart>,
+    parents: Steps,
+    compound: Compound,
+}
+
+/// A compound selector, i.e. a sequence of simple selectors.
+#[derive(Clone, Debug, Eq)]
+struct Compound(Vec<SelectorPart>);
+
+/// The order of simple selectors in a compound does not matter.
+impl PartialEq for Compound {
+    fn eq(&self, other: &Compound) -> bool {
+        self.0.len() == other.0.len()
+            && self.0.iter().all(|p| other.0.contains(p))
+    }
+}
+
+impl Compound {
+    /// True if this compound selector contains all parts of `target`.
+    fn matches(&self, target: &Compound) -> bool {
+        !target.0.is_empty() && target.0.iter().all(|t| self.0.contains(t))
+    }
+
+    /// True if this matches every element that `other` matches.
+    fn is_superselector(&self, other: &Compound) -> bool {
+        self.0.iter().all(|p| simple_is_superselector(p, other))
+    }
+
+    /// Unify this compound selector into `other`.
+    ///
+    /// Returns a compound selector that matches only elements matched
+    /// by both, or `None` if no such element can exist.
+    fn unify(&self, other: Compound) -> Option<Compound> {
+        self.0
+            .iter()
+            .try_fold(other, |result, simple| unify_simple(simple, result))
+    }
+
+    /// Apply the extensions `exts` at once on this compound selector.
+    ///
+    /// Each target is either kept or replaced by one of its extenders,
+    /// with the first target varying fastest.  The original is first
+    /// in the result.
+    fn extend_with(&self, exts: &[&Extension]) -> Vec<Piece> {
+        // The matching extensions, grouped by target.
+        let mut targets: Vec<(&Compound, Vec<Option<&Extension>>)> =
+            Vec::new();
+        for ext in exts.iter().filter(|ext| self.matches(&ext.target)) {
+            match targets.iter_mut().find(|(t, _)| **t == ext.target) {
+                Some((_, group)) => group.push(Some(ext)),
+                None => targets.push((&ext.target, vec![None, Some(ext)])),
+            }
+        }
+        targets.sort_by_key(|(t, _)| {
+            self.0.iter().position(|p| t.0.contains(p))
+        });
+        let choices = targets.into_iter().map(|(_, e)| e).collect::<Vec<_>>();
+        let mut result = vec![Piece {
+            leading: None,
+            parents: Vec::new(),
+            compound: self.clone(),
+        }];
+        for path in paths(&choices).into_iter().skip(1) {
+            let chosen = path.into_iter().flatten().collect::<Vec<_>>();
+            let rest = self
+                .0
+                .iter()
+                .filter(|p| !chosen.iter().any(|e| e.target.0.contains(p)))
+                .cloned()
+                .collect();
+            let mut compound = Some(Compound(rest));
+            let mut partial = vec![(None, Vec::new())];
+            for ext in chosen {
+                compound = compound.and_then(|c| ext.extender.last.unify(c));
+                partial = partial
+                    .into_iter()
+                    .filter_map(|(leading, steps)| {
+                        merge_leading(&leading, &ext.extender.leading)
+                            .map(|leading| (leading, steps))
+                    })
+                    .flat_map(|(leading, steps)| {
+                        weave_parents(&steps, &ext.extender.steps)
+                            .into_iter()
+                            .map(move |steps| (leading.clone(), steps))
+                    })
+                    .collect();
+            }
+            if let Some(compound) = compound {
+                for (leading, parents) in partial {
+                    result.push(Piece {
+                        leading,
+                        parents,
+                        compound: compound.clone(),
+                    });
+                }
+            }
+        }
+        result
+    }
+}
+
+/// The kinds of simple selectors, needed for unification.
+#[derive(Debug, PartialEq, Eq)]
+enum Kind {
+    Universal,
+    Type,
+    Id,
+    Class,
+    Placeholder,
+    Attribute,
+    PseudoClass,
+    PseudoElement,
+    Other,
+}
+
+fn kind(part: &SelectorPart) -> Kind {
+    match *part {
+        SelectorPart::Universal { .. } => Kind::Universal,
+        SelectorPart::Type { .. } => Kind::Type,
+        SelectorPart::Class(_) => Kind::Class,
+        SelectorPart::Id(_) => Kind::Id,
+        SelectorPart::Placeholder(_) => Kind::Placeholder,
+        SelectorPart::Attribute { .. } => Kind::Attribute,
+        ref p if p.is_pseudo_element() => Kind::PseudoElement,
+        SelectorPart::Pseudo { .. } => Kind::PseudoClass,
+        _ => Kind::Other,
+    }
+}
+
+/// True if every element matched by `compound` is matched by `simple`.
+fn simple_is_superselector(
+    simple: &SelectorPart,
+    compound: &Compound,
+) -> bool {
+    if compound.0.contains(simple) {
+        return true;
+    }
+    let (name, arg) = match *simple {
+        SelectorPart::Pseudo {
+            ref name,
+            arg: Some(ref arg),
+        } => (name, arg),
+        _ => {
+            // `.a` matches everything `:is(.a.b, .a.c)` matches.
+            return compound.0.iter().any(|part| match *part {
+                SelectorPart::Pseudo {
+                    ref name,
+                    arg: Some(ref arg),
+                } if is_matches_pseudo(name) => arg.s.iter().all(|s| {
+                    let s = Complex::from_selector(s);
+                    s.last.0.contains(simple)
+                }),
+                _ => false,
+            });
+        }
+    };
+    // The argument of the same pseudo selector in `compound`, if any.
+    let same_pseudo = || {
+        compound.0.iter().filter_map(move |part| match *part {
+            SelectorPart::Pseudo {
+                name: ref name2,
+                arg: Some(ref arg2),
+            } if raw(name2) == raw(name) => Some(arg2),
+            _ => None,
+        })
+    };
+    if is_matches_pseudo(name) {
+        let compound = Complex {
+            leading: None,
+            steps: vec![],
+            last: compound.clone(),
+        };
+        arg.s
+            .iter()
+            .any(|s| Complex::from_selector(s).is_superselector(&compound))
+            || same_pseudo().any(|arg2| is_superselector(arg, arg2))
+    } else if raw(name) == "not" {
+        // Each selector in the argument must be excluded by something
+        // in `compound`.
+        arg.s.iter().all(|complex| {
+            let last = Complex::from_selector(complex).last;
+            compound.0.iter().any(|part| match kind(part) {
+                k @ Kind::Type | k @ Kind::Id => {
+                    last.0.iter().any(|p| kind(p) == k && p != part)
+                }
+                Kind::PseudoClass => match *part {
+                    SelectorPart::Pseudo {
+                        name: ref name2,
+                        arg: Some(ref arg2),
+                    } if raw(name2) == "not" => is_superselector(
+                        arg2,
+                        &Selectors::new(vec![complex.clone()]),
+                    ),
+                    _ => false,
+                },
+                _ => false,
+            })
+        })
+    } else {
+        same_pseudo().any(|arg2| is_superselector(arg, arg2))
+    }
+}
+
+/// Unify the simple selector `simple` into the compound selector `compound`.
+fn unify_simple(
+    simple: &SelectorPart,
+    compound: Compound,
+) -> Option<Compound> {
+    let mut compound = compound.0;
+    match kind(simple) {
+        Kind::Universal | Kind::Type => {
+            match compound.first().map(kind) {
+                Some(Kind::Universal) | Some(Kind::Type) => {
+                    compound[0] = unify_types(simple, &compound[0])?;
+                }
+                _ if kind(simple) == Kind::Universal
+                    && ns_name(simple)
+                        .0
+                        .map(|ns| ns == "*")
+                        .unwrap_or(true) =>
+                {
+                    // A universal selector without a specific
+                    // namespace adds nothing to a compound selector.
+                    if compound.is_empty() {
+                        compound.push(simple.clone());
+                    }
+                }
+                _ => compound.insert(0, simple.clone()),
+            }
+            return Some(Compound(compound));
+        }
+        Kind::Id
+            if compound
+                .iter()
+                .any(|p| kind(p) == Kind::Id && p != simple) =>
+        {
+            return None;
+        }
+        _ => (),
+    }
+    if compound.contains(simple) {
+        return Some(Compound(compound));
+    }
+    let pos = match kind(simple) {
+        Kind::PseudoElement => {
+            if compound.iter().any(|p| kind(p) == Kind::PseudoElement) {
+                return None;
+            }
+            None
+        }
+        Kind::PseudoClass => {
+            compound.iter().position(|p| kind(p) == Kind::PseudoElement)
+        }
+        _ => compound.iter().position(|p| {
+            matches!(kind(p), Kind::PseudoClass | Kind::PseudoElement)
+        }),
+    };
+    match pos {
+        Some(pos) => compound.insert(pos, simple.clone()),
+        None => compound.push(simple.clone()),
+    }
+    Some(Compound(compound))
+}
+
+/// Unify two type or universal selectors, considering namespaces.
+fn unify_types(a: &SelectorPart, b: &SelectorPart) -> Option<SelectorPart> {
+    let (ns1, name1) = ns_name(a);
+    let (ns2, name2) = ns_name(b);
+    let ns = if ns1 == ns2 || ns2 == Some("*") {
+        ns1
+    } else if ns1 == Some("*") {
+        ns2
+    } else {
+        return None;
+    };
+    let name = if name1 == name2 || name2 == "*" {
+        name1
+    } else if name1 == "*" {
+        name2
+    } else {
+        return None;
+    };
+    let ns = ns.map(SassString::from);
+    Some(if name == "*" {
+        SelectorPart::Universal { ns }
+    } else {
+        SelectorPart::Type {
+            ns,
+            name: name.into(),
+        }
+    })
+}
+
+fn raw(s: &SassString) -> &str {
+    s.single_raw().unwrap_or_default()
+}
+
+/// Get the namespace and name of a type or universal selector.
+fn ns_name(part: &SelectorPart) -> (Option<&str>, &str) {
+    match *part {
+        SelectorPart::Type { ref ns, ref name } => {
+            (ns.as_ref().map(raw), raw(name))
+        }
+        SelectorPart::Universal { ref ns } => (ns.as_ref().map(raw), "*"),
+        _ => (None, ""),
+    }
+}
+
+type Steps = Vec<(Compound, SelectorPart)>;
+
+/// Combine the parents of two complex selectors.
+///
+/// The result contains all ways to combine the parents so that both
+/// the original relations are kept.
+fn weave_parents(
+    a: &[(Compound, SelectorPart)],
+    b: &[(Compound, SelectorPart)],
+) -> Vec<Steps> {
+    let (la, lb) = match (a.last(), b.last()) {
+        (None, _) => return vec![b.to_vec()],
+        (_, None) => return vec![a.to_vec()],
+        (Some(la), Some(lb)) => (la, lb),
+    };
+    let (na, nb) = (a.len() - 1, b.len() - 1);
+    let append = |last: (Compound, SelectorPart)| {
+        move |mut steps: Steps| {
+            steps.push(last.clone());
+            steps
+        }
+    };
+    match (is_descendant(&la.1), is_descendant(&lb.1)) {
+        (true, true) => weave_descendants(a, b),
+        (false, true) => weave_parents(&a[..na], b)
+            .into_iter()
+            .map(append(la.clone()))
+            .collect(),
+        (true, false) => weave_parents(a, &b[..nb])
+            .into_iter()
+            .map(append(lb.clone()))
+            .collect(),
+        (false, false) => {
+            if la.1 != lb.1 {
+                return vec![];
+            }
+            match la.0.unify(lb.0.clone()) {
+                Some(unified) => weave_parents(&a[..na], &b[..nb])
+                    .into_iter()
+                    .map(append((unified, la.1.clone())))
+                    .collect(),
+                None => vec![],
+            }
+        }
+    }
+}
+
+/// Merge two leading combinators, or `None` if they conflict.
+fn merge_leading(
+    a: &Option<SelectorPart>,
+    b: &Option<SelectorPart>,
+) -> Option<Option<SelectorPart>> {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => None,
+        (Some(a), _) => Some(Some(a.clone())),
+        (None, b) => Some(b.clone()),
+    }
+}
+
+/// All combinations of one option for each choice, with the first
+/// choice varying fastest.
+fn paths<T: Clone>(choices: &[Vec<T>]) -> Vec<Vec<T>> {
+    choices.iter().fold(vec![vec![]], |paths, options| {
+        options
+            .iter()
+            .flat_map(|option| {
+                paths.iter().map(move |path| {
+                    let mut path = path.clone();
+                    path.push(option.clone());
+                    path
+                })
+            })
+            .collect()
+    })
+}
+
+fn is_descendant(combinator: &SelectorPart) -> bool {
+    *combinator == SelectorPart::Descendant
+}
+
+/// Weave parents where both end with a descendant combinator.
+///
+/// The parents are grouped by descendant combinators, and groups that
+/// are common to both (in the same order) are kept only once.  Groups
+/// between the common groups are interleaved in both possible orders.
+fn weave_descendants(
+    a: &[(Compound, SelectorPart)],
+    b: &[(Compound, SelectorPart)],
+) -> Vec<Steps> {
+    let ga = group_descendants(a);
+    let gb = group_descendants(b);
+    let common = lcs(&ga, &gb);
+    let mut result: Vec<Steps> = vec![vec![]];
+    let (mut ia, mut ib) = (0, 0);
+    for (ca, cb) in common.into_iter().chain(Some((ga.len(), gb.len()))) {
+        let gap_a = ga[ia..ca].concat();
+        let gap_b = gb[ib..cb].concat();
+        let mut options = if gap_a.is_empty() || gap_b.is_empty() {
+            vec![[gap_a, gap_b].concat()]
+        } else {
+            vec![
+                [gap_a.clone(), gap_b.clone()].concat(),
+                [gap_b, gap_a].concat(),
+            ]
+        };
+        if let Some(group) = ga.get(ca) {
+            for option in &mut options {
+                option.extend(group.iter().cloned());
+            }
+        }
+        result = options
+            .iter()
+            .flat_map(|option| {
+                result.iter().map(move |prefix| {
+                    let mut steps = prefix.clone();
+                    steps.extend(option.iter().cloned());
+                    steps
+                })
+            })
+            .collect();
+        ia = ca + 1;
+        ib = cb + 1;
+    }
+    result
+}
+
+fn group_descendants(steps: &[(Compound, SelectorPart)]) -> Vec<Steps> {
+    let mut result = Vec::new();
+    let mut current = Vec::new();
+    for step in steps {
+        current.push(step.clone());
+        if is_descendant(&step.1) {
+            result.push(current);
+            current = Vec::new();
+        }
+    }
+    if !current.is_empty() {
+        result.push(current);
+    }
+    result
+}
+
+/// Find the longest common subsequence of `a` and `b`.
+///
+/// Returns pairs of indexes into `a` and `b` of the common items.
+fn lcs<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let mut len = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            len[i][j] = if a[i] == b[j] {
+                len[i + 1][j + 1] + 1
+            } else {
+                len[i + 1][j].max(len[i][j + 1])
+            };
+        }
+    }
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            result.push((i, j));
+            i += 1;
+            j += 1;
+        } else if len[i + 1][j] >= len[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::selectors::selectors;
+
+    fn extend(rule: &str, extender: &str, target: &str) -> String {
+        let ext =
+            Extension::create(&sel(extender), &sel(target), false, None)
+                .unwrap();
+        Extender::new(vec![ext])
+            .extend(&sel(rule), None, 0)
+            .map(|s| s.to_string())
+            .unwrap_or_default()
+    }
+
+    fn sel(s: &str) -> Selectors {
+        selectors(format!("{} ", s).as_bytes()).unwrap().1
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(extend(".foo", ".bar", ".foo"), ".foo, .bar")
+    }
+    #[test]
+    fn compound() {
+        assert_eq!(
+            extend("a.foo:hover", ".bar", ".foo"),
+            "a.foo:hover, a.bar:hover"
+        )
+    }
+    #[test]
+    fn conflicting_types() {
+        assert_eq!(extend("a.foo", "b", ".foo"), "a.foo")
+    }
+    #[test]
+    fn common_parent() {
+        assert_eq!(extend(".p .foo", ".p .bar", ".foo"), ".p .foo, .p .bar")
+    }
+    #[test]
+    fn every_matching_compound() {
+        assert_eq!(
+            extend(".a + .a", ".c", ".a"),
+            ".a + .a, .c + .a, .a + .c, .c + .c"
+        );
+        assert_eq!(extend(".a .a", ".c", ".a"), ".a .a, .c .a, .a .c, .c .c")
+    }
+    #[test]
+    fn weave_parents() {
+        assert_eq!(
+            extend(".a .foo", ".b .bar", ".foo"),
+            ".a .foo, .a .b .bar, .b .a .bar",
+        )
+    }
+    #[test]
+    fn child_combinator() {
+        assert_eq!(
+            extend(".a > .foo", ".b .bar", ".foo"),
+            ".a > .foo, .b .a > .bar",
+        )
+    }
+    #[test]
+    fn placeholder() {
+        assert_eq!(extend("%foo", ".bar", "%foo"), ".bar")
+    }
+    #[test]
+    fn placeholder_only() {
+        assert_eq!(extend("%foo", ".bar", "%baz"), "")
+    }
+
+    #[test]
+    fn unify_weave() {
+        assert_eq!(
+            unify_selectors(&sel(".c .d"), &sel(".e .f"))
+                .unwrap()
+                .to_string(),
+            ".c .e .d.f, .e .c .d.f",
+        )
+    }
+    #[test]
+    fn unify_conflict() {
+        assert_eq!(unify_selectors(&sel("a"), &sel("h1")), None)
+    }
+    #[test]
+    fn superselector_descendant() {
+        assert!(is_superselector(&sel("a"), &sel("sidebar a")));
+        assert!(!is_superselector(&sel("sidebar a"), &sel("a")));
+    }
+    #[test]
+    fn extend_in_not() {
+        assert_eq!(extend(".a:not(.b)", ".c", ".b"), ".a:not(.b):not(.c)")
+    }
+    #[test]
+    fn extend_in_is() {
+        assert_eq!(extend(":is(.a, .b) x", ".c", ".b"), ":is(.a, .b, .c) x")
+    }
+    #[test]
+    fn extend_in_not_drops_complex() {
+        assert_eq!(extend(".a:not(.b)", ".c .d", ".b"), ".a:not(.b)")
+    }
+    #[test]
+    fn superselector_is() {
+        assert!(is_superselector(&sel(":is(.a, .b)"), &sel(".a")));
+        assert!(!is_superselector(&sel(":is(.a, .b)"), &sel(".c")));
+        assert!(is_superselector(&sel(".a"), &sel(":is(.a.b, .a.c)")));
+    }
+    #[test]
+    fn superselector_not() {
+        assert!(is_superselector(&sel(":not(a)"), &sel("b")));
+        assert!(!is_superselector(&sel(":not(a)"), &sel(".b")));
+        assert!(is_superselector(&sel(":not(.a.b)"), &sel(":not(.a)")));
+    }
+    #[test]
+    fn replace() {
+        let result =
+            extend_selectors(&sel("c d"), &sel("d"), &sel("e f"), true);
+        assert_eq!(result.unwrap().to_string(), "c e f, e c f")
+    }
+}
@@ -1,4 +1,9 @@
+use crate::importer::{FsImporter, Importer};
+use crate::sass::Item;
+use crate::{parser, Error};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 /// A file context specifies where to find files to load.
 ///
@@ -6,6 +11,20 @@ use std::path::{Path, PathBuf};
 /// created, to find further included files relative to the file they
 /// are inlcuded from.
 ///
+/// A file to load is searched for in the directory of the file that
+/// loads it, then in the directories of the files that loaded that
+/// file, then in each load path added by [`push_path`](Self::push_path),
+/// in the order they were added, and finally in the current directory.
+/// In each directory, a name is tried as given, as a partial (with a
+/// leading underscore), with `.scss` or `.sass` extension, and as
+/// an `index` file in a directory with the name.
+///
+/// Files are found and loaded by an [`Importer`], by default from the
+/// file system.
+/// Names with a url scheme, such as `pkg:some/lib`, are found by an
+/// importer registered for that scheme by
+/// [`add_scheme`](Self::add_scheme), without searching the directories.
+///
 /// # Example
 /// ```
 /// use rsass::FileContext;
@@ -22,7 +41,16 @@ use std::path::{Path, PathBuf};
 /// ```
 #[derive(Clone, Debug)]
 pub struct FileContext {
+    /// The directories of the loading files, innermost first, and
+    /// last the current directory.
     path: Vec<PathBuf>,
+    /// Load paths, searched before the current directory.
+    load_paths: Vec<PathBuf>,
+    importer: Arc<dyn Importer>,
+    /// Importers for url schemes, by scheme name.
+    schemes: BTreeMap<String, Arc<dyn Importer>>,
+    /// All files found through this context or contexts derived from it.
+    loaded: Arc<Mutex<BTreeSet<PathBuf>>>,
 }
 
 impl FileContext {
@@ -30,13 +58,57 @@ impl FileContext {
     ///
     /// Files will be resolved from the current working directory.
     pub fn new() -> Self {
+        FileContext::with_importer(FsImporter::new())
+    }
+
+    /// Create a new FileContext, finding files with `importer`.
+    pub fn with_importer(importer: impl Importer + 'static) -> Self {
         FileContext {
             path: vec![PathBuf::new()],
+            load_paths: Vec::new(),
+            importer: Arc::new(importer),
+            schemes: BTreeMap::new(),
+            loaded: Default::default(),
         }
     }
 
+    /// Use `importer` for names starting with `scheme` and a colon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rsass::{output::Format, FileContext, GlobalScope, MemoryImporter};
+    ///
+    /// let mut pkg = MemoryImporter::new();
+    /// pkg.add("theme/_index.scss", "$color: green;");
+    /// let mut context = FileContext::new();
+    /// context.add_scheme("pkg", pkg);
+    /// let items = rsass::parse_scss_data(
+    ///     b"@use 'pkg:theme';\na { b: theme.$color }",
+    /// ).unwrap();
+    /// let format = Format::default();
+    /// let css = format.write_root(
+    ///     &items,
+    ///     &mut GlobalScope::new(format),
+    ///     &context,
+    /// ).unwrap();
+    /// assert_eq!(css, b"a {\n  b: green;\n}\n");
+    /// ```
+    pub fn add_scheme(
+        &mut self,
+        scheme: &str,
+        importer: impl Importer + 'static,
+    ) {
+        self.schemes.insert(scheme.into(), Arc::new(importer));
+    }
+
+    /// Add a load path to search for files.
+    ///
+    /// Load paths are searched after the directory of the loading
+    /// files, in the order they are added, but before the current
+    /// directory.
     pub fn push_path(&mut self, path: &Path) {
-        self.path.push(path.into());
+        self.load_paths.push(path.into());
     }
 
     /// Get a file from this context.
@@ -49,41 +121,198 @@ impl FileContext {
             path.push(PathBuf::from(dir));
         }
         path.extend_from_slice(&self.path);
-        (FileContext { path }, t)
+        (self.with_path(path), t)
+    }
+
+    /// Get the files found by `find_file` on this context, or any
+    /// context derived from it.
+    ///
+    /// These are the files that a compiled document depends on, e.g.
+    /// by `@import` or `@use`.
+    ///
+    /// # Example
+    /// ```
+    /// use rsass::{output::Format, parse_file, FileContext, GlobalScope};
+    /// use std::path::PathBuf;
+    ///
+    /// let base = FileContext::new();
+    /// let (context, file) =
+    ///     base.file("tests/basic/14_imports/input.scss".as_ref());
+    /// let items = parse_file(&file).unwrap();
+    /// let format = Format::default();
+    /// format
+    ///     .write_root(&items, &mut GlobalScope::new(format), &context)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     base.loaded_files(),
+    ///     [
+    ///         "tests/basic/14_imports/a.scss",
+    ///         "tests/basic/14_imports/b.scss",
+    ///         "tests/basic/14_imports/d.scss",
+    ///         "tests/basic/14_imports/sub/c.scss",
+    ///     ]
+    ///     .iter()
+    ///     .map(PathBuf::from)
+    ///     .collect::<Vec<_>>(),
+    /// );
+    /// ```
+    pub fn loaded_files(&self) -> Vec<PathBuf> {
+        self.loaded
+            .lock()
+            .map(|loaded| loaded.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get an error for failing to find `name` in this context.
+    ///
+    /// The error message describes where the file was searched for.
+    pub fn not_found(&self, name: &str) -> Error {
+        let mut dirs = Vec::new();
+        for dir in self.search_dirs() {
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        let dirs = dirs
+            .iter()
+            .map(|dir| {
+                if dir.as_os_str().is_empty() {
+                    "\n  - . (current directory)".to_string()
+                } else {
+                    format!("\n  - {}", dir.display())
+                }
+            })
+            .collect::<String>();
+        Error::S(format!(
+            "Can't find stylesheet to import.\n\
+             Looked for {:?}, as given, as a partial and as an index file, \
+             with .scss and .sass extensions, in these directories:{}",
+            name, dirs,
+        ))
     }
 
+    /// Find a file to load by `name`, as described for [`FileContext`].
+    ///
+    /// Returns the found file and a context for loading files
+    /// relative to it.
     pub fn find_file(&self, name: &Path) -> Option<(Self, PathBuf)> {
         // TODO Check docs what expansions should be tried!
-        // Files with .sass extension needs another parser.
+        let (scheme, name) = match self.scheme_of(name) {
+            Some((scheme, _, rest)) => (Some(scheme), rest),
+            None => (None, name),
+        };
         let parent = name.parent();
+        let no_base = PathBuf::new();
+        let bases = if scheme.is_some() {
+            vec![&no_base]
+        } else {
+            self.search_dirs()
+        };
         if let Some(name) = name.file_name().and_then(|n| n.to_str()) {
-            for base in &self.path {
+            for base in bases {
                 for name in &[
                     name,
                     &format!("{}.scss", name),
                     &format!("_{}.scss", name),
+                    &format!("{}.sass", name),
+                    &format!("_{}.sass", name),
                     &format!("{}/index.scss", name),
                     &format!("{}/_index.scss", name),
+                    &format!("{}/index.sass", name),
+                    &format!("{}/_index.sass", name),
                 ] {
                     let full = if let Some(parent) = parent {
                         base.join(parent).join(name)
                     } else {
                         base.join(name)
                     };
-                    if full.is_file() {
-                        let c = if let Some(parent) = parent {
-                            let mut path = vec![];
-                            path.push(PathBuf::from(parent));
-                            path.extend_from_slice(&self.path);
-                            FileContext { path }
-                        } else {
-                            self.clone()
-                        };
-                        return Some((c, full));
+                    let full = match scheme {
+                        Some(scheme) => {
+                            format!("{}:{}", scheme, full.display()).into()
+                        }
+                        None => full,
+                    };
+                    if let Some(full) = self.canonicalize(&full) {
+                        let mut path = vec![];
+                        match self.scheme_of(&full) {
+                            Some((scheme, _, rest)) => {
+                                let dir = rest.parent().unwrap_or(rest);
+                                path.push(
+                                    format!("{}:{}", scheme, dir.display())
+                                        .into(),
+                                );
+                            }
+                            None => {
+                                if let Some(dir) = full.parent() {
+                                    path.push(PathBuf::from(dir));
+                                }
+                            }
+                        }
+                        path.extend_from_slice(&self.path);
+                        if let Ok(mut loaded) = self.loaded.lock() {
+                            loaded.insert(full.clone());
+                        }
+                        return Some((self.with_path(path), full));
                     }
                 }
             }
         }
         None
     }
+
+    /// Get the directories to search for files, in order.
+    fn search_dirs(&self) -> Vec<&PathBuf> {
+        let (cwd, loading) = self.path.split_last().unwrap();
+        let mut dirs = loading.iter().collect::<Vec<_>>();
+        dirs.extend(&self.load_paths);
+        dirs.push(cwd);
+        dirs
+    }
+
+    /// Parse a file found by this context.
+    ///
+    /// Files named `*.sass` are parsed in the indented syntax, all
+    /// other files are parsed as scss.
+    pub fn parse(&self, file: &Path) -> Result<Vec<Item>, Error> {
+        let data = match self.scheme_of(file) {
+            Some((_, importer, rest)) => importer.load(rest)?,
+            None => self.importer.load(file)?,
+        };
+        parser::parse_loaded(file, data)
+    }
+
+    fn canonicalize(&self, path: &Path) -> Option<PathBuf> {
+        match self.scheme_of(path) {
+            Some((scheme, importer, rest)) => {
+                let found = importer.canonicalize(rest)?;
+                Some(format!("{}:{}", scheme, found.display()).into())
+            }
+            None => self.importer.canonicalize(path),
+        }
+    }
+
+    /// Get the url scheme of `path` and its importer, if any, and the
+    /// rest of the path.
+    fn scheme_of<'a>(
+        &self,
+        path: &'a Path,
+    ) -> Option<(&'a str, &dyn Importer, &'a Path)> {
+        let path = path.to_str()?;
+        let colon = path.find(':')?;
+        let importer = self.schemes.get(&path[..colon])?;
+        // A path in the top directory of a scheme may be joined as
+        // "scheme:/name".
+        let rest = path[colon + 1..].trim_start_matches('/');
+        Some((&path[..colon], importer.as_ref(), Path::new(rest)))
+    }
+
+    fn with_path(&self, path: Vec<PathBuf>) -> Self {
+        FileContext {
+            path,
+            load_paths: self.load_paths.clone(),
+            importer: self.importer.clone(),
+            schemes: self.schemes.clone(),
+            loaded: self.loaded.clone(),
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use super::colors_space::legacy_only;
 use super::{make_call, Error, SassFunction};
 use crate::css::Value;
 use crate::value::{Number, Unit};
@@ -7,48 +8,21 @@ use num_traits::{One, Zero};
 use std::collections::BTreeMap;
 
 pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
-    def!(f, hsl(hue, saturation, lightness), |s| {
-        let hue = s.get("hue")?;
-        let sat = s.get("saturation")?;
-        let lig = s.get("lightness")?;
-        if let (Ok(hue), Ok(sat), Ok(lig)) = (
-            to_rational(&hue),
-            to_rational_percent(&sat),
-            to_rational_percent(&lig),
-        ) {
-            Ok(Value::hsla(hue, sat, lig, Rational::one()))
-        } else {
-            Ok(make_call("hsl", vec![hue, sat, lig]))
-        }
-    });
-    def!(f, hsla(hue, saturation, lightness, alpha), |s| {
-        let hue = s.get("hue")?;
-        let sat = s.get("saturation")?;
-        let lig = s.get("lightness")?;
-        let a = s.get("alpha")?;
-        if let (Ok(hue), Ok(sat), Ok(lig), Ok(a)) = (
-            to_rational(&hue),
-            to_rational_percent(&sat),
-            to_rational_percent(&lig),
-            if a.is_null() {
-                Ok(Rational::one())
-            } else {
-                to_rational(&a)
-            },
-        ) {
-            Ok(Value::hsla(hue, sat, lig, a))
-        } else {
-            Ok(make_call("hsla", vec![hue, sat, lig, a]))
-        }
+    def!(f, hsl(hue, saturation, lightness, alpha = b"null"), |s| {
+        hsla(s, "hsl")
     });
+    def!(f, hsla(hue, saturation, lightness, alpha), |s| hsla(
+        s, "hsla"
+    ));
     def!(f, adjust_hue(color, degrees), |s: &dyn Scope| match (
         s.get("color")?,
         s.get("degrees")?,
     ) {
         (c @ Value::Color(..), Value::Null) => Ok(c),
-        (Value::Color(rgba, _), Value::Numeric(v, ..)) => {
-            let (h, s, l, alpha) = rgba.to_hsla();
-            Ok(Value::hsla(h + v.value, s, l, alpha))
+        (Value::Color(c, _), Value::Numeric(v, ..)) => {
+            let (h, s, l, alpha) =
+                legacy_only(&c, "adjust-hue", "color.adjust")?.to_hsla();
+            Ok(Value::hsla(h + v.as_ratio()?, s, l, alpha))
         }
         (c, v) => Err(Error::badargs(&["color", "number"], &[&c, &v])),
     });
@@ -56,8 +30,8 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         f,
         complement(color),
         |s: &dyn Scope| match &s.get("color")? {
-            &Value::Color(ref rgba, _) => {
-                let (h, s, l, alpha) = rgba.to_hsla();
+            &Value::Color(ref c, _) => {
+                let (h, s, l, alpha) = c.to_rgba().to_hsla();
                 Ok(Value::hsla(h + 180, s, l, alpha))
             }
             v => Err(Error::badarg("color", v)),
@@ -68,9 +42,10 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         s.get("amount")?
     ) {
         (Value::Color(c, _), Value::Null) => Ok(Value::Color(c, None)),
-        (Value::Color(rgba, _), Value::Numeric(v, u, _)) => {
-            let (h, s, l, alpha) = rgba.to_hsla();
-            let v = v.value;
+        (Value::Color(c, _), Value::Numeric(v, u, _)) => {
+            let (h, s, l, alpha) =
+                legacy_only(&c, "saturate", "color.adjust")?.to_hsla();
+            let v = v.as_ratio()?;
             let v = if u == Unit::Percent { v / 100 } else { v };
             Ok(Value::hsla(h, s + v, l, alpha))
         }
@@ -79,8 +54,9 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     def!(f, lighten(color, amount), |args: &dyn Scope| match &args
         .get("color")?
     {
-        &Value::Color(ref rgba, _) => {
-            let (h, s, l, alpha) = rgba.to_hsla();
+        &Value::Color(ref c, _) => {
+            let (h, s, l, alpha) =
+                legacy_only(c, "lighten", "color.adjust")?.to_hsla();
             let amount = to_rational_percent(&args.get("amount")?)?;
             Ok(Value::hsla(h, s, l + amount, alpha))
         }
@@ -89,8 +65,9 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     def!(f, darken(color, amount), |args: &dyn Scope| match &args
         .get("color")?
     {
-        &Value::Color(ref rgba, _) => {
-            let (h, s, l, alpha) = rgba.to_hsla();
+        &Value::Color(ref c, _) => {
+            let (h, s, l, alpha) =
+                legacy_only(c, "darken", "color.adjust")?.to_hsla();
             let amount = to_rational_percent(&args.get("amount")?)?;
             Ok(Value::hsla(h, s, l - amount, alpha))
         }
@@ -100,23 +77,26 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         f,
         hue(color),
         |args: &dyn Scope| match &args.get("color")? {
-            &Value::Color(ref rgba, _) => {
-                let (h, _s, _l, _a) = rgba.to_hsla();
-                Ok(Value::Numeric(Number::from(h), Unit::Deg, true))
+            &Value::Color(ref c, _) => {
+                let (h, _s, _l, _a) =
+                    legacy_only(c, "hue", "color.channel")?.to_hsla();
+                Ok(Value::Numeric(Number::from(h), Unit::Deg.into(), true))
             }
             v => Err(Error::badarg("color", v)),
         }
     );
     def!(f, saturation(color), |args| match &args.get("color")? {
-        &Value::Color(ref rgba, _) => {
-            let (_h, s, _l, _a) = rgba.to_hsla();
+        &Value::Color(ref c, _) => {
+            let (_h, s, _l, _a) =
+                legacy_only(c, "saturation", "color.channel")?.to_hsla();
             Ok(percentage(s))
         }
         v => Err(Error::badarg("color", v)),
     });
     def!(f, lightness(color), |args| match &args.get("color")? {
-        &Value::Color(ref rgba, _) => {
-            let (_h, _s, l, _a) = rgba.to_hsla();
+        &Value::Color(ref c, _) => {
+            let (_h, _s, l, _a) =
+                legacy_only(c, "lightness", "color.channel")?.to_hsla();
             Ok(percentage(l))
         }
         v => Err(Error::badarg("color", v)),
@@ -124,16 +104,17 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     def!(f, desaturate(color, amount), |args: &dyn Scope| match &args
         .get("color")?
     {
-        &Value::Color(ref rgba, _) => {
-            let (h, s, l, alpha) = rgba.to_hsla();
+        &Value::Color(ref c, _) => {
+            let (h, s, l, alpha) =
+                legacy_only(c, "desaturate", "color.adjust")?.to_hsla();
             let amount = to_rational_percent(&args.get("amount")?)?;
             Ok(Value::hsla(h, s - amount, l, alpha))
         }
         v => Err(Error::badarg("color", v)),
     });
     def!(f, grayscale(color), |args| match args.get("color")? {
-        Value::Color(ref rgba, _) => {
-            let (h, _s, l, alpha) = rgba.to_hsla();
+        Value::Color(ref c, _) => {
+            let (h, _s, l, alpha) = c.to_rgba().to_hsla();
             Ok(Value::hsla(h, Zero::zero(), l, alpha))
         }
         v => Ok(make_call("grayscale", vec![v])),
@@ -141,24 +122,53 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
 }
 
 fn percentage(v: Rational) -> Value {
-    Value::Numeric(Number::from(v * 100), Unit::Percent, true)
+    Value::Numeric(Number::from(v * 100), Unit::Percent.into(), true)
 }
 
 fn to_rational(v: &Value) -> Result<Rational, Error> {
     match v {
-        Value::Numeric(v, ..) => Ok(v.value),
+        Value::Numeric(v, ..) => Ok(v.as_ratio()?),
         v => Err(Error::badarg("number", v)),
     }
 }
 
 /// Gets a percentage as a fraction 0 .. 1.
+/// The implementation of `hsl` and `hsla`, named `name`.
+///
+/// If any argument is not a number (e.g. a css `var()`), the result is
+/// a plain css function call.
+fn hsla(s: &dyn Scope, name: &str) -> Result<Value, Error> {
+    let hue = s.get("hue")?;
+    let sat = s.get("saturation")?;
+    let lig = s.get("lightness")?;
+    let a = s.get("alpha")?;
+    if let (Ok(hue), Ok(sat), Ok(lig), Ok(a)) = (
+        to_rational(&hue),
+        to_rational_percent(&sat),
+        to_rational_percent(&lig),
+        if a.is_null() {
+            Ok(Rational::one())
+        } else {
+            to_rational(&a)
+        },
+    ) {
+        Ok(Value::hsla(hue, sat, lig, a))
+    } else {
+        let mut args = vec![hue, sat, lig];
+        if !a.is_null() {
+            args.push(a);
+        }
+        Ok(make_call(name, args))
+    }
+}
+
 /// If v is not a percentage, keep it as it is.
 fn to_rational_percent(v: &Value) -> Result<Rational, Error> {
     match v {
         Value::Null => Ok(Rational::zero()),
-        Value::Numeric(v, Unit::Percent, _) => Ok(v.value / 100),
+        Value::Numeric(v, u, _) if u.is_percent() => Ok(v.as_ratio()? / 100),
         Value::Numeric(v, ..) => {
-            let v = v.value;
+            let v = v.as_ratio()?;
             Ok(if v <= Rational::one() { v } else { v / 100 })
         }
         v => Err(Error::badarg("number", &v)),
@@ -1,6 +1,6 @@
 use super::{make_call, Error, SassFunction};
 use crate::css::Value;
-use crate::value::{Quotes, Unit};
+use crate::value::Quotes;
 use crate::variablescope::Scope;
 use num_rational::Rational;
 use num_traits::{One, Signed, Zero};
@@ -13,7 +13,8 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             color, red, green, blue, hue, saturation, lightness, alpha
         ),
         |s: &dyn Scope| match &s.get("color")? {
-            &Value::Color(ref rgba, _) => {
+            &Value::Color(ref color, _) => {
+                let rgba = color.to_rgba();
                 let c_add = |orig: Rational, name: &str| match s.get(name)? {
                     Value::Null => Ok(orig),
                     x => to_rational(x).map(|x| orig + x),
@@ -51,7 +52,8 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             color, red, green, blue, hue, saturation, lightness, alpha
         ),
         |s: &dyn Scope| match &s.get("color")? {
-            &Value::Color(ref rgba, _) => {
+            &Value::Color(ref color, _) => {
+                let rgba = color.to_rgba();
                 let h_adj = s.get("hue")?;
                 let s_adj = s.get("saturation")?;
                 let l_adj = s.get("lightness")?;
@@ -91,18 +93,21 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     );
 
     def!(f, opacity(color), |args| match args.get("color")? {
-        Value::Color(ref rgba, _) => Ok(Value::scalar(rgba.alpha)),
+        Value::Color(ref color, _) =>
+            Ok(Value::scalar(color.to_rgba().alpha)),
         v => Ok(make_call("opacity", vec![v])),
     });
     def!(f, alpha(color), |args| match args.get("color")? {
-        Value::Color(ref rgba, _) => Ok(Value::scalar(rgba.alpha)),
+        Value::Color(ref color, _) =>
+            Ok(Value::scalar(color.to_rgba().alpha)),
         v => Ok(make_call("alpha", vec![v])),
     });
 
     fn fade_in(color: Value, amount: Value) -> Result<Value, Error> {
         match (color, amount) {
-            (Value::Color(rgba, _), Value::Numeric(v, ..)) => {
-                let a = rgba.alpha + v.value;
+            (Value::Color(color, _), Value::Numeric(v, ..)) => {
+                let rgba = color.to_rgba();
+                let a = rgba.alpha + v.as_ratio()?;
                 Ok(Value::rgba(rgba.red, rgba.green, rgba.blue, a))
             }
             (c, v) => Err(Error::badargs(&["color", "number"], &[&c, &v])),
@@ -113,8 +118,9 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
 
     fn fade_out(color: Value, amount: Value) -> Result<Value, Error> {
         match (color, amount) {
-            (Value::Color(rgba, _), Value::Numeric(v, ..)) => {
-                let a = rgba.alpha - v.value;
+            (Value::Color(color, _), Value::Numeric(v, ..)) => {
+                let rgba = color.to_rgba();
+                let a = rgba.alpha - v.as_ratio()?;
                 Ok(Value::rgba(rgba.red, rgba.green, rgba.blue, a))
             }
             (c, v) => Err(Error::badargs(&["color", "number"], &[&c, &v])),
@@ -129,7 +135,8 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             color, red, green, blue, hue, saturation, lightness, alpha
         ),
         |s: &dyn Scope| match s.get("color")? {
-            Value::Color(rgba, _) => {
+            Value::Color(color, _) => {
+                let rgba = color.to_rgba();
                 let h_adj = s.get("hue")?;
                 let s_adj = s.get("saturation")?;
                 let l_adj = s.get("lightness")?;
@@ -167,8 +174,8 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         }
     );
     def!(f, ie_hex_str(color), |s| match s.get("color")? {
-        Value::Color(rgba, _) => {
-            let (r, g, b, a) = rgba.to_bytes();
+        Value::Color(color, _) => {
+            let (r, g, b, a) = color.to_rgba().to_bytes();
             Ok(Value::Literal(
                 format!("#{:02X}{:02X}{:02X}{:02X}", a, r, g, b),
                 Quotes::None,
@@ -180,7 +187,7 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
 
 fn to_rational(v: Value) -> Result<Rational, Error> {
     match v {
-        Value::Numeric(v, ..) => Ok(v.value),
+        Value::Numeric(v, ..) => Ok(v.as_ratio()?),
         v => Err(Error::badarg("number", &v)),
     }
 }
@@ -193,12 +200,15 @@ fn to_rational(v: Value) -> Result<Rational, Error> {
 fn to_rational_percent(v: Value) -> Result<Rational, Error> {
     match v {
         Value::Null => Ok(Rational::zero()),
-        Value::Numeric(v, Unit::Percent, _) => Ok(v.value / 100),
-        Value::Numeric(v, ..) => Ok(if v.value.abs() < Rational::one() {
-            v.value
-        } else {
-            v.value / 100
-        }),
+        Value::Numeric(v, u, _) if u.is_percent() => Ok(v.as_ratio()? / 100),
+        Value::Numeric(v, ..) => {
+            let v = v.as_ratio()?;
+            Ok(if v.abs() < Rational::one() {
+                v
+            } else {
+                v / 100
+            })
+        }
         v => Err(Error::badarg("number", &v)),
     }
 }
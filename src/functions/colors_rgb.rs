@@ -1,6 +1,7 @@
+use super::colors_space::{interpolation_method, legacy_only};
 use super::{make_call, Error, SassFunction};
 use crate::css::{CallArgs, Value};
-use crate::value::{Number, Quotes, Unit};
+use crate::value::{NumValue, Number, Quotes, Rgba, Unit, UnitSet};
 use crate::variablescope::Scope;
 use num_rational::Rational;
 use num_traits::{One, Zero};
@@ -10,12 +11,16 @@ fn do_rgba(fn_name: &str, s: &dyn Scope) -> Result<Value, Error> {
     let a = s.get("alpha")?;
     let red = s.get("red")?;
     let red = if red.is_null() { s.get("color")? } else { red };
-    if let Value::Color(rgba, _) = red {
+    if let Value::Color(color, _) = red {
+        let rgba = color.to_rgba();
         let a = if a.is_null() { s.get("green")? } else { a };
         match a {
-            Value::Numeric(a, ..) => {
-                Ok(Value::rgba(rgba.red, rgba.green, rgba.blue, a.value))
-            }
+            Value::Numeric(a, ..) => Ok(Value::rgba(
+                rgba.red,
+                rgba.green,
+                rgba.blue,
+                a.as_ratio()?,
+            )),
             _ => Ok(make_call(
                 fn_name,
                 vec![
@@ -79,21 +84,27 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         "rgba", s
     ));
     fn num(v: &Rational) -> Result<Value, Error> {
-        Ok(Value::Numeric(Number::from(*v), Unit::None, true))
+        Ok(Value::Numeric(Number::from(*v), UnitSet::scalar(), true))
     }
     def!(f, red(color), |s| match &s.get("color")? {
-        &Value::Color(ref rgba, _) => num(&rgba.red),
+        &Value::Color(ref color, _) => {
+            num(&legacy_only(color, "red", "color.channel")?.red)
+        }
         value => Err(Error::badarg("color", value)),
     });
     def!(f, green(color), |s| match &s.get("color")? {
-        &Value::Color(ref rgba, _) => num(&rgba.green),
+        &Value::Color(ref color, _) => {
+            num(&legacy_only(color, "green", "color.channel")?.green)
+        }
         value => Err(Error::badarg("color", value)),
     });
     def!(f, blue(color), |s| match &s.get("color")? {
-        &Value::Color(ref rgba, _) => num(&rgba.blue),
+        &Value::Color(ref color, _) => {
+            num(&legacy_only(color, "blue", "color.channel")?.blue)
+        }
         value => Err(Error::badarg("color", value)),
     });
-    def!(f, mix(color1, color2, weight = b"50%"), |s| match (
+    def!(f, mix(color1, color2, weight = b"50%", method), |s| match (
         s.get("color1")?,
         s.get("color2")?,
         s.get("weight")?,
@@ -103,27 +114,28 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             Value::Color(b, _),
             Value::Numeric(w, wu, ..),
         ) => {
-            let p = if wu == Unit::Percent {
-                w.value / 100
-            } else {
-                w.value
-            };
-            let one = Rational::one();
-            let w = p * 2 - one;
-            let wa = a.alpha - b.alpha;
-
-            let divis = w * wa + 1;
-            let w1 =
-                (if divis.is_zero() { w } else { (w + wa) / divis } + 1) / 2;
-            let w2 = one - w1;
-
-            let m_c = |c1, c2| w1 * c1 + w2 * c2;
-            Ok(Value::rgba(
-                m_c(a.red, b.red),
-                m_c(a.green, b.green),
-                m_c(a.blue, b.blue),
-                a.alpha * p + b.alpha * (one - p),
-            ))
+            let w = w.as_ratio()?;
+            let p = if wu == Unit::Percent { w / 100 } else { w };
+            match s.get("method")? {
+                Value::Null if a.is_legacy() && b.is_legacy() => {
+                    Ok(mix_legacy(&a.to_rgba(), &b.to_rgba(), p))
+                }
+                Value::Null => Err(Error::S(
+                    "$method: To use mix() with non-legacy colors, \
+                     you must provide a $method."
+                        .into(),
+                )),
+                method => {
+                    let (space, hue) = interpolation_method(&method)?;
+                    let mixed = a.to_space_color().interpolate(
+                        &b.to_space_color(),
+                        Number::from(p).to_f64(),
+                        space,
+                        hue,
+                    );
+                    Ok(Value::Color(mixed.to_space(a.space()).into(), None))
+                }
+            }
         }
         (color1, color2, weight) => Err(Error::badargs(
             &["color", "color", "number"],
@@ -134,12 +146,10 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         s.get("color")?,
         s.get("weight")?,
     ) {
-        (Value::Color(rgba, _), Value::Numeric(w, wu, ..)) => {
-            let w = if wu == Unit::Percent {
-                w.value / 100
-            } else {
-                w.value
-            };
+        (Value::Color(color, _), Value::Numeric(w, wu, ..)) => {
+            let rgba = color.to_rgba();
+            let w = w.as_ratio()?;
+            let w = if wu == Unit::Percent { w / 100 } else { w };
             let inv = |v: Rational| -(v - 255) * w + v * -(w - 1);
             Ok(Value::rgba(
                 inv(rgba.red),
@@ -149,8 +159,7 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             ))
         }
         (ref by, Value::Numeric(Number { ref value, .. }, ref wu, ..))
-            if value == &Rational::from_integer(100)
-                && wu == &Unit::Percent =>
+            if value == &NumValue::from(100) && wu == &Unit::Percent =>
         {
             Ok(make_call("invert", vec![by.clone()]))
         }
@@ -158,22 +167,45 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     });
 }
 
+/// Mix two legacy colors, `p` (0 .. 1) of `a` and the rest of `b`.
+fn mix_legacy(a: &Rgba, b: &Rgba, p: Rational) -> Value {
+    let one = Rational::one();
+    let w = p * 2 - one;
+    let wa = a.alpha - b.alpha;
+
+    let divis = w * wa + 1;
+    let w1 = (if divis.is_zero() { w } else { (w + wa) / divis } + 1) / 2;
+    let w2 = one - w1;
+
+    let m_c = |c1, c2| w1 * c1 + w2 * c2;
+    Value::rgba(
+        m_c(a.red, b.red),
+        m_c(a.green, b.green),
+        m_c(a.blue, b.blue),
+        a.alpha * p + b.alpha * (one - p),
+    )
+}
+
 fn int_value(v: Rational) -> Value {
     Value::scalar(v.to_integer())
 }
 
 fn to_int(v: &Value) -> Result<Rational, Error> {
     match v {
-        Value::Numeric(v, Unit::Percent, _) => Ok(v.value * 255 / 100),
-        Value::Numeric(v, ..) => Ok(v.value),
+        Value::Numeric(v, u, _) if u.is_percent() => {
+            Ok(v.as_ratio()? * 255 / 100)
+        }
+        Value::Numeric(v, ..) => Ok(v.as_ratio()?),
         v => Err(Error::badarg("number", &v)),
     }
 }
 
 fn to_rational(v: &Value) -> Result<Rational, Error> {
     match v {
-        Value::Numeric(num, Unit::Percent, _) => Ok(num.value / 100),
-        Value::Numeric(num, ..) => Ok(num.value),
+        Value::Numeric(num, u, _) if u.is_percent() => {
+            Ok(num.as_ratio()? / 100)
+        }
+        Value::Numeric(num, ..) => Ok(num.as_ratio()?),
         v => Err(Error::badarg("number", &v)),
     }
 }
@@ -0,0 +1,444 @@
+//! Functions for colors in the color spaces of css color level 4.
+use super::{make_call, Error, SassFunction};
+use crate::css::Value;
+use crate::value::{
+    Color, ColorSpace, HueInterpolation, ListSeparator, Number, Operator,
+    Quotes, Rgba, SpaceColor, Unit, UnitSet,
+};
+use crate::variablescope::{GlobalScope, Scope};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
+    def!(f, lab(channels), |s| space_fn(ColorSpace::Lab, s));
+    def!(f, lch(channels), |s| space_fn(ColorSpace::Lch, s));
+    def!(f, oklab(channels), |s| space_fn(ColorSpace::Oklab, s));
+    def!(f, oklch(channels), |s| space_fn(ColorSpace::Oklch, s));
+    def!(f, color(description), |s| {
+        let desc = s.get("description")?;
+        let space = match &desc {
+            Value::List(v, ListSeparator::Space, false) => v.first(),
+            _ => None,
+        };
+        let space = match space {
+            Some(Value::Literal(name, Quotes::None)) => {
+                match ColorSpace::from_name(name) {
+                    Some(space)
+                        if matches!(
+                            space,
+                            ColorSpace::Srgb
+                                | ColorSpace::SrgbLinear
+                                | ColorSpace::DisplayP3
+                                | ColorSpace::XyzD50
+                                | ColorSpace::XyzD65
+                        ) =>
+                    {
+                        space
+                    }
+                    _ => {
+                        return Err(Error::S(format!(
+                            "$description: Unknown color space \"{}\".",
+                            name
+                        )))
+                    }
+                }
+            }
+            _ => return Ok(make_call("color", vec![desc])),
+        };
+        let channels = match desc {
+            Value::List(mut v, sep, b) => {
+                v.remove(0);
+                Value::List(v, sep, b)
+            }
+            v => v,
+        };
+        match parse_channels(space, &channels)? {
+            Some(color) => Ok(color),
+            None => Ok(make_call("color", vec![desc_again(space, channels)])),
+        }
+    });
+    def!(f, hwb(hue, whiteness, blackness, alpha), |s| {
+        let hue = s.get("hue")?;
+        let whiteness = s.get("whiteness")?;
+        if whiteness.is_null() {
+            return space_fn(ColorSpace::Hwb, s);
+        }
+        let channels = Value::List(
+            vec![hue, whiteness, s.get("blackness")?],
+            ListSeparator::Space,
+            false,
+        );
+        let channels = match s.get("alpha")? {
+            Value::Null => channels,
+            alpha => slash(channels, alpha),
+        };
+        match parse_channels(ColorSpace::Hwb, &channels)? {
+            Some(color) => Ok(color),
+            None => Ok(make_call("hwb", vec![channels])),
+        }
+    });
+}
+
+/// Add the functions that are only available in the `sass:color`
+/// module.
+pub fn expose(m: &mut GlobalScope) {
+    let mut f = BTreeMap::new();
+    def!(f, space(color), |s| {
+        let color = get_color(s, "color")?;
+        Ok(Value::Literal(color.space().to_string(), Quotes::None))
+    });
+    def!(f, to_space(color, space), |s| {
+        let color = get_color(s, "color")?;
+        let space = get_space(s, "space")?;
+        Ok(Value::Color(
+            color.to_space_color().to_space(space).into(),
+            None,
+        ))
+    });
+    def!(f, channel(color, channel, space), |s| {
+        let color = get_color(s, "color")?;
+        let color = match s.get("space")? {
+            Value::Null => color.to_space_color(),
+            _ => color.to_space_color().to_space(get_space(s, "space")?),
+        };
+        let name = match s.get("channel")? {
+            Value::Literal(name, _) => name,
+            v => return Err(Error::badarg("string", &v)),
+        };
+        if name == "alpha" {
+            return Ok(Value::scalar(color.alpha()));
+        }
+        let space = color.space();
+        match space.channels().iter().position(|c| *c == name) {
+            Some(i) => Ok(channel_value(space, i, color.channels()[i])),
+            None => Err(Error::S(format!(
+                "$channel: Color {} has no channel named {}.",
+                Value::Color(color.into(), None).format(Default::default()),
+                name,
+            ))),
+        }
+    });
+    def!(f, is_in_gamut(color, space), |s| {
+        let color = get_color(s, "color")?;
+        let space = match s.get("space")? {
+            Value::Null => color.space(),
+            _ => get_space(s, "space")?,
+        };
+        Ok(Value::bool(
+            color.to_space_color().to_space(space).is_in_gamut(),
+        ))
+    });
+    def!(f, to_gamut(color, space, method), |s| {
+        let color = get_color(s, "color")?;
+        let space = match s.get("space")? {
+            Value::Null => color.space(),
+            _ => get_space(s, "space")?,
+        };
+        let in_space = color.to_space_color().to_space(space);
+        let mapped = match s.get("method")? {
+            Value::Literal(ref m, _) if m == "clip" => in_space.clip(),
+            Value::Literal(ref m, _) if m == "local-minde" => {
+                in_space.to_gamut_local_minde()
+            }
+            Value::Null => return Err(Error::S(NO_GAMUT_METHOD.into())),
+            v => {
+                return Err(Error::S(format!(
+                    "$method: Unknown gamut mapping method {}.",
+                    v.format(Default::default())
+                )))
+            }
+        };
+        Ok(Value::Color(mapped.to_space(color.space()).into(), None))
+    });
+    def!(f, whiteness(color), |s| {
+        let hwb = get_color(s, "color")?
+            .to_space_color()
+            .to_space(ColorSpace::Hwb);
+        Ok(channel_value(ColorSpace::Hwb, 1, hwb.channels()[1]))
+    });
+    def!(f, blackness(color), |s| {
+        let hwb = get_color(s, "color")?
+            .to_space_color()
+            .to_space(ColorSpace::Hwb);
+        Ok(channel_value(ColorSpace::Hwb, 2, hwb.channels()[2]))
+    });
+    for (name, func) in f {
+        m.define_function(name, func);
+    }
+}
+
+static NO_GAMUT_METHOD: &str =
+    "$method: color.to-gamut() requires a $method argument \
+     (clip or local-minde).";
+
+/// Get the color space and hue interpolation method of a `$method`
+/// argument to `mix`, like `oklch` or `lch longer hue`.
+pub fn interpolation_method(
+    method: &Value,
+) -> Result<(ColorSpace, HueInterpolation), Error> {
+    let bad = || {
+        Error::S(format!(
+            "$method: Expected a color interpolation method, got {}.",
+            method.format(Default::default())
+        ))
+    };
+    let words = match method {
+        Value::List(v, ListSeparator::Space, false) => v
+            .iter()
+            .map(|w| match w {
+                Value::Literal(w, Quotes::None) => Ok(w.as_str()),
+                _ => Err(bad()),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Value::Literal(w, Quotes::None) => vec![w.as_str()],
+        _ => return Err(bad()),
+    };
+    let space = ColorSpace::from_name(words[0]).ok_or_else(bad)?;
+    match words[1..] {
+        [] => Ok((space, HueInterpolation::Shorter)),
+        [hue, "hue"]
+            if space.is_polar()
+                || space == ColorSpace::Hsl
+                || space == ColorSpace::Hwb =>
+        {
+            Ok((space, HueInterpolation::from_name(hue).ok_or_else(bad)?))
+        }
+        _ => Err(bad()),
+    }
+}
+
+/// Create a color in `space` from the `channels` argument.
+fn space_fn(space: ColorSpace, s: &dyn Scope) -> Result<Value, Error> {
+    let arg = if space == ColorSpace::Hwb {
+        "hue"
+    } else {
+        "channels"
+    };
+    let channels = s.get(arg)?;
+    match parse_channels(space, &channels)? {
+        Some(color) => Ok(color),
+        None => Ok(make_call(&space.to_string(), vec![channels])),
+    }
+}
+
+/// Parse the channels of a color function, like `50% 40 30 / 0.5`.
+///
+/// Returns None if the channels can't be evaluated, e.g. if any of
+/// them is a `var(...)`, so the function should be kept as css.
+fn parse_channels(
+    space: ColorSpace,
+    channels: &Value,
+) -> Result<Option<Value>, Error> {
+    let mut values = match channels {
+        Value::List(v, ListSeparator::Space, false) => v.clone(),
+        v => vec![v.clone()],
+    };
+    let alpha = match values.pop() {
+        Some(Value::BinOp(a, _, Operator::Div, _, b)) => {
+            values.push(*a);
+            Some(*b)
+        }
+        Some(last) => {
+            values.push(last);
+            None
+        }
+        None => None,
+    };
+    if !values.iter().chain(alpha.iter()).all(is_number) {
+        return Ok(None);
+    }
+    if values.len() != 3 {
+        return Err(Error::S(format!(
+            "$channels: The {} color space has 3 channels but {} has {}.",
+            space,
+            channels.format(Default::default()),
+            values.len(),
+        )));
+    }
+    let mut c = [0.; 3];
+    for (i, v) in values.iter().enumerate() {
+        c[i] = channel_arg(space, i, v)?;
+    }
+    let alpha = match alpha {
+        Some(Value::Numeric(a, u, _)) if u.is_percent() => a.to_f64() / 100.,
+        Some(Value::Numeric(a, u, _)) if u.is_none() => a.to_f64(),
+        Some(a) => return Err(Error::badarg("number", &a)),
+        None => 1.,
+    };
+    let color = Color::from(SpaceColor::new(space, c, alpha));
+    Ok(Some(Value::Color(color, None)))
+}
+
+fn is_number(v: &Value) -> bool {
+    matches!(v, Value::Numeric(..))
+}
+
+/// Get the value of channel `i` in `space` from a number argument.
+fn channel_arg(space: ColorSpace, i: usize, v: &Value) -> Result<f64, Error> {
+    let name = space.channels()[i];
+    match v {
+        Value::Numeric(n, u, _) if name == "hue" => {
+            match u.scale_to(&Unit::Deg.into()) {
+                _ if u.is_none() => Ok(n.to_f64()),
+                Some(scale) => Ok((n * &scale).to_f64()),
+                None => Err(Error::S(format!(
+                    "${}: Expected {} to have an angle unit (deg, grad, \
+                     rad, turn).",
+                    name,
+                    v.format(Default::default())
+                ))),
+            }
+        }
+        Value::Numeric(n, u, _) if u.is_percent() => {
+            Ok(n.to_f64() / 100. * percent_reference(space, i))
+        }
+        Value::Numeric(n, u, _) if u.is_none() => match space {
+            // Unitless whiteness and blackness are percentages.
+            ColorSpace::Hsl | ColorSpace::Hwb => Ok(n.to_f64() / 100.),
+            _ => Ok(n.to_f64()),
+        },
+        v => Err(Error::S(format!(
+            "${}: Expected {} to have no units or \"%\".",
+            name,
+            v.format(Default::default())
+        ))),
+    }
+}
+
+/// Get channel `i` of a color in `space` as a sass value.
+fn channel_value(space: ColorSpace, i: usize, v: f64) -> Value {
+    let name = space.channels()[i];
+    let (v, unit) = if name == "hue" {
+        (v, Unit::Deg.into())
+    } else if name == "lightness"
+        || space == ColorSpace::Hsl
+        || space == ColorSpace::Hwb
+    {
+        (v * 100. / percent_reference(space, i), Unit::Percent.into())
+    } else {
+        (v, UnitSet::scalar())
+    };
+    Value::Numeric(Number::from(v), unit, true)
+}
+
+/// The channel value of `100%` for channel `i` in `space`.
+fn percent_reference(space: ColorSpace, i: usize) -> f64 {
+    match (space, i) {
+        (ColorSpace::Rgb, _) => 255.,
+        (ColorSpace::Lab, 0) | (ColorSpace::Lch, 0) => 100.,
+        (ColorSpace::Lab, _) => 125.,
+        (ColorSpace::Lch, _) => 150.,
+        (ColorSpace::Oklab, 0) | (ColorSpace::Oklch, 0) => 1.,
+        (ColorSpace::Oklab, _) | (ColorSpace::Oklch, _) => 0.4,
+        _ => 1.,
+    }
+}
+
+/// Put back the color space in a `color(...)` description.
+fn desc_again(space: ColorSpace, channels: Value) -> Value {
+    let name = Value::Literal(space.to_string(), Quotes::None);
+    match channels {
+        Value::List(mut v, sep, b) => {
+            v.insert(0, name);
+            Value::List(v, sep, b)
+        }
+        v => Value::List(vec![name, v], ListSeparator::Space, false),
+    }
+}
+
+/// Add a `/ alpha` to the last of a list of channels.
+fn slash(channels: Value, alpha: Value) -> Value {
+    match channels {
+        Value::List(mut v, sep, b) => {
+            if let Some(last) = v.pop() {
+                v.push(Value::BinOp(
+                    Box::new(last),
+                    true,
+                    Operator::Div,
+                    true,
+                    Box::new(alpha),
+                ));
+            }
+            Value::List(v, sep, b)
+        }
+        v => v,
+    }
+}
+
+/// Get `color` as rgba for the legacy function `name`.
+///
+/// The legacy functions are only defined for legacy colors, so
+/// other colors are an error, suggesting the function `instead`.
+pub(super) fn legacy_only<'a>(
+    color: &'a Color,
+    name: &str,
+    instead: &str,
+) -> Result<Cow<'a, Rgba>, Error> {
+    if color.is_legacy() {
+        Ok(color.to_rgba())
+    } else {
+        Err(Error::S(format!(
+            "$color: {}() is only supported for legacy colors. \
+             Please use {}() instead with an explicit $space argument.",
+            name, instead,
+        )))
+    }
+}
+
+fn get_color(s: &dyn Scope, name: &str) -> Result<Color, Error> {
+    match s.get(name)? {
+        Value::Color(color, _) => Ok(color),
+        v => Err(Error::badarg("color", &v)),
+    }
+}
+
+fn get_space(s: &dyn Scope, name: &str) -> Result<ColorSpace, Error> {
+    match s.get(name)? {
+        Value::Literal(ref space, _) => ColorSpace::from_name(space)
+            .ok_or_else(|| {
+                Error::S(format!(
+                    "${}: Unknown color space \"{}\".",
+                    name, space
+                ))
+            }),
+        v => Err(Error::badarg("string", &v)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::variablescope::test::do_evaluate;
+
+    #[test]
+    fn lab_round_trip() {
+        assert_eq!(
+            do_evaluate(&[], b"lab(50% 40 30 / 0.5);"),
+            "lab(50% 40 30 / 0.5)"
+        );
+    }
+    #[test]
+    fn oklch_round_trip() {
+        assert_eq!(
+            do_evaluate(&[], b"oklch(60% 0.15 50deg);"),
+            "oklch(60% 0.15 50deg)"
+        );
+    }
+    #[test]
+    fn display_p3_round_trip() {
+        assert_eq!(
+            do_evaluate(&[], b"color(display-p3 1 0.5 0);"),
+            "color(display-p3 1 0.5 0)"
+        );
+    }
+    #[test]
+    fn hwb_is_legacy() {
+        assert_eq!(do_evaluate(&[], b"hwb(120 30% 40%);"), "#4d994d");
+    }
+    #[test]
+    fn var_channel_is_kept() {
+        assert_eq!(
+            do_evaluate(&[], b"lab(var(--l) 40 30);"),
+            "lab(var(--l) 40 30)"
+        );
+    }
+}
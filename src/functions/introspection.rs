@@ -1,14 +1,17 @@
+use super::lists::plain_list;
 use super::{Error, SassFunction};
 use crate::css::{CallArgs, Value};
+use crate::ordermap::OrderMap;
 use crate::value::{ListSeparator, Quotes, Unit};
-use crate::variablescope::Scope;
+use crate::variablescope::{GlobalScope, Scope};
 use std::collections::BTreeMap;
 
 static IMPLEMENTED_FEATURES: &[&str] = &[
     // A local variable will shadow a global variable unless
     // `!global` is used.
     "global-variable-shadowing",
-    // "extend-selector-pseudoclass" - nothing with `@extend` is implemented
+    // "extend-selector-pseudoclass" - `@extend` does not yet affect
+    // selectors inside pseudoclasses like `:not(...)`.
     // Full support for unit arithmetic using units defined in the
     // [Values and Units Level 3][] spec.
     "units-level-3",
@@ -33,26 +36,39 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         }
         v => Err(Error::badarg("string", v)),
     });
-    def!(f, global_variable_exists(name), |s| match &s.get("name")? {
-        &Value::Literal(ref v, _) => {
-            Ok(Value::bool(s.get_global_or_none(v).is_some()))
-        }
-        v => Err(Error::badarg("string", v)),
-    });
-    def!(f, function_exists(name), |s| match &s.get("name")? {
-        &Value::Literal(ref v, _) => {
-            Ok(Value::bool(s.get_function(v).is_some()))
+    def!(
+        f,
+        global_variable_exists(name, module = b"null"),
+        |s| match &s.get("name")? {
+            &Value::Literal(ref v, _) =>
+                Ok(Value::bool(match opt_module(s)? {
+                    Some(module) => module.get_or_none(v).is_some(),
+                    None => s.get_global_or_none(v).is_some(),
+                })),
+            v => Err(Error::badarg("string", v)),
         }
+    );
+    def!(f, function_exists(name, module = b"null"), |s| match &s
+        .get("name")?
+    {
+        &Value::Literal(ref v, _) => Ok(Value::bool(match opt_module(s)? {
+            Some(module) => module.get_function(v).is_some(),
+            None => s.get_function(v).is_some(),
+        })),
         v => Err(Error::badarg("string", v)),
     });
     def!(
         f,
-        get_function(name, css = b"false"),
+        get_function(name, css = b"false", module = b"null"),
         |s| match s.get("name")? {
             Value::Literal(ref v, _) => {
+                let f = match opt_module(s)? {
+                    Some(module) => module.get_function(v),
+                    None => s.get_function(v),
+                };
                 if s.get("css")?.is_true() {
                     Ok(Value::Function(v.to_string(), None))
-                } else if let Some(f) = s.get_function(v) {
+                } else if let Some(f) = f {
                     Ok(Value::Function(v.to_string(), Some(f.clone())))
                 } else {
                     Err(Error::S(format!("Function {} does not exist", v)))
@@ -61,20 +77,28 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             ref v => Err(Error::badarg("string", v)),
         }
     );
-    def!(f, mixin_exists(name), |s| match &s.get("name")? {
-        &Value::Literal(ref v, _) => {
-            Ok(Value::bool(s.get_mixin(&v.replace('-', "_")).is_some()))
+    def!(
+        f,
+        mixin_exists(name, module = b"null"),
+        |s| match &s.get("name")? {
+            &Value::Literal(ref v, _) => {
+                let name = v.replace('-', "_");
+                Ok(Value::bool(match opt_module(s)? {
+                    Some(module) => module.get_mixin(&name).is_some(),
+                    None => s.get_mixin(&name).is_some(),
+                }))
+            }
+            v => Err(Error::badarg("string", v)),
         }
-        v => Err(Error::badarg("string", v)),
-    });
-    def!(f, content_exists(), |s| {
-        let content = s.get_mixin("%%BODY%%");
-        Ok(Value::bool(
-            content.map(|(_, b)| !b.is_empty()).unwrap_or(false),
-        ))
+    );
+    def!(f, content_exists(), |s| match s.get_content() {
+        Some(content) => Ok(Value::bool(content.block.is_some())),
+        None => Err(Error::S(
+            "content-exists() may only be called within a mixin.".into(),
+        )),
     });
     def!(f, inspect(value), |s| Ok(Value::Literal(
-        match s.get("value")? {
+        match plain_list(s.get("value")?) {
             Value::Null => "null".to_string(),
             Value::List(ref v, ref sep, brackets) => {
                 // TODO Try to unify this with the Display formatting?
@@ -115,6 +139,17 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         },
         Quotes::None
     )));
+    def!(f, keywords(args), |s| match s.get("args")? {
+        Value::ArgList(args) => Ok(Value::Map(
+            args.named()
+                .map(|(name, value)| {
+                    let name = name.replace('_', "-");
+                    (Value::Literal(name, Quotes::None), value.clone())
+                })
+                .collect::<OrderMap<_, _>>(),
+        )),
+        v => Err(Error::badarg("argument list", &v)),
+    });
     def!(f, type_of(value), |s| Ok(Value::Literal(
         s.get("value")?.type_name().into(),
         Quotes::None
@@ -135,12 +170,9 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             (
                 &Value::Numeric(_, ref u1, ..),
                 &Value::Numeric(_, ref u2, ..),
-            ) => {
-                // TODO e.g. cm and mm are comparable too!
-                // Simply comparig the dimension is not enough, though,
-                // as e.g em and vh is not comparable.
-                Ok(Value::bool(u1 == u2))
-            }
+            ) => Ok(Value::bool(
+                u1.is_none() || u2.is_none() || u1.scale_to(u2).is_some(),
+            )),
             (v1, v2) => Err(Error::badargs(&["number", "number"], &[v1, v2])),
         }
     });
@@ -151,6 +183,7 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             }
             Value::Literal(ref name, _) => {
                 dep_warn!(
+                    s,
                     "Passing a string to call() is deprecated and \
                      will be illegal"
                 );
@@ -167,6 +200,66 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     });
 }
 
+/// Add the functions that are only available in the `sass:meta` module.
+pub fn expose(m: &mut GlobalScope) {
+    let mut f = BTreeMap::new();
+    def!(f, module_functions(module), |s| {
+        let module = get_module(s, s.get("module")?)?;
+        Ok(Value::Map(
+            module
+                .exported_functions()
+                .into_iter()
+                .map(|(name, func)| {
+                    let name = name.replace('_', "-");
+                    (
+                        Value::Literal(name.clone(), Quotes::Double),
+                        Value::Function(name, Some(func)),
+                    )
+                })
+                .collect(),
+        ))
+    });
+    def!(f, module_variables(module), |s| {
+        let module = get_module(s, s.get("module")?)?;
+        Ok(Value::Map(
+            module
+                .exported_variables()
+                .into_iter()
+                .map(|(name, value)| {
+                    let name = name.replace('_', "-");
+                    (Value::Literal(name, Quotes::Double), value)
+                })
+                .collect(),
+        ))
+    });
+    for (name, func) in f {
+        m.define_function(name, func);
+    }
+}
+
+/// Get the module used with the namespace `name` in the scope `s`.
+/// Get the module named by the `module` argument, unless it is null.
+fn opt_module(s: &dyn Scope) -> Result<Option<&GlobalScope>, Error> {
+    match s.get("module")? {
+        Value::Null => Ok(None),
+        m => get_module(s, m).map(Some),
+    }
+}
+
+fn get_module(s: &dyn Scope, name: Value) -> Result<&GlobalScope, Error> {
+    match name {
+        Value::Literal(ref name, _) => {
+            s.get_global_scope().get_module(name).ok_or_else(|| {
+                Error::S(format!(
+                    "There is no module with namespace \"{}\".",
+                    name
+                ))
+            })
+        }
+        ref v => Err(Error::badarg("string", v)),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::super::variablescope::test::do_evaluate;
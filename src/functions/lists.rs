@@ -4,7 +4,7 @@ use crate::value::{ListSeparator, Quotes};
 use std::collections::BTreeMap;
 
 pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
-    def!(f, length(list), |s| match s.get("list")? {
+    def!(f, length(list), |s| match plain_list(s.get("list")?) {
         Value::List(v, _, _) => Ok(Value::scalar(v.len() as isize)),
         Value::Map(m) => Ok(Value::scalar(m.len() as isize)),
         // A null value is considered eqivalent to an empty list
@@ -14,7 +14,7 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     });
     def!(f, nth(list, n), |s| {
         let n = s.get("n")?.integer_value()?;
-        match s.get("list")? {
+        match plain_list(s.get("list")?) {
             Value::List(list, _, _) => {
                 Ok(list[list_index(n, &list)?].clone())
             }
@@ -91,52 +91,59 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         list.push(s.get("val")?);
         Ok(Value::List(list, sep, bra))
     });
-    def_va!(f, zip(lists), |s| match s.get("lists")? {
-        Value::List(v, _, _) => {
-            let lists =
-                v.into_iter().map(|v| v.iter_items()).collect::<Vec<_>>();
-            let len = lists.iter().map(|v| v.len()).min().unwrap_or(0);
-            let result = (0..len)
-                .map(|i| {
-                    let items = lists.iter().map(|v| v[i].clone()).collect();
-                    Value::List(items, ListSeparator::Space, false)
-                })
-                .collect();
-            Ok(Value::List(result, ListSeparator::Comma, false))
-        }
-        v => Err(Error::badarg("list", &v)),
+    def_va!(f, zip(lists), |s| {
+        let lists = s
+            .get("lists")?
+            .iter_items()
+            .into_iter()
+            .map(|v| v.iter_items())
+            .collect::<Vec<_>>();
+        let len = lists.iter().map(|v| v.len()).min().unwrap_or(0);
+        let result = (0..len)
+            .map(|i| {
+                let items = lists.iter().map(|v| v[i].clone()).collect();
+                Value::List(items, ListSeparator::Space, false)
+            })
+            .collect();
+        Ok(Value::List(result, ListSeparator::Comma, false))
     });
-    def!(f, index(list, value), |s| match s.get("list")? {
-        Value::List(v, _, _) => {
-            let value = s.get("value")?;
-            for (i, v) in v.iter().enumerate() {
-                if v == &value {
-                    return Ok(Value::scalar(i as isize + 1));
-                }
-            }
-            Ok(Value::Null)
-        }
-        Value::Map(map) => match s.get("value")? {
-            Value::List(ref l, ListSeparator::Space, _) if l.len() == 2 => {
-                for (i, &(ref k, ref v)) in map.iter().enumerate() {
-                    if *k == l[0] && *v == l[1] {
+    def!(
+        f,
+        index(list, value),
+        |s| match plain_list(s.get("list")?) {
+            Value::List(v, _, _) => {
+                let value = s.get("value")?;
+                for (i, v) in v.iter().enumerate() {
+                    if v == &value {
                         return Ok(Value::scalar(i as isize + 1));
                     }
                 }
                 Ok(Value::Null)
             }
-            _ => Ok(Value::Null),
-        },
-        v => {
-            if v == s.get("value")? {
-                Ok(Value::scalar(1))
-            } else {
-                Ok(Value::Null)
+            Value::Map(map) => match s.get("value")? {
+                Value::List(ref l, ListSeparator::Space, _)
+                    if l.len() == 2 =>
+                {
+                    for (i, &(ref k, ref v)) in map.iter().enumerate() {
+                        if *k == l[0] && *v == l[1] {
+                            return Ok(Value::scalar(i as isize + 1));
+                        }
+                    }
+                    Ok(Value::Null)
+                }
+                _ => Ok(Value::Null),
+            },
+            v => {
+                if v == s.get("value")? {
+                    Ok(Value::scalar(1))
+                } else {
+                    Ok(Value::Null)
+                }
             }
         }
-    });
+    );
     def!(f, list_separator(list), |s| Ok(Value::Literal(
-        match s.get("list")? {
+        match plain_list(s.get("list")?) {
             Value::List(_, ListSeparator::Comma, _) => "comma",
             Value::Map(_) => "comma",
             _ => "space",
@@ -150,8 +157,18 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     }));
 }
 
-fn get_list(value: Value) -> (Vec<Value>, Option<ListSeparator>, bool) {
+/// An argument list is treated as a comma separated list.
+pub(crate) fn plain_list(value: Value) -> Value {
     match value {
+        Value::ArgList(args) => {
+            Value::List(args.positional(), ListSeparator::Comma, false)
+        }
+        v => v,
+    }
+}
+
+fn get_list(value: Value) -> (Vec<Value>, Option<ListSeparator>, bool) {
+    match plain_list(value) {
         Value::List(v, s, bra) => {
             let sep = if v.is_empty() && s == ListSeparator::Space {
                 None
@@ -50,16 +50,13 @@ macro_rules! func2 {
 }
 
 macro_rules! dep_warn {
-    ($first: expr, $($arg:expr),*) => {{
-        use std::sync::Once;
-        static WARN: Once = Once::new();
-        WARN.call_once(|| {
-            eprintln!(concat!("DEPRECATION WARNING: ", $first), $($arg),*);
-        });
+    ($scope: expr, $first: expr, $($arg:expr),*) => {{
+        $scope.get_logger().deprecation(
+            &format!($first, $($arg),*),
+            &crate::logger::trace(),
+        );
     }};
-    ($first: expr) => {{
-        use std::sync::Once;
-        static WARN: Once = Once::new();
-        WARN.call_once(|| eprintln!(concat!("DEPRECATION WARNING: ", $first)));
+    ($scope: expr, $first: expr) => {{
+        $scope.get_logger().deprecation($first, &crate::logger::trace());
     }}
 }
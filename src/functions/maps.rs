@@ -1,33 +1,38 @@
 use super::{Error, SassFunction};
-use crate::css::Value;
+use crate::css::{CallArgs, Value};
 use crate::ordermap::OrderMap;
 use crate::value::ListSeparator;
+use crate::variablescope::{GlobalScope, Scope};
 use std::collections::BTreeMap;
 
 pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
-    def!(f, map_get(map, key), |s| Ok(get_map(s.get("map")?)?
-        .get(&s.get("key")?)
-        .cloned()
-        .unwrap_or(Value::Null)));
-    def!(f, map_merge(map1, map2), |s| {
-        let mut map1 = get_map(s.get("map1")?)?;
-        let map2 = get_map(s.get("map2")?)?;
-        for (key, value) in map2 {
-            map1.insert(key, value);
-        }
-        Ok(Value::Map(map1))
+    def_va!(f, map_get(map, key, keys), |s| {
+        let map = get_map(s.get("map")?)?;
+        Ok(get_nested(map, &key_path(s)?).unwrap_or(Value::Null))
+    });
+    def_va!(f, map_merge(map1, args), |s| {
+        let map1 = get_map(s.get("map1")?)?;
+        let args = CallArgs::from_value(s.get("args")?);
+        let mut keys = args.positional();
+        let map2 = match args.named().find(|(name, _)| *name == "map2") {
+            Some((_, map2)) => map2.clone(),
+            None => keys.pop().ok_or_else(|| {
+                Error::S("Expected $args to contain a map.".into())
+            })?,
+        };
+        let map2 = get_map(map2)?;
+        let merged = set_nested(map1, &keys, |mut map| {
+            for (key, value) in map2 {
+                map.insert(key, value);
+            }
+            map
+        });
+        Ok(Value::Map(merged))
     });
     def_va!(f, map_remove(map, keys), |s| {
         let mut map = get_map(s.get("map")?)?;
-        match s.get("keys")? {
-            Value::List(keys, ..) => {
-                for key in keys {
-                    map.remove(&key);
-                }
-            }
-            key => {
-                map.remove(&key);
-            }
+        for key in s.get("keys")?.iter_items() {
+            map.remove(&key);
         }
         Ok(Value::Map(map))
     });
@@ -39,12 +44,64 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         let map = get_map(s.get("map")?)?;
         Ok(Value::List(map.values(), ListSeparator::Comma, false))
     });
-    def!(f, map_has_key(map, key), |s| {
+    def_va!(f, map_has_key(map, key, keys), |s| {
         let map = get_map(s.get("map")?)?;
-        Ok(Value::bool(map.contains_key(&s.get("key")?)))
+        let mut keys = key_path(s)?;
+        let last = keys.pop().unwrap_or(Value::Null);
+        Ok(Value::bool(match get_nested(map, &keys) {
+            Some(inner) => get_map(inner)
+                .map(|inner| inner.contains_key(&last))
+                .unwrap_or(false),
+            None => false,
+        }))
     });
 }
 
+/// Add the functions that are only available in the `sass:map` module.
+pub fn expose(m: &mut GlobalScope) {
+    let mut f = BTreeMap::new();
+    def!(f, deep_merge(map1, map2), |s| {
+        let map1 = get_map(s.get("map1")?)?;
+        let map2 = get_map(s.get("map2")?)?;
+        Ok(Value::Map(deep_merge(map1, map2)))
+    });
+    def_va!(f, deep_remove(map, key, keys), |s| {
+        let map = get_map(s.get("map")?)?;
+        let mut keys = key_path(s)?;
+        let last = keys.pop().unwrap_or(Value::Null);
+        // Nothing is removed unless the full path of keys exists.
+        let found = get_nested(map.clone(), &keys)
+            .and_then(|inner| get_map(inner).ok())
+            .map(|inner| inner.contains_key(&last))
+            .unwrap_or(false);
+        if found {
+            Ok(Value::Map(set_nested(map, &keys, |mut inner| {
+                inner.remove(&last);
+                inner
+            })))
+        } else {
+            Ok(Value::Map(map))
+        }
+    });
+    def_va!(f, set(map, args), |s| {
+        let map = get_map(s.get("map")?)?;
+        let mut keys = s.get("args")?.iter_items();
+        let value = keys.pop().ok_or_else(|| {
+            Error::S("Expected $args to contain a key.".into())
+        })?;
+        let key = keys.pop().ok_or_else(|| {
+            Error::S("Expected $args to contain a value.".into())
+        })?;
+        Ok(Value::Map(set_nested(map, &keys, |mut inner| {
+            inner.insert(key, value);
+            inner
+        })))
+    });
+    for (name, func) in f {
+        m.define_function(name, func);
+    }
+}
+
 fn get_map(v: Value) -> Result<OrderMap<Value, Value>, Error> {
     match v {
         Value::Map(m) => Ok(m),
@@ -54,6 +111,68 @@ fn get_map(v: Value) -> Result<OrderMap<Value, Value>, Error> {
     }
 }
 
+/// Get the `key` argument followed by any rest `keys` arguments.
+fn key_path(s: &dyn Scope) -> Result<Vec<Value>, Error> {
+    let mut keys = vec![s.get("key")?];
+    keys.extend(s.get("keys")?.iter_items());
+    Ok(keys)
+}
+
+/// Get the value at a path of keys in nested maps, if any.
+fn get_nested(map: OrderMap<Value, Value>, keys: &[Value]) -> Option<Value> {
+    let mut value = Value::Map(map);
+    for key in keys {
+        value = get_map(value).ok()?.get(key)?.clone();
+    }
+    Some(value)
+}
+
+/// Update the map at a path of keys in nested maps.
+///
+/// Maps are created for any key in the path that is missing or does
+/// not refer to a map.
+fn set_nested<F>(
+    mut map: OrderMap<Value, Value>,
+    keys: &[Value],
+    update: F,
+) -> OrderMap<Value, Value>
+where
+    F: FnOnce(OrderMap<Value, Value>) -> OrderMap<Value, Value>,
+{
+    match keys.split_first() {
+        Some((key, rest)) => {
+            let inner = map
+                .get(key)
+                .and_then(|v| get_map(v.clone()).ok())
+                .unwrap_or_else(OrderMap::new);
+            map.insert(
+                key.clone(),
+                Value::Map(set_nested(inner, rest, update)),
+            );
+            map
+        }
+        None => update(map),
+    }
+}
+
+/// Merge `map2` into `map1`, recursively merging values that are
+/// maps in both.
+fn deep_merge(
+    mut map1: OrderMap<Value, Value>,
+    map2: OrderMap<Value, Value>,
+) -> OrderMap<Value, Value> {
+    for (key, value) in map2 {
+        let value = match (map1.get(&key), value) {
+            (Some(Value::Map(m1)), Value::Map(m2)) => {
+                Value::Map(deep_merge(m1.clone(), m2))
+            }
+            (_, value) => value,
+        };
+        map1.insert(key, value);
+    }
+    map1
+}
+
 #[cfg(test)]
 mod test {
     // http://sass-lang.com/documentation/Sass/Script/Functions.html
@@ -73,6 +192,14 @@ mod test {
         fn c() {
             check_val("map-get((\"foo\": 1, \"bar\": 2), \"baz\");", "")
         }
+        #[test]
+        fn nested() {
+            check_val("map-get((a: (b: (c: 1))), a, b, c);", "1")
+        }
+        #[test]
+        fn nested_missing() {
+            check_val("map-get((a: (b: 1)), a, b, c);", "")
+        }
     }
 
     mod map_has_key {
@@ -92,6 +219,45 @@ mod test {
                 "false",
             )
         }
+        #[test]
+        fn nested() {
+            check_val("map-has-key((a: (b: 1)), a, b);", "true")
+        }
+        #[test]
+        fn nested_missing() {
+            check_val("map-has-key((a: (b: 1)), a, c);", "false")
+        }
+    }
+
+    mod map_merge {
+        use super::check_val;
+
+        #[test]
+        fn nested() {
+            check_val(
+                "inspect(map-merge((a: (b: 1), c: 2), a, (d: 3)));",
+                "(a: (b: 1, d: 3), c: 2)",
+            )
+        }
+        #[test]
+        fn named() {
+            check_val(
+                "inspect(map-merge((a: 1), $map2: (b: 2)));",
+                "(a: 1, b: 2)",
+            )
+        }
+    }
+
+    mod map_remove {
+        use super::check_val;
+
+        #[test]
+        fn several() {
+            check_val(
+                "inspect(map-remove((a: 1, b: 2, c: 3), a, c));",
+                "(b: 2)",
+            )
+        }
     }
 
     fn check_val(src: &str, correct: &str) {
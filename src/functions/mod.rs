@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::variablescope::Scope;
+use crate::variablescope::{GlobalScope, Scope};
 use crate::{css, sass};
 use lazy_static::lazy_static;
 use std::collections::BTreeMap;
@@ -12,6 +12,7 @@ mod macros;
 mod colors_hsl;
 mod colors_other;
 mod colors_rgb;
+mod colors_space;
 mod introspection;
 mod lists;
 mod maps;
@@ -25,6 +26,11 @@ pub fn get_builtin_function(name: &str) -> Option<&'static SassFunction> {
     FUNCTIONS.get(name)
 }
 
+/// Get a builtin module, like `math` for `sass:math`.
+pub fn get_builtin_module(name: &str) -> Option<Arc<GlobalScope>> {
+    MODULES.get(name).cloned()
+}
+
 type BuiltinFn =
     dyn Fn(&dyn Scope) -> Result<css::Value, Error> + Send + Sync;
 
@@ -104,11 +110,22 @@ impl SassFunction {
         body: Arc<BuiltinFn>,
     ) -> Self {
         SassFunction {
-            args: sass::FormalArgs::new(args, is_varargs),
+            args: sass::FormalArgs::new(
+                args.into_iter().map(|(name, v)| (name, Some(v))).collect(),
+                is_varargs,
+            ),
             body: FuncImpl::Builtin(body),
         }
     }
 
+    /// Return true if this function is implemented in rust.
+    pub fn is_builtin(&self) -> bool {
+        match self.body {
+            FuncImpl::Builtin(_) => true,
+            FuncImpl::UserDefined(_) => false,
+        }
+    }
+
     /// Create a new `SassFunction` from a scss implementation.
     pub fn new(args: sass::FormalArgs, body: Vec<sass::Item>) -> Self {
         SassFunction {
@@ -125,6 +142,7 @@ impl SassFunction {
         args: &css::CallArgs,
     ) -> Result<css::Value, Error> {
         let mut s = self.args.eval(scope, args)?;
+        s.set_call();
         match self.body {
             FuncImpl::Builtin(ref body) => body(&s),
             FuncImpl::UserDefined(ref body) => {
@@ -147,6 +165,7 @@ lazy_static! {
         colors_hsl::register(&mut f);
         colors_rgb::register(&mut f);
         colors_other::register(&mut f);
+        colors_space::register(&mut f);
         introspection::register(&mut f);
         selector::register(&mut f);
         strings::register(&mut f);
@@ -157,6 +176,126 @@ lazy_static! {
     };
 }
 
+lazy_static! {
+    static ref MODULES: BTreeMap<&'static str, Arc<GlobalScope>> = {
+        let mut m = BTreeMap::new();
+        let mut math = module(&[
+            ("abs", "abs"),
+            ("ceil", "ceil"),
+            ("compatible", "comparable"),
+            ("floor", "floor"),
+            ("is_unitless", "unitless"),
+            ("max", "max"),
+            ("min", "min"),
+            ("percentage", "percentage"),
+            ("random", "random"),
+            ("round", "round"),
+            ("unit", "unit"),
+        ]);
+        numbers::expose(&mut math);
+        m.insert("math", Arc::new(math));
+        let mut color = module(&[
+            ("adjust", "adjust_color"),
+            ("alpha", "alpha"),
+            ("blue", "blue"),
+            ("change", "change_color"),
+            ("complement", "complement"),
+            ("grayscale", "grayscale"),
+            ("green", "green"),
+            ("hue", "hue"),
+            ("hwb", "hwb"),
+            ("ie_hex_str", "ie_hex_str"),
+            ("invert", "invert"),
+            ("lightness", "lightness"),
+            ("mix", "mix"),
+            ("opacity", "opacity"),
+            ("red", "red"),
+            ("saturation", "saturation"),
+            ("scale", "scale_color"),
+        ]);
+        colors_space::expose(&mut color);
+        m.insert("color", Arc::new(color));
+        m.insert(
+            "list",
+            Arc::new(module(&[
+                ("append", "append"),
+                ("index", "index"),
+                ("is_bracketed", "is_bracketed"),
+                ("join", "join"),
+                ("length", "length"),
+                ("nth", "nth"),
+                ("separator", "list_separator"),
+                ("set_nth", "set_nth"),
+                ("zip", "zip"),
+            ])),
+        );
+        let mut map = module(&[
+            ("get", "map_get"),
+            ("has_key", "map_has_key"),
+            ("keys", "map_keys"),
+            ("merge", "map_merge"),
+            ("remove", "map_remove"),
+            ("values", "map_values"),
+        ]);
+        maps::expose(&mut map);
+        m.insert("map", Arc::new(map));
+        m.insert(
+            "string",
+            Arc::new(module(&[
+                ("index", "str_index"),
+                ("insert", "str_insert"),
+                ("length", "str_length"),
+                ("quote", "quote"),
+                ("slice", "str_slice"),
+                ("to_lower_case", "to_lower_case"),
+                ("to_upper_case", "to_upper_case"),
+                ("unique_id", "unique_id"),
+                ("unquote", "unquote"),
+            ])),
+        );
+        m.insert(
+            "selector",
+            Arc::new(module(&[
+                ("append", "selector_append"),
+                ("extend", "selector_extend"),
+                ("is_superselector", "is_superselector"),
+                ("nest", "selector_nest"),
+                ("parse", "selector_parse"),
+                ("replace", "selector_replace"),
+                ("simple_selectors", "simple_selectors"),
+                ("unify", "selector_unify"),
+            ])),
+        );
+        let mut meta = module(&[
+            ("call", "call"),
+            ("content_exists", "content_exists"),
+            ("feature_exists", "feature_exists"),
+            ("function_exists", "function_exists"),
+            ("get_function", "get_function"),
+            ("global_variable_exists", "global_variable_exists"),
+            ("inspect", "inspect"),
+            ("keywords", "keywords"),
+            ("mixin_exists", "mixin_exists"),
+            ("type_of", "type_of"),
+            ("variable_exists", "variable_exists"),
+        ]);
+        introspection::expose(&mut meta);
+        m.insert("meta", Arc::new(meta));
+        m
+    };
+}
+
+/// Create a builtin module of global functions.
+///
+/// Each member is given as its name in the module and its global name.
+fn module(members: &[(&str, &str)]) -> GlobalScope {
+    let mut m = GlobalScope::new_builtin();
+    for &(name, global) in members {
+        m.define_function(name, FUNCTIONS[global].clone());
+    }
+    m
+}
+
 fn make_call(name: &str, args: Vec<css::Value>) -> css::Value {
     css::Value::Call(
         name.into(),
@@ -180,11 +319,20 @@ fn test_rgb() -> Result<(), Box<dyn std::error::Error>> {
             &scope,
             &call_args(b"(17, 0, 225)")?.1.evaluate(&scope, true)?
         )?,
-        css::Value::Color(Rgba::from_rgb(17, 0, 225), None)
+        css::Value::Color(Rgba::from_rgb(17, 0, 225).into(), None)
     );
     Ok(())
 }
 
+#[test]
+fn test_modules() {
+    for name in
+        &["color", "list", "map", "math", "meta", "selector", "string"]
+    {
+        assert!(get_builtin_module(name).is_some(), "Missing {}", name);
+    }
+}
+
 #[test]
 fn test_nth() {
     assert_eq!("foo", do_evaluate(&[("x", "foo, bar")], b"nth($x, 1);"))
@@ -1,44 +1,40 @@
 use super::{Error, SassFunction};
 use crate::css::Value;
-use crate::value::{Number, Unit};
+use crate::value::{NumValue, Number, Operator, Unit, UnitSet};
+use crate::variablescope::{GlobalScope, Scope};
 use num_rational::Rational;
-use num_traits::Signed;
 use rand::{thread_rng, Rng};
 use std::cmp::Ordering;
 use std::collections::BTreeMap;
 
 pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     def!(f, abs(number), |s| match s.get("number")? {
-        Value::Numeric(v, u, ..) => Ok(number(v.value.abs(), u)),
+        Value::Numeric(v, u, ..) => Ok(number(v.abs(), u)),
         v => Err(Error::badarg("number", &v)),
     });
     def!(f, ceil(number), |s| match s.get("number")? {
-        Value::Numeric(v, u, ..) => Ok(number(v.value.ceil(), u)),
+        Value::Numeric(v, u, ..) => Ok(number(v.ceil(), u)),
         v => Err(Error::badarg("number", &v)),
     });
     def!(f, floor(number), |s| match s.get("number")? {
-        Value::Numeric(v, u, ..) => Ok(number(v.value.floor(), u)),
+        Value::Numeric(v, u, ..) => Ok(number(v.floor(), u)),
         v => Err(Error::badarg("number", &v)),
     });
     def!(f, percentage(number), |s| match s.get("number")? {
-        Value::Numeric(val, Unit::None, _) => {
-            Ok(number(val.value * 100, Unit::Percent))
+        Value::Numeric(val, u, _) if u.is_none() => {
+            Ok(number(&val * &Number::from(100), Unit::Percent))
         }
         v => Err(Error::badarg("number", &v)),
     });
     def!(f, round(number), |s| match s.get("number")? {
-        Value::Numeric(val, unit, _) => Ok(number(val.value.round(), unit)),
+        Value::Numeric(val, unit, _) => Ok(number(val.round(), unit)),
         v => Err(Error::badarg("number", &v)),
     });
-    def_va!(f, max(numbers), |s| match s.get("numbers")? {
-        Value::List(v, _, _) => {
-            Ok(find_extreme(&v, Ordering::Greater).clone())
-        }
-        single_value => Ok(single_value),
+    def_va!(f, max(numbers), |s| {
+        find_extreme(s.get("numbers")?.iter_items(), Ordering::Greater)
     });
-    def_va!(f, min(numbers), |s| match s.get("numbers")? {
-        Value::List(v, _, _) => Ok(find_extreme(&v, Ordering::Less).clone()),
-        single_value => Ok(single_value),
+    def_va!(f, min(numbers), |s| {
+        find_extreme(s.get("numbers")?.iter_items(), Ordering::Less)
     });
     def!(f, random(limit), |s| match s.get("limit")? {
         Value::Null => {
@@ -49,7 +45,7 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
             let bound = val.to_integer();
             if bound > 0 {
                 let res = 1 + intrand(bound);
-                Ok(number(Rational::from_integer(res), Unit::None))
+                Ok(number(res, Unit::None))
             } else {
                 Err(Error::S("bound must be > 0".into()))
             }
@@ -58,48 +54,297 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     });
 }
 
-fn number(v: Rational, unit: Unit) -> Value {
-    Value::Numeric(Number::from(v), unit, true)
+/// Add the members of the `sass:math` module that have no global name.
+pub fn expose(m: &mut GlobalScope) {
+    let mut f = BTreeMap::new();
+    def!(f, div(number1, number2), |s| {
+        let (a, a_u) = number_arg(s, "number1")?;
+        let (b, b_u) = number_arg(s, "number2")?;
+        let a = Value::Numeric(a, a_u, true);
+        let b = Value::Numeric(b, b_u, true);
+        match Operator::Div.eval(a, b)? {
+            Some(result) => Ok(result),
+            None => Err(Error::S("Undefined operation.".into())),
+        }
+    });
+    def!(f, clamp(min, number, max), |s| {
+        let (min, min_u) = number_arg(s, "min")?;
+        let (v, u) = number_arg(s, "number")?;
+        let (max, max_u) = number_arg(s, "max")?;
+        if min_u.is_none() != u.is_none() {
+            return Err(mixed_units("$min", &min_u, "$number", &u));
+        }
+        if min_u.is_none() != max_u.is_none() {
+            return Err(mixed_units("$min", &min_u, "$max", &max_u));
+        }
+        let min_v = to_unit(&min, &min_u, &u)?;
+        let max_v = to_unit(&max, &max_u, &u)?;
+        if min_v.value >= max_v.value || min_v.value >= v.value {
+            Ok(number(min, min_u))
+        } else if v.value >= max_v.value {
+            Ok(number(max, max_u))
+        } else {
+            Ok(number(v, u))
+        }
+    });
+    def!(f, sqrt(number), |s| {
+        let v = unitless_arg(s, "number")?.to_f64();
+        Ok(number(v.sqrt(), Unit::None))
+    });
+    def!(f, pow(base, exponent), |s| {
+        let base = unitless_arg(s, "base")?;
+        let exponent = unitless_arg(s, "exponent")?;
+        Ok(number(pow(&base, &exponent), Unit::None))
+    });
+    def!(f, log(number, base), |s| {
+        let v = unitless_arg(s, "number")?.to_f64().ln();
+        match s.get("base")? {
+            Value::Null => Ok(number(v, Unit::None)),
+            _ => {
+                let base = unitless_arg(s, "base")?.to_f64();
+                let ln_base = if fuzzy_eq(base, 1.) {
+                    0.
+                } else if fuzzy_eq(base, 0.) {
+                    f64::NEG_INFINITY
+                } else {
+                    base.ln()
+                };
+                Ok(number(v / ln_base, Unit::None))
+            }
+        }
+    });
+    def_va!(f, hypot(numbers), |s| {
+        let numbers = s.get("numbers")?.iter_items();
+        let unit = match numbers.first() {
+            Some(Value::Numeric(_, u, ..)) => u.clone(),
+            Some(v) => return Err(Error::badarg("number", v)),
+            None => {
+                return Err(Error::S(
+                    "At least one argument must be passed.".into(),
+                ))
+            }
+        };
+        let mut sum = 0.;
+        for (i, v) in numbers.into_iter().enumerate() {
+            match v {
+                Value::Numeric(v, u, ..) => {
+                    if u.is_none() != unit.is_none() {
+                        return Err(mixed_units(
+                            "Argument 1",
+                            &unit,
+                            &format!("argument {}", i + 1),
+                            &u,
+                        ));
+                    }
+                    let v = to_unit(&v, &u, &unit)?.to_f64();
+                    sum += v * v;
+                }
+                v => return Err(Error::badarg("number", &v)),
+            }
+        }
+        Ok(number(sum.sqrt(), unit))
+    });
+    def!(f, sin(number), |s| {
+        Ok(number(angle_arg(s, "number")?.sin(), Unit::None))
+    });
+    def!(f, cos(number), |s| {
+        Ok(number(angle_arg(s, "number")?.cos(), Unit::None))
+    });
+    def!(f, tan(number), |s| {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        let v = angle_arg(s, "number")?;
+        // At the asymptotes, rounding errors in pi should not give
+        // a huge finite number.
+        let at = |asymptote: f64| {
+            let r = (v - asymptote).rem_euclid(2. * PI);
+            fuzzy_eq(r, 0.) || fuzzy_eq(r, 2. * PI)
+        };
+        let result = if at(FRAC_PI_2) {
+            f64::INFINITY
+        } else if at(-FRAC_PI_2) {
+            f64::NEG_INFINITY
+        } else {
+            v.tan()
+        };
+        Ok(number(result, Unit::None))
+    });
+    def!(f, asin(number), |s| {
+        let v = fuzzy_unit_range(unitless_arg(s, "number")?.to_f64());
+        Ok(number(v.asin().to_degrees(), Unit::Deg))
+    });
+    def!(f, acos(number), |s| {
+        let v = fuzzy_unit_range(unitless_arg(s, "number")?.to_f64());
+        Ok(number(v.acos().to_degrees(), Unit::Deg))
+    });
+    def!(f, atan(number), |s| {
+        let v = unitless_arg(s, "number")?.to_f64();
+        Ok(number(v.atan().to_degrees(), Unit::Deg))
+    });
+    def!(f, atan2(y, x), |s| {
+        let (y, yu) = number_arg(s, "y")?;
+        let (x, xu) = number_arg(s, "x")?;
+        let x = to_unit(&x, &xu, &yu)?;
+        Ok(number(y.to_f64().atan2(x.to_f64()).to_degrees(), Unit::Deg))
+    });
+    for (name, func) in f {
+        m.define_function(name, func);
+    }
+    m.define("pi", &number(std::f64::consts::PI, Unit::None));
+    m.define("e", &number(std::f64::consts::E, Unit::None));
 }
 
-fn find_extreme(v: &[Value], pref: Ordering) -> &Value {
-    match v.split_first() {
-        Some((first, rest)) => {
-            let second = find_extreme(rest, pref);
-            match (first, second) {
-                (&Value::Null, b) => b,
-                (a, &Value::Null) => a,
-                (
-                    &Value::Numeric(ref va, ref ua, _),
-                    &Value::Numeric(ref vb, ref ub, _),
-                ) => {
-                    if ua == &Unit::None || ua == ub || ub == &Unit::None {
-                        if va.cmp(vb) == pref {
-                            first
-                        } else {
-                            second
-                        }
-                    } else if ua.dimension() == ub.dimension() {
-                        let sa = va.value * ua.scale_factor();
-                        let sb = vb.value * ub.scale_factor();
-                        if sa.cmp(&sb) == pref {
-                            first
+fn number(v: impl Into<Number>, unit: impl Into<UnitSet>) -> Value {
+    Value::Numeric(v.into(), unit.into(), true)
+}
+
+/// Get the argument `name` as a number and its unit.
+fn number_arg(s: &dyn Scope, name: &str) -> Result<(Number, UnitSet), Error> {
+    match s.get(name)? {
+        Value::Numeric(v, u, ..) => Ok((v, u)),
+        v => Err(Error::S(format!(
+            "${}: {} is not a number.",
+            name,
+            v.format(Default::default())
+        ))),
+    }
+}
+
+/// Get the argument `name`, if it is a unitless number.
+fn unitless_arg(s: &dyn Scope, name: &str) -> Result<Number, Error> {
+    match number_arg(s, name)? {
+        (v, u) if u.is_none() => Ok(v),
+        (v, u) => Err(Error::S(format!(
+            "${}: Expected {} to have no units.",
+            name,
+            number(v, u).format(Default::default())
+        ))),
+    }
+}
+
+/// Get the argument `name` as an angle in radians.
+///
+/// A unitless number is considered to be in radians.
+fn angle_arg(s: &dyn Scope, name: &str) -> Result<f64, Error> {
+    match number_arg(s, name)? {
+        (v, u) if u.is_none() => Ok(v.to_f64()),
+        (v, u) => match u.scale_to(&Unit::Rad.into()) {
+            Some(factor) => Ok((&v * &factor).to_f64()),
+            None => Err(Error::S(format!(
+                "${}: Expected {} to be an angle.",
+                name,
+                number(v, u).format(Default::default())
+            ))),
+        },
+    }
+}
+
+/// Convert the number `v` in `unit` to the unit `to`.
+///
+/// A unitless number is compatible with any unit.
+fn to_unit(
+    v: &Number,
+    unit: &UnitSet,
+    to: &UnitSet,
+) -> Result<Number, Error> {
+    if unit.is_none() || to.is_none() {
+        Ok(v.clone())
+    } else if let Some(factor) = unit.scale_to(to) {
+        Ok(v * &factor)
+    } else {
+        Err(Error::S(format!("Incompatible units {} and {}.", to, unit)))
+    }
+}
+
+/// An error for arguments where one has units and the other does not.
+fn mixed_units(
+    name1: &str,
+    unit1: &UnitSet,
+    name2: &str,
+    unit2: &UnitSet,
+) -> Error {
+    let describe = |unit: &UnitSet| {
+        if unit.is_none() {
+            "is unitless".to_string()
+        } else {
+            format!("has unit {}", unit)
+        }
+    };
+    Error::S(format!(
+        "{} {} but {} {}. \
+         Arguments must all have units or all be unitless.",
+        name1,
+        describe(unit1),
+        name2,
+        describe(unit2),
+    ))
+}
+
+/// Raise `base` to the power of `exponent`.
+///
+/// Small integer powers of exact numbers are computed exactly.
+fn pow(base: &Number, exponent: &Number) -> Number {
+    let exact = !matches!(base.value, NumValue::Float(_));
+    let n = exponent.to_integer();
+    if exact && exponent.is_integer() && n.unsigned_abs() <= 100 {
+        let mut result = Number::from(1);
+        for _ in 0..n.unsigned_abs() {
+            result = &result * base;
+        }
+        if n < 0 {
+            &Number::from(1) / &result
+        } else {
+            result
+        }
+    } else {
+        Number::from(base.to_f64().powf(exponent.to_f64()))
+    }
+}
+
+fn fuzzy_eq(a: f64, b: f64) -> bool {
+    NumValue::from(a).fuzzy_cmp(&NumValue::from(b)) == Some(Ordering::Equal)
+}
+
+/// Values just outside -1 .. 1 are considered to be at the limits.
+fn fuzzy_unit_range(v: f64) -> f64 {
+    if fuzzy_eq(v, 1.) {
+        1.
+    } else if fuzzy_eq(v, -1.) {
+        -1.
+    } else {
+        v
+    }
+}
+
+/// Find the largest or smallest of some numbers, depending on `pref`.
+///
+/// Null values are ignored.
+fn find_extreme(values: Vec<Value>, pref: Ordering) -> Result<Value, Error> {
+    let mut best: Option<(Number, UnitSet)> = None;
+    for v in values {
+        match v {
+            Value::Null => (),
+            Value::Numeric(v, u, ..) => {
+                best = match best {
+                    Some((bv, bu)) => {
+                        let cv = to_unit(&v, &u, &bu)?;
+                        if cv.value.fuzzy_cmp(&bv.value) == Some(pref) {
+                            Some((v, u))
                         } else {
-                            second
+                            Some((bv, bu))
                         }
-                    } else {
-                        &NULL_VALUE
                     }
+                    None => Some((v, u)),
                 }
-                (_, _) => &NULL_VALUE,
             }
+            v => return Err(Error::badarg("number", &v)),
         }
-        None => &NULL_VALUE,
+    }
+    match best {
+        Some((v, u)) => Ok(number(v, u)),
+        None => Err(Error::S("At least one argument must be passed.".into())),
     }
 }
 
-static NULL_VALUE: Value = Value::Null;
-
 fn intrand(lim: isize) -> isize {
     thread_rng().gen_range(0, lim)
 }
@@ -1,61 +1,100 @@
 use super::SassFunction;
 use crate::css::Value;
 use crate::error::Error;
+use crate::extend::{
+    extend_selectors, is_superselector, simple_selectors, unify_selectors,
+};
 use crate::parser::selectors::{selector, selectors};
 use crate::selectors::{Selector, Selectors};
-use crate::value::Quotes;
+use crate::value::{ListSeparator, Quotes};
 use std::collections::BTreeMap;
 
 pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
-    def_va!(f, selector_nest(selectors), |s| match s.get("selectors")? {
-        Value::List(v, _, _) => Ok(Value::Literal(
-            format!(
-                "{}",
-                v.into_iter()
-                    .map(parse_selectors)
-                    .try_fold(Selectors::root(), |b, e| e
-                        .map(|e| e.inside(&b)))?
-            ),
-            Quotes::None,
-        )),
-        v => Ok(Value::Literal(
-            format!("{}", parse_selectors(v)?),
-            Quotes::None,
-        )),
-    });
-    def_va!(
-        f,
-        selector_append(selectors),
-        |s| match s.get("selectors")? {
-            Value::List(v, _, _) => Ok(Value::Literal(
-                format!(
-                    "{}",
-                    v.into_iter().map(parse_selectors).try_fold(
-                        Selectors::root(),
-                        |base, ext| ext.and_then(|ext| Ok(Selectors::new(
-                            base.s
-                                .into_iter()
-                                .flat_map(|b| {
-                                    ext.s.iter().map(move |e| {
-                                        parse_selector(&format!("{}{}", b, e))
-                                    })
-                                })
-                                .collect::<Result<_, _>>()?
-                        ))),
-                    )?,
-                ),
-                Quotes::None,
-            )),
-            v => Ok(Value::Literal(
-                format!("{}", parse_selectors(v)?),
-                Quotes::None,
-            )),
-        }
-    );
+    def_va!(f, selector_nest(selectors), |s| Ok(Value::Literal(
+        format!(
+            "{}",
+            s.get("selectors")?
+                .iter_items()
+                .into_iter()
+                .map(parse_selectors)
+                .try_fold(Selectors::root(), |b, e| e
+                    .map(|e| e.inside(&b)))?
+        ),
+        Quotes::None,
+    )));
+    def_va!(f, selector_append(selectors), |s| Ok(Value::Literal(
+        format!(
+            "{}",
+            s.get("selectors")?
+                .iter_items()
+                .into_iter()
+                .map(parse_selectors)
+                .try_fold(Selectors::root(), |base, ext| ext.map(|ext| {
+                    Selectors::new(
+                        base.s
+                            .iter()
+                            .flat_map(|b| {
+                                ext.s.iter().map(move |e| b.append(e))
+                            })
+                            .collect(),
+                    )
+                }))?,
+        ),
+        Quotes::None,
+    )));
     def!(f, selector_parse(selector), |s| Ok(parse_selectors(
         s.get("selector")?
     )?
     .to_value()));
+    def!(f, selector_extend(selector, extendee, extender), |s| {
+        Ok(extend_selectors(
+            &parse_selectors(s.get("selector")?)?,
+            &parse_selectors(s.get("extendee")?)?,
+            &parse_selectors(s.get("extender")?)?,
+            false,
+        )?
+        .to_value())
+    });
+    def!(f, selector_replace(selector, original, replacement), |s| {
+        Ok(extend_selectors(
+            &parse_selectors(s.get("selector")?)?,
+            &parse_selectors(s.get("original")?)?,
+            &parse_selectors(s.get("replacement")?)?,
+            true,
+        )?
+        .to_value())
+    });
+    def!(f, selector_unify(selector1, selector2), |s| {
+        Ok(unify_selectors(
+            &parse_selectors(s.get("selector1")?)?,
+            &parse_selectors(s.get("selector2")?)?,
+        )
+        .map(|s| s.to_value())
+        .unwrap_or(Value::Null))
+    });
+    def!(f, is_superselector(super, sub), |s| {
+        Ok(Value::bool(is_superselector(
+            &parse_selectors(s.get("super")?)?,
+            &parse_selectors(s.get("sub")?)?,
+        )))
+    });
+    def!(f, simple_selectors(selector), |s| {
+        let v = s.get("selector")?;
+        let selector = parse_selector(
+            &v.clone().unquote().format(Default::default()).to_string(),
+        )?;
+        match simple_selectors(&selector) {
+            Some(parts) => Ok(Value::List(
+                parts
+                    .iter()
+                    .map(|p| Value::Literal(p.to_string(), Quotes::None))
+                    .collect(),
+                ListSeparator::Comma,
+                false,
+            )),
+            None => Err(Error::badarg("compound selector", &v)),
+        }
+    });
 }
 
 fn parse_selectors(v: Value) -> Result<Selectors, Error> {
@@ -66,8 +105,8 @@ fn parse_selectors(v: Value) -> Result<Selectors, Error> {
         let (rest, result) = selectors(s.as_bytes())?;
         if !rest.is_empty() && rest != b"," {
             Err(Error::S(format!(
-                "Unexpected remains in parse_selectors: {:?}",
-                rest
+                "Invalid selector \"{}\": expected end of selector.",
+                s
             )))
         } else {
             Ok(result)
@@ -79,8 +118,8 @@ fn parse_selector(s: &str) -> Result<Selector, Error> {
     let (rest, result) = selector(s.as_bytes())?;
     if !rest.is_empty() {
         Err(Error::S(format!(
-            "Unexpected remains in parse_selector: {:?}",
-            rest
+            "Invalid selector \"{}\": expected end of selector.",
+            s
         )))
     } else {
         Ok(result)
@@ -1,8 +1,7 @@
 use super::{Error, SassFunction};
 use crate::css::Value;
-use crate::value::{Number, Quotes, Unit};
+use crate::value::{Number, Quotes, UnitSet};
 use lazy_static::lazy_static;
-use num_rational::Rational;
 use std::cmp::max;
 use std::collections::BTreeMap;
 use std::sync::Mutex;
@@ -23,6 +22,7 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         Value::Literal(v, _) => Ok(Value::Literal(v, Quotes::None)),
         v => {
             dep_warn!(
+                s,
                 "Passing {}, a non-string value, to unquote()",
                 v.format(Default::default())
             );
@@ -37,9 +37,9 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
         (
             Value::Literal(s, q),
             Value::Literal(insert, _),
-            Value::Numeric(index, Unit::None, ..),
-        ) => {
-            let index = index.value.to_integer();
+            Value::Numeric(index, u, ..),
+        ) if u.is_none() => {
+            let index = index.to_integer();
             let i = if index.is_negative() {
                 let len = s.chars().count() as isize;
                 max(len + 1 + index, 0) as usize
@@ -71,11 +71,11 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
     ) {
         (
             Value::Literal(s, q),
-            Value::Numeric(start_at, Unit::None, ..),
-            Value::Numeric(end_at, Unit::None, ..),
-        ) => {
-            let start_at = index_to_rust(start_at.value, &s)?;
-            let end_at = index_to_rust_end(end_at.value, &s)?;
+            Value::Numeric(start_at, su, ..),
+            Value::Numeric(end_at, eu, ..),
+        ) if su.is_none() && eu.is_none() => {
+            let start_at = index_to_rust(&start_at, &s)?;
+            let end_at = index_to_rust_end(&end_at, &s)?;
             let c = s.chars();
             if start_at <= end_at {
                 Ok(Value::Literal(
@@ -145,17 +145,17 @@ pub fn register(f: &mut BTreeMap<&'static str, SassFunction>) {
 }
 
 fn intvalue(n: usize) -> Value {
-    Value::Numeric(Number::from(n as isize), Unit::None, true)
+    Value::Numeric(Number::from(n as isize), UnitSet::scalar(), true)
 }
 
 /// Convert index from sass (rational number, first is one) to rust
 /// (usize, first is zero).  Sass values might be negative, then -1 is
 /// the last char in the string.
-fn index_to_rust(index: Rational, s: &str) -> Result<usize, Error> {
+fn index_to_rust(index: &Number, s: &str) -> Result<usize, Error> {
     let index = require_integer(index)?;
     let len = s.chars().count();
     Ok(if index.is_negative() {
-        let i = index.abs() as usize;
+        let i = index.unsigned_abs();
         if i <= len {
             len - i
         } else {
@@ -176,11 +176,11 @@ fn index_to_rust(index: Rational, s: &str) -> Result<usize, Error> {
 /// Convert index from sass (rational number, first is one) to rust
 /// (usize, first is zero).  Sass values might be negative, then -1 is
 /// the last char in the string.
-fn index_to_rust_end(index: Rational, s: &str) -> Result<usize, Error> {
+fn index_to_rust_end(index: &Number, s: &str) -> Result<usize, Error> {
     let index = require_integer(index)?;
     Ok(if index.is_negative() {
         let len = s.chars().count();
-        let i = index.abs() as usize - 1;
+        let i = index.unsigned_abs() - 1;
         if i <= len {
             len - i
         } else {
@@ -193,10 +193,13 @@ fn index_to_rust_end(index: Rational, s: &str) -> Result<usize, Error> {
     })
 }
 
-fn require_integer(value: Rational) -> Result<isize, Error> {
+fn require_integer(value: &Number) -> Result<isize, Error> {
     if value.is_integer() {
         Ok(value.to_integer())
     } else {
-        Err(Error::S(format!("{} is not an int", value)))
+        Err(Error::S(format!(
+            "{} is not an int",
+            value.format(Default::default())
+        )))
     }
 }
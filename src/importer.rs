@@ -0,0 +1,172 @@
+//! Importers find and load the files used by `@import`, `@use` and
+//! `@forward`.
+use crate::Error;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// Something that can find and load sass and scss files.
+///
+/// A [`FileContext`](crate::FileContext) uses an importer to find
+/// files.
+/// Each name tried (with or without leading underscore and file name
+/// extension, in each directory of the search path) is given to
+/// `canonicalize`, and the first name found is loaded by `load`.
+pub trait Importer: fmt::Debug + Send + Sync {
+    /// Get the canonical name of a file, if it exists.
+    fn canonicalize(&self, path: &Path) -> Option<PathBuf>;
+
+    /// Load the content of a file, by its canonical name.
+    fn load(&self, path: &Path) -> Result<Vec<u8>, Error>;
+}
+
+/// An importer for files in the file system.
+///
+/// This is the default importer.
+#[derive(Debug, Default)]
+pub struct FsImporter {
+    base: PathBuf,
+}
+
+impl FsImporter {
+    /// Create an importer for files in the file system.
+    ///
+    /// Relative paths are relative to the current directory.
+    pub fn new() -> Self {
+        FsImporter::default()
+    }
+
+    /// Create an importer for files in (and below) a directory.
+    ///
+    /// File names are relative to `base`, which is useful for
+    /// importers of url schemes, e.g. `pkg:`.
+    pub fn in_dir(base: impl Into<PathBuf>) -> Self {
+        FsImporter { base: base.into() }
+    }
+}
+
+impl Importer for FsImporter {
+    fn canonicalize(&self, path: &Path) -> Option<PathBuf> {
+        if self.base.join(path).is_file() {
+            Some(normalize(path))
+        } else {
+            None
+        }
+    }
+
+    fn load(&self, path: &Path) -> Result<Vec<u8>, Error> {
+        let full = self.base.join(path);
+        let mut f =
+            File::open(&full).map_err(|e| Error::Input(full.clone(), e))?;
+        let mut data = vec![];
+        f.read_to_end(&mut data)
+            .map_err(|e| Error::Input(full, e))?;
+        Ok(data)
+    }
+}
+
+/// An importer for files kept in memory.
+///
+/// # Example
+///
+/// ```
+/// use rsass::{output::Format, FileContext, GlobalScope, MemoryImporter};
+///
+/// let mut files = MemoryImporter::new();
+/// files.add("main.scss", "@use 'lib/colors';\np { color: colors.$main }");
+/// files.add("lib/_colors.scss", "$main: red;");
+/// let (context, file) =
+///     FileContext::with_importer(files).file("main.scss".as_ref());
+/// let format = Format::default();
+/// let css = format.write_root(
+///     &context.parse(&file).unwrap(),
+///     &mut GlobalScope::new(format),
+///     &context,
+/// ).unwrap();
+/// assert_eq!(css, b"p {\n  color: red;\n}\n");
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryImporter {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl MemoryImporter {
+    /// Create an importer without any files.
+    pub fn new() -> Self {
+        MemoryImporter::default()
+    }
+
+    /// Add a file to this importer.
+    ///
+    /// If there already was a file with the same name, it is replaced.
+    pub fn add(&mut self, name: impl AsRef<Path>, data: impl Into<Vec<u8>>) {
+        self.files.insert(normalize(name.as_ref()), data.into());
+    }
+}
+
+impl Importer for MemoryImporter {
+    fn canonicalize(&self, path: &Path) -> Option<PathBuf> {
+        let path = normalize(path);
+        if self.files.contains_key(&path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    fn load(&self, path: &Path) -> Result<Vec<u8>, Error> {
+        self.files.get(&normalize(path)).cloned().ok_or_else(|| {
+            Error::Input(path.into(), io::ErrorKind::NotFound.into())
+        })
+    }
+}
+
+/// Remove `.` and resolve `..` components of a path, without looking
+/// at any file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                _ => result.push(component),
+            },
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_paths() {
+        assert_eq!(
+            normalize("a/./b/../c.scss".as_ref()),
+            Path::new("a/c.scss")
+        );
+        assert_eq!(
+            normalize("../../a.scss".as_ref()),
+            Path::new("../../a.scss")
+        );
+    }
+
+    #[test]
+    fn memory_canonicalize() {
+        let mut files = MemoryImporter::new();
+        files.add("./lib/_a.scss", "a {}");
+        assert_eq!(
+            files.canonicalize("x/../lib/_a.scss".as_ref()),
+            Some("lib/_a.scss".into()),
+        );
+        assert_eq!(files.canonicalize("lib/a.scss".as_ref()), None);
+        assert_eq!(files.load("lib/_a.scss".as_ref()).unwrap(), b"a {}");
+    }
+}
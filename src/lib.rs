@@ -10,10 +10,9 @@
 //! use rsass::{compile_scss_file, output};
 //!
 //! let file = "tests/basic/14_imports/a.scss".as_ref();
-//! let format = output::Format {
-//!     style: output::Style::Compressed,
-//!     precision: 5,
-//! };
+//! let format = output::Format::default()
+//!     .with_style(output::Style::Compressed)
+//!     .with_precision(5);
 //! let css = compile_scss_file(file, format).unwrap();
 //!
 //! assert_eq!(css, b"div span{moo:goo}\n")
@@ -41,23 +40,35 @@ use std::path::Path;
 
 pub mod css;
 mod error;
+mod extend;
 mod file_context;
 mod functions;
+mod importer;
+mod logger;
 mod ordermap;
 pub mod output;
 mod parser;
 pub mod sass;
 pub mod selectors;
+mod source_pos;
 mod value;
 mod variablescope;
 
-pub use crate::error::{ErrPos, Error};
+pub use crate::error::{ErrPos, Error, Frame};
 pub use crate::file_context::FileContext;
 pub use crate::functions::SassFunction;
-use crate::output::Format;
-pub use crate::parser::{parse_scss_data, parse_scss_file, parse_value_data};
+pub use crate::importer::{FsImporter, Importer, MemoryImporter};
+pub use crate::logger::{Logger, SilentLogger, StdErrLogger};
+use crate::output::{Format, SourceMap};
+pub use crate::parser::{
+    parse_file, parse_sass_data, parse_sass_file, parse_scss_data,
+    parse_scss_file, parse_value_data,
+};
 pub use crate::sass::Item;
-pub use crate::value::{ListSeparator, Number, Quotes, Unit};
+pub use crate::source_pos::{SourceFile, SourcePos};
+pub use crate::value::{
+    ListSeparator, NumValue, Number, Quotes, Unit, UnitSet,
+};
 pub use crate::variablescope::{GlobalScope, Scope};
 pub use num_rational::Rational;
 
@@ -116,6 +127,7 @@ pub fn compile_scss(input: &[u8], format: Format) -> Result<Vec<u8>, Error> {
 ///
 /// Any `@import` directives will be handled relative to the directory
 /// part of `file`.
+/// A file named `*.sass` is parsed in the indented syntax.
 ///
 /// # Example
 ///
@@ -125,7 +137,9 @@ pub fn compile_scss(input: &[u8], format: Format) -> Result<Vec<u8>, Error> {
 /// assert_eq!(
 ///     compile_scss_file(
 ///         "tests/basic/14_imports/a.scss".as_ref(),
-///         Format { style: Style::Compressed, precision: 5 },
+///         Format::default()
+///             .with_style(Style::Compressed)
+///             .with_precision(5),
 ///     ).unwrap(),
 ///     b"div span{moo:goo}\n"
 /// )
@@ -136,6 +150,41 @@ pub fn compile_scss_file(
 ) -> Result<Vec<u8>, Error> {
     let file_context = FileContext::new();
     let (sub_context, file) = file_context.file(file);
-    let items = parse_scss_file(&file)?;
+    let items = sub_context.parse(&file)?;
     format.write_root(&items, &mut GlobalScope::new(format), &sub_context)
 }
+
+/// Parse a file of scss data and write css in the given style,
+/// together with a source map for the css.
+///
+/// The source map maps each selector and property in the output to
+/// its position in `file` or any module or file imported by it.
+///
+/// # Example
+///
+/// ```
+/// use rsass::{compile_scss_file_with_source_map, output::Format};
+///
+/// let (css, map) = compile_scss_file_with_source_map(
+///     "tests/basic/14_imports/a.scss".as_ref(),
+///     Format::default(),
+/// ).unwrap();
+/// assert_eq!(css, b"div span {\n  moo: goo;\n}\n");
+/// assert_eq!(
+///     map.sources(),
+///     ["tests/basic/14_imports/a.scss"],
+/// );
+/// ```
+pub fn compile_scss_file_with_source_map(
+    file: &Path,
+    format: Format,
+) -> Result<(Vec<u8>, SourceMap), Error> {
+    let file_context = FileContext::new();
+    let (sub_context, file) = file_context.file(file);
+    let items = sub_context.parse(&file)?;
+    format.write_root_mapped(
+        &items,
+        &mut GlobalScope::new(format),
+        &sub_context,
+    )
+}
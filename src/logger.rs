@@ -0,0 +1,176 @@
+//! Handling of messages from `@warn` and `@debug` rules, and of
+//! deprecation warnings.
+use crate::error::write_trace;
+use crate::{Frame, SourcePos};
+use std::cell::RefCell;
+use std::fmt;
+
+/// Something that receives the messages of a compilation.
+///
+/// The logger of a compilation is set on its
+/// [`GlobalScope`](crate::GlobalScope).
+/// The default logger writes all messages to stderr.
+///
+/// # Example
+///
+/// ```
+/// use rsass::output::Format;
+/// use rsass::{parse_scss_data, FileContext, Frame, GlobalScope};
+/// use rsass::{Logger, SourcePos};
+/// use std::sync::{Arc, Mutex};
+///
+/// #[derive(Debug, Default)]
+/// struct Collect(Mutex<Vec<String>>);
+///
+/// impl Logger for Collect {
+///     fn warn(&self, message: &str, _trace: &[Frame]) {
+///         self.0.lock().unwrap().push(message.into());
+///     }
+///     fn debug(&self, _message: &str, _pos: &SourcePos) {}
+/// }
+///
+/// let messages = Arc::new(Collect::default());
+/// let format = Format::default();
+/// let mut scope = GlobalScope::new(format).with_logger(messages.clone());
+/// let items = parse_scss_data(b"@warn 'Careful!';").unwrap();
+/// let css = format.write_root(&items, &mut scope, &FileContext::new());
+/// assert_eq!(css.unwrap(), b"");
+/// assert_eq!(*messages.0.lock().unwrap(), ["Careful!"]);
+/// ```
+pub trait Logger: fmt::Debug + Send + Sync {
+    /// Handle a warning, e.g. from a `@warn` rule.
+    ///
+    /// The `trace` is innermost first.
+    fn warn(&self, message: &str, trace: &[Frame]);
+
+    /// Handle a message from a `@debug` rule.
+    fn debug(&self, message: &str, pos: &SourcePos);
+
+    /// Handle a deprecation warning.
+    ///
+    /// By default, this is handled like any other warning.
+    fn deprecation(&self, message: &str, trace: &[Frame]) {
+        self.warn(message, trace)
+    }
+}
+
+/// A logger that writes messages to stderr.
+///
+/// This is the default logger.
+#[derive(Debug, Default)]
+pub struct StdErrLogger;
+
+impl Logger for StdErrLogger {
+    fn warn(&self, message: &str, trace: &[Frame]) {
+        eprintln!("WARNING: {}{}\n", message, Trace(trace));
+    }
+    fn debug(&self, message: &str, pos: &SourcePos) {
+        let (line, _) = pos.line_col();
+        eprintln!("{}:{} DEBUG: {}", pos.file().name(), line, message);
+    }
+    fn deprecation(&self, message: &str, trace: &[Frame]) {
+        eprintln!("DEPRECATION WARNING: {}{}\n", message, Trace(trace));
+    }
+}
+
+/// A logger that ignores all messages.
+#[derive(Debug, Default)]
+pub struct SilentLogger;
+
+impl Logger for SilentLogger {
+    fn warn(&self, _message: &str, _trace: &[Frame]) {}
+    fn debug(&self, _message: &str, _pos: &SourcePos) {}
+}
+
+impl<L: Logger + ?Sized> Logger for std::sync::Arc<L> {
+    fn warn(&self, message: &str, trace: &[Frame]) {
+        (**self).warn(message, trace)
+    }
+    fn debug(&self, message: &str, pos: &SourcePos) {
+        (**self).debug(message, pos)
+    }
+    fn deprecation(&self, message: &str, trace: &[Frame]) {
+        (**self).deprecation(message, trace)
+    }
+}
+
+/// Display of a trace, as indented lines.
+struct Trace<'a>(&'a [Frame]);
+
+impl fmt::Display for Trace<'_> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write_trace(out, self.0, "    ")
+    }
+}
+
+thread_local! {
+    /// The stack of mixins, functions and files being evaluated.
+    static STACK: RefCell<Vec<Frame>> = RefCell::new(Vec::new());
+}
+
+/// Note that evaluation is at `pos` in the current frame.
+pub(crate) fn at(pos: Option<&SourcePos>) {
+    if let Some(pos) = pos.filter(|p| !p.file().name().is_empty()) {
+        STACK.with(|stack| {
+            if let Some(frame) = stack.borrow_mut().last_mut() {
+                frame.pos = Some(pos.clone());
+            }
+        })
+    }
+}
+
+/// Enter a new frame, e.g. a call to the mixin or function `name`,
+/// or (if `name` is `None`) the root stylesheet.
+///
+/// The frame is left when the returned guard is dropped.
+pub(crate) fn enter(name: Option<&str>) -> FrameGuard {
+    STACK.with(|stack| {
+        stack.borrow_mut().push(Frame {
+            pos: None,
+            name: name.map(Into::into),
+        })
+    });
+    FrameGuard
+}
+
+/// Guard for a frame entered by [`enter`].
+pub(crate) struct FrameGuard;
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        STACK.with(|stack| stack.borrow_mut().pop());
+    }
+}
+
+/// Get the current stack, innermost first.
+///
+/// Frames without a known position, e.g. calls to builtin functions,
+/// are not included.
+pub(crate) fn trace() -> Vec<Frame> {
+    STACK.with(|stack| {
+        stack
+            .borrow()
+            .iter()
+            .rev()
+            .filter(|f| f.pos.is_some())
+            .cloned()
+            .collect()
+    })
+}
+
+/// Get the current stack, innermost first, with `pos` as the position
+/// of the innermost frame.
+///
+/// This is the trace of an error at `pos`.
+pub(crate) fn trace_at(pos: &SourcePos) -> Vec<Frame> {
+    let mut trace: Vec<Frame> =
+        STACK.with(|stack| stack.borrow().iter().rev().cloned().collect());
+    match trace.first_mut() {
+        Some(Frame { pos: ref mut p, .. }) => *p = Some(pos.clone()),
+        None => trace.push(Frame {
+            pos: Some(pos.clone()),
+            name: None,
+        }),
+    }
+    trace
+}
@@ -1,10 +1,18 @@
 use rsass::{
-    output::{Format, Style},
-    parse_scss_file, Error, FileContext, GlobalScope,
+    output::{ColorFormat, Format, Style},
+    parse_sass_data, parse_scss_data, ErrPos, Error, FileContext, Frame,
+    GlobalScope, Item, Logger, SourcePos, StdErrLogger,
 };
-use std::io::{stdout, Write};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env::{split_paths, var_os};
+use std::fs::{create_dir_all, metadata, read, read_dir, write};
+use std::io::{stdin, stdout, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
 use structopt::StructOpt;
 
 fn main() {
@@ -39,36 +47,523 @@ struct Args {
                 possible_values = Style::variants())]
     style: Style,
 
-    /// Where to search for included resources.
-    #[structopt(long, short = "I")]
-    include_path: Option<PathBuf>,
+    /// Write colors as calculated, rather than as written in the source.
+    #[structopt(long)]
+    normalize_colors: bool,
 
-    /// Sass file(s) to translate
-    #[structopt(required = true)]
-    input: Vec<PathBuf>,
+    /// Write colors in their shortest form, also in expanded style and
+    /// for colors written as hex or names in the source.
+    #[structopt(long)]
+    short_colors: bool,
+
+    /// Write translucent colors as `rgb(r g b / a)` rather than
+    /// `rgba(r, g, b, a)`.
+    #[structopt(long)]
+    modern_colors: bool,
+
+    /// Write translucent colors as `#rrggbbaa` hex.
+    #[structopt(long)]
+    alpha_hex: bool,
+
+    /// A path to search for imported files.
+    ///
+    /// This option may be given several times.
+    /// Files are searched for relative to the importing file first,
+    /// then in each load path in the order given, then in each
+    /// path in the `SASS_PATH` environment variable, and last in the
+    /// current directory.
+    #[structopt(
+        long = "load-path",
+        short = "I",
+        alias = "include-path",
+        value_name = "PATH",
+        number_of_values = 1
+    )]
+    load_path: Vec<PathBuf>,
+
+    /// Write a source map for each output file, to the name of the
+    /// output file with `.map` added.
+    ///
+    /// A sourceMappingURL comment referring to the map is added to
+    /// the css output.
+    /// This needs an output file, use --embed-source-map to get a
+    /// source map when writing to stdout.
+    #[structopt(long)]
+    source_map: bool,
+
+    /// Embed a source map as a data url in the css output.
+    #[structopt(long, conflicts_with = "source-map")]
+    embed_source_map: bool,
+
+    /// Read input from stdin in the indented syntax, rather than scss.
+    #[structopt(long)]
+    indented: bool,
+
+    /// Write output to this file.
+    ///
+    /// When there are several inputs, or an input is a directory,
+    /// this is the directory to write output files to.
+    #[structopt(long, short = "o", value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Don't write output files whose content is unchanged.
+    ///
+    /// This keeps the modification time of such files, so that tools
+    /// watching them are not triggered needlessly.
+    #[structopt(long)]
+    skip_unchanged: bool,
+
+    /// Watch the input files and everything they import, and
+    /// recompile when any of them changes.
+    ///
+    /// Inputs that have no given output file are compiled to a css
+    /// file with the same name in the same directory.
+    #[structopt(long, short = "w")]
+    watch: bool,
+
+    /// Don't print warnings or debug messages.
+    #[structopt(long, short = "q")]
+    quiet: bool,
+
+    /// Treat warnings as errors.
+    ///
+    /// The compilation fails, and no output is written, if there are
+    /// any warnings.
+    #[structopt(long)]
+    fatal_warnings: bool,
+
+    /// Sass file(s) or directories to translate.
+    ///
+    /// An input may be given as `input.scss:output.css` to write the
+    /// css to a file rather than stdout, or as `src/:dist/` to
+    /// compile each sass and scss file in the `src` directory tree,
+    /// except partials, to a css file in the same place in `dist`.
+    /// If no input is given, input is read from stdin.
+    input: Vec<String>,
 }
 
 impl Args {
     fn run(self) -> Result<(), Error> {
-        let format = Format {
-            style: self.style,
-            precision: self.precision,
-        };
-        for name in &self.input {
-            let mut file_context = FileContext::new();
-            if let Some(include_path) = &self.include_path {
-                file_context.push_path(include_path.as_ref());
+        let format = Format::default()
+            .with_style(self.style)
+            .with_precision(self.precision)
+            .with_colors(ColorFormat {
+                keep_original: !self.normalize_colors,
+                shortest: self.short_colors,
+                modern_syntax: self.modern_colors,
+                alpha_hex: self.alpha_hex,
+            });
+        let mut targets = self.targets()?;
+        if self.watch {
+            return self.watch(targets, format);
+        }
+        if self.input.is_empty() {
+            let mut data = vec![];
+            stdin().read_to_end(&mut data)?;
+            let items = if self.indented {
+                parse_sass_data(&data)
+            } else {
+                parse_scss_data(&data)
             }
-            let (sub_context, file) = file_context.file(name.as_ref());
-            let items = parse_scss_file(&file)?;
-            let result = format.write_root(
+            .map_err(|(pos, kind)| Error::ParseError {
+                file: "-".into(),
+                pos: ErrPos::pos_of(pos, &data),
+                kind,
+            })?;
+            let mut css = vec![];
+            self.write(
                 &items,
-                &mut GlobalScope::new(format),
-                &sub_context,
+                format,
+                &self.file_context(),
+                self.output.as_deref(),
+                &mut css,
+            )?;
+            self.output(self.output.as_deref(), &css)?;
+        }
+        for target in &mut targets {
+            self.compile_target(target, format)?;
+        }
+        Ok(())
+    }
+
+    /// Get the inputs to compile, and where to write their output.
+    fn targets(&self) -> Result<Vec<Target>, Error> {
+        let several = self.input.len() > 1;
+        let mut targets = Vec::new();
+        for arg in &self.input {
+            let (input, output) = split_target(arg, cfg!(windows));
+            if input.is_dir() {
+                let out_dir = output
+                    .or_else(|| self.output.clone())
+                    .ok_or_else(|| {
+                        Error::S(format!(
+                            "An output directory is needed for compiling \
+                             the directory {}.",
+                            input.display(),
+                        ))
+                    })?;
+                for file in sass_files(&input)? {
+                    let output = file
+                        .strip_prefix(&input)
+                        .map(|rel| out_dir.join(rel).with_extension("css"))
+                        .ok();
+                    targets.push(Target::new(file, output));
+                }
+            } else {
+                let output = output.or_else(|| {
+                    self.output.as_ref().map(|out| {
+                        match input.file_name().filter(|_| several) {
+                            Some(name) => out
+                                .join(Path::new(name).with_extension("css")),
+                            None => out.clone(),
+                        }
+                    })
+                });
+                targets.push(Target::new(input, output));
+            }
+        }
+        Ok(targets)
+    }
+
+    /// Compile all targets, and then keep recompiling them whenever a
+    /// file they depend on is changed.
+    ///
+    /// Changes are detected by polling the modification time of files.
+    fn watch(
+        &self,
+        mut targets: Vec<Target>,
+        format: Format,
+    ) -> Result<(), Error> {
+        if targets.is_empty() {
+            return Err(Error::S("Watch mode needs input files.".into()));
+        }
+        for target in &mut targets {
+            if target.output.is_none() {
+                target.output = Some(target.input.with_extension("css"));
+            }
+        }
+        let mut seen = BTreeMap::new();
+        for target in &mut targets {
+            self.watch_target(target, format);
+            for dep in &target.dependencies {
+                seen.insert(dep.clone(), modified(dep));
+            }
+        }
+        loop {
+            sleep(POLL_INTERVAL);
+            let changed = seen
+                .iter_mut()
+                .filter_map(|(path, time)| {
+                    let now = modified(path);
+                    if now != *time {
+                        *time = now;
+                        Some(path.clone())
+                    } else {
+                        None
+                    }
+                })
+                .collect::<BTreeSet<_>>();
+            if changed.is_empty() {
+                continue;
+            }
+            for target in &mut targets {
+                if target.dependencies.iter().any(|d| changed.contains(d)) {
+                    self.watch_target(target, format);
+                    for dep in &target.dependencies {
+                        seen.entry(dep.clone())
+                            .or_insert_with(|| modified(dep));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Compile a target in watch mode, reporting the result.
+    fn watch_target(&self, target: &mut Target, format: Format) {
+        match self.compile_target(target, format) {
+            Ok(()) => eprintln!(
+                "Compiled {} to {}.",
+                target.input.display(),
+                target
+                    .output
+                    .as_deref()
+                    .unwrap_or_else(|| "-".as_ref())
+                    .display(),
+            ),
+            Err(err) => eprintln!("{}", err),
+        }
+    }
+
+    /// Compile a target to its output.
+    ///
+    /// The dependencies of the target are updated, also when the
+    /// compilation fails, so that a fix can be detected.
+    fn compile_target(
+        &self,
+        target: &mut Target,
+        format: Format,
+    ) -> Result<(), Error> {
+        let file_context = self.file_context();
+        let (sub_context, file) = file_context.file(&target.input);
+        let output = target.output.as_deref();
+        let result = sub_context.parse(&file).and_then(|items| {
+            let mut css = vec![];
+            self.write(&items, format, &sub_context, output, &mut css)?;
+            Ok(css)
+        });
+        target.dependencies = vec![file];
+        target.dependencies.extend(file_context.loaded_files());
+        self.output(target.output.as_deref(), &result?)
+    }
+
+    /// Write `css` to a file, or to stdout if no file is given.
+    fn output(&self, file: Option<&Path>, css: &[u8]) -> Result<(), Error> {
+        if let Some(file) = file {
+            if self.skip_unchanged
+                && read(file).map(|old| old == css).unwrap_or(false)
+            {
+                return Ok(());
+            }
+            if let Some(dir) = file.parent() {
+                create_dir_all(dir)?;
+            }
+            write(file, css)?;
+        } else {
+            stdout().write_all(css)?;
+        }
+        Ok(())
+    }
+
+    fn file_context(&self) -> FileContext {
+        let mut file_context = FileContext::new();
+        for path in &self.load_path {
+            file_context.push_path(path);
+        }
+        if let Some(sass_path) = var_os("SASS_PATH") {
+            for path in split_paths(&sass_path) {
+                file_context.push_path(&path);
+            }
+        }
+        file_context
+    }
+
+    /// Compile `items` and write the css to `out`.
+    ///
+    /// The `output` is the file the css is written to, if any.
+    /// A source map file is written next to it if requested.
+    fn write(
+        &self,
+        items: &[Item],
+        format: Format,
+        file_context: &FileContext,
+        output: Option<&Path>,
+        out: &mut dyn Write,
+    ) -> Result<(), Error> {
+        let logger = Arc::new(CliLogger::new(self.quiet));
+        let mut globals =
+            GlobalScope::new(format).with_logger(logger.clone());
+        if self.source_map || self.embed_source_map {
+            let (result, mut map) = format.write_root_mapped(
+                items,
+                &mut globals,
+                file_context,
             )?;
-            let out = stdout();
-            out.lock().write_all(&result)?;
+            self.check_warnings(&logger)?;
+            let css_name = output
+                .and_then(|file| file.file_name())
+                .map(|name| name.to_string_lossy().into_owned());
+            if let Some(dir) = output.and_then(|file| file.parent()) {
+                map.relative_to(dir);
+            }
+            out.write_all(&result)?;
+            let url = if self.source_map {
+                let output = output.ok_or_else(|| {
+                    Error::S(
+                        "A source map file needs an output file, use \
+                         --embed-source-map when writing to stdout."
+                            .into(),
+                    )
+                })?;
+                let mut map_file = output.as_os_str().to_owned();
+                map_file.push(".map");
+                let map_file = PathBuf::from(map_file);
+                let json = map.to_json(css_name.as_deref(), false);
+                self.output(Some(&map_file), json.as_bytes())?;
+                // The map is in the same directory as the css.
+                map_file.file_name().unwrap().to_string_lossy().into_owned()
+            } else {
+                map.to_data_url(css_name.as_deref())
+            };
+            if !format.is_compressed() {
+                writeln!(out)?;
+            }
+            writeln!(out, "/*# sourceMappingURL={} */", url)?;
+        } else {
+            let result =
+                format.write_root(items, &mut globals, file_context)?;
+            self.check_warnings(&logger)?;
+            out.write_all(&result)?;
         }
         Ok(())
     }
+
+    /// Fail if there were warnings and warnings are fatal.
+    fn check_warnings(&self, logger: &CliLogger) -> Result<(), Error> {
+        if self.fatal_warnings && logger.warned.load(Ordering::Relaxed) {
+            Err(Error::S(
+                "Error: Warnings were treated as errors (--fatal-warnings)."
+                    .into(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Writes messages to stderr, unless quiet, and remembers if there
+/// were any warnings.
+#[derive(Debug)]
+struct CliLogger {
+    quiet: bool,
+    warned: AtomicBool,
+}
+
+impl CliLogger {
+    fn new(quiet: bool) -> Self {
+        CliLogger {
+            quiet,
+            warned: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Logger for CliLogger {
+    fn warn(&self, message: &str, trace: &[Frame]) {
+        self.warned.store(true, Ordering::Relaxed);
+        if !self.quiet {
+            StdErrLogger.warn(message, trace);
+        }
+    }
+    fn debug(&self, message: &str, pos: &SourcePos) {
+        if !self.quiet {
+            StdErrLogger.debug(message, pos);
+        }
+    }
+    fn deprecation(&self, message: &str, trace: &[Frame]) {
+        self.warned.store(true, Ordering::Relaxed);
+        if !self.quiet {
+            StdErrLogger.deprecation(message, trace);
+        }
+    }
+}
+
+/// An input file to compile, and where to write the result.
+struct Target {
+    input: PathBuf,
+    /// The output file, or None for stdout.
+    output: Option<PathBuf>,
+    /// The input and all files it imports, as of the last compilation.
+    dependencies: Vec<PathBuf>,
+}
+
+impl Target {
+    fn new(input: PathBuf, output: Option<PathBuf>) -> Self {
+        Target {
+            input,
+            output,
+            dependencies: vec![],
+        }
+    }
+}
+
+/// Find all sass and scss files in a directory tree, except partials.
+fn sass_files(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut entries = read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    let mut result = Vec::new();
+    for path in entries {
+        if path.is_dir() {
+            result.extend(sass_files(&path)?);
+        } else if is_sass_file(&path) {
+            result.push(path);
+        }
+    }
+    Ok(result)
+}
+
+/// Split an `input:output` argument.
+///
+/// On windows, a colon directly after a single drive letter, as in
+/// `C:\src\a.scss:out.css`, is part of the input path.
+fn split_target(arg: &str, windows: bool) -> (PathBuf, Option<PathBuf>) {
+    let drive = windows
+        && arg.len() > 1
+        && arg.as_bytes()[0].is_ascii_alphabetic()
+        && arg.as_bytes()[1] == b':';
+    let start = if drive { 2 } else { 0 };
+    match arg[start..].find(':') {
+        Some(p) => (
+            PathBuf::from(&arg[..start + p]),
+            Some(PathBuf::from(&arg[start + p + 1..])),
+        ),
+        None => (PathBuf::from(arg), None),
+    }
+}
+
+fn is_sass_file(path: &Path) -> bool {
+    let partial = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with('_'))
+        .unwrap_or(true);
+    let sass = path
+        .extension()
+        .map(|ext| ext == "scss" || ext == "sass")
+        .unwrap_or(false);
+    sass && !partial
+}
+
+/// How often to check for changed files in watch mode.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Get the modification time of a file, if it exists.
+fn modified(path: &Path) -> Option<SystemTime> {
+    metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::split_target;
+    use std::path::PathBuf;
+
+    #[test]
+    fn target_with_output() {
+        assert_eq!(
+            split_target("src/a.scss:out.css", false),
+            (PathBuf::from("src/a.scss"), Some(PathBuf::from("out.css"))),
+        );
+    }
+    #[test]
+    fn target_windows_drive() {
+        assert_eq!(
+            split_target("C:\\src\\a.scss:out.css", true),
+            (
+                PathBuf::from("C:\\src\\a.scss"),
+                Some(PathBuf::from("out.css"))
+            ),
+        );
+        assert_eq!(
+            split_target("C:\\src\\a.scss", true),
+            (PathBuf::from("C:\\src\\a.scss"), None),
+        );
+    }
+    #[test]
+    fn target_drive_letter_not_on_windows() {
+        assert_eq!(
+            split_target("C:out.css", false),
+            (PathBuf::from("C"), Some(PathBuf::from("out.css"))),
+        );
+    }
 }
@@ -0,0 +1,260 @@
+use super::Format;
+use crate::error::Error;
+use crate::extend::{Extender, Extension};
+use crate::selectors::Selectors;
+use crate::source_pos::SourcePos;
+use std::io::{self, Write};
+
+/// Source positions of css output, as byte offsets in the output.
+pub type Marks = Vec<(usize, SourcePos)>;
+
+/// A buffer for css output under construction.
+///
+/// Most content is written as plain bytes, but the selectors of rules
+/// are kept until the entire document is evaluated, so that `@extend`
+/// can be applied to rules both before and after it.
+pub struct CssBuf {
+    chunks: Vec<Chunk>,
+    /// The extensions of each `@extend`, in order.
+    extensions: Vec<Vec<Extension>>,
+    media: Option<String>,
+    /// True if source positions should be kept, for a source map.
+    mapped: bool,
+}
+
+enum Chunk {
+    Raw(Vec<u8>),
+    /// The source of the following output.
+    Mark(SourcePos),
+    Rule {
+        pre: Vec<u8>,
+        selectors: Selectors,
+        media: Option<String>,
+        post: Vec<u8>,
+        /// The source of the rule itself.
+        pos: Option<SourcePos>,
+        /// Source positions in `post`.
+        marks: Marks,
+        /// The number of `@extend` directives before the rule.
+        extends: usize,
+    },
+    /// A line break between top-level items, if there is anything
+    /// both before and after it.
+    Separator,
+}
+
+impl CssBuf {
+    pub fn new() -> Self {
+        CssBuf {
+            chunks: Vec::new(),
+            extensions: Vec::new(),
+            media: None,
+            mapped: false,
+        }
+    }
+
+    /// Create an empty buffer that keeps source positions of its
+    /// content, and of content appended to it.
+    pub fn new_mapped() -> Self {
+        CssBuf {
+            mapped: true,
+            ..CssBuf::new()
+        }
+    }
+
+    /// Create an empty buffer in the same media context as this.
+    pub fn sub(&self) -> Self {
+        CssBuf {
+            media: self.media.clone(),
+            mapped: self.mapped,
+            ..CssBuf::new()
+        }
+    }
+
+    /// Create an empty buffer for content inside a `@media` rule.
+    pub fn in_media(&self, query: &str) -> Self {
+        CssBuf {
+            media: Some(match self.media {
+                Some(ref outer) => format!("{} {}", outer, query),
+                None => query.to_string(),
+            }),
+            mapped: self.mapped,
+            ..CssBuf::new()
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    /// Check if source positions are kept in this buffer.
+    ///
+    /// If not, there is no need to collect them.
+    pub fn is_mapped(&self) -> bool {
+        self.mapped
+    }
+
+    /// Add a rule.
+    ///
+    /// The `pre` and `post` content are written before and after
+    /// the selectors, if the rule is written at all.
+    /// The `marks` are source positions of content in `post`.
+    pub fn add_rule(
+        &mut self,
+        pre: Vec<u8>,
+        selectors: Selectors,
+        post: Vec<u8>,
+        pos: Option<SourcePos>,
+        marks: Marks,
+    ) {
+        self.chunks.push(Chunk::Rule {
+            pre,
+            selectors,
+            media: self.media.clone(),
+            post,
+            pos: pos.filter(|_| self.mapped),
+            marks,
+            extends: self.extensions.len(),
+        });
+    }
+
+    /// Write `data`, with source positions in `marks`.
+    pub fn write_marked(&mut self, data: &[u8], marks: Marks) {
+        let mut done = 0;
+        for (at, pos) in marks {
+            self.write_all(&data[done..at]).unwrap();
+            self.chunks.push(Chunk::Mark(pos));
+            done = at;
+        }
+        self.write_all(&data[done..]).unwrap();
+    }
+
+    pub fn add_separator(&mut self) {
+        self.chunks.push(Chunk::Separator);
+    }
+
+    /// Add an `@extend` of `target` by `extenders`.
+    pub fn add_extend(
+        &mut self,
+        extenders: &Selectors,
+        target: &Selectors,
+        optional: bool,
+    ) -> Result<(), Error> {
+        self.extensions.push(Extension::create(
+            extenders,
+            target,
+            optional,
+            self.media.as_ref().map(|m| m.as_ref()),
+        )?);
+        Ok(())
+    }
+
+    /// Move all content and extensions of `other` to the end of this.
+    pub fn append(&mut self, other: CssBuf) {
+        let base = self.extensions.len();
+        for chunk in other.chunks {
+            match chunk {
+                Chunk::Raw(data) => self.write_all(&data).unwrap(),
+                mut chunk => {
+                    if let Chunk::Rule {
+                        ref mut extends, ..
+                    } = chunk
+                    {
+                        *extends += base;
+                    }
+                    self.chunks.push(chunk)
+                }
+            }
+        }
+        self.extensions.extend(other.extensions);
+    }
+
+    /// Render this buffer to bytes, applying all collected extensions.
+    ///
+    /// Returns the output and the source positions of it.
+    pub fn render(self, format: Format) -> Result<(Vec<u8>, Marks), Error> {
+        let mut extender = Extender::new(self.extensions);
+        let mut out = Vec::new();
+        let mut out_marks = Vec::new();
+        let mut pending = Vec::new();
+        let mut separate = false;
+        for chunk in self.chunks {
+            let (mut data, marks) = match chunk {
+                Chunk::Raw(data) => (data, vec![]),
+                Chunk::Mark(pos) => {
+                    pending.push(pos);
+                    continue;
+                }
+                Chunk::Rule {
+                    pre,
+                    selectors,
+                    media,
+                    post,
+                    pos,
+                    marks,
+                    extends,
+                } => match extender.extend(
+                    &selectors,
+                    media.as_deref(),
+                    extends,
+                ) {
+                    Some(selectors) => {
+                        let mut data = pre;
+                        let mut all_marks = Vec::new();
+                        if let Some(pos) = pos {
+                            all_marks.push((data.len(), pos));
+                        }
+                        if format.is_compressed() {
+                            write!(data, "{:#}", selectors)?;
+                        } else {
+                            write!(data, "{}", selectors)?;
+                        }
+                        let base = data.len();
+                        all_marks.extend(
+                            marks
+                                .into_iter()
+                                .map(|(at, pos)| (base + at, pos)),
+                        );
+                        data.extend(post);
+                        (data, all_marks)
+                    }
+                    None => continue,
+                },
+                Chunk::Separator => {
+                    separate = true;
+                    continue;
+                }
+            };
+            if !data.is_empty() {
+                if separate && !out.is_empty() {
+                    out.push(b'\n');
+                }
+                separate = false;
+                let base = out.len();
+                out_marks.extend(pending.drain(..).map(|pos| (base, pos)));
+                out_marks.extend(
+                    marks.into_iter().map(|(at, pos)| (base + at, pos)),
+                );
+                out.append(&mut data);
+            }
+        }
+        extender.check()?;
+        Ok((out, out_marks))
+    }
+}
+
+impl Write for CssBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if !buf.is_empty() {
+            if let Some(Chunk::Raw(ref mut data)) = self.chunks.last_mut() {
+                data.extend_from_slice(buf);
+            } else {
+                self.chunks.push(Chunk::Raw(buf.to_vec()));
+            }
+        }
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -2,18 +2,42 @@ use super::Style;
 
 /// Specifies the format for outputing css.
 ///
-/// The format is the style (expanded or compressed) and the precision
-/// for numeric values.
+/// The format is the style (expanded or compressed), the precision
+/// for numeric values, and how colors are written.
+///
+/// More options may be added, so a format is created from the
+/// default and the `with_` methods, like
+/// `Format::default().with_style(Style::Compressed)`.
 #[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
 pub struct Format {
     pub style: Style,
     pub precision: usize,
+    pub colors: ColorFormat,
 }
 
 impl Format {
+    /// Get this format with the given style.
+    pub fn with_style(self, style: Style) -> Self {
+        Format { style, ..self }
+    }
+    /// Get this format with the given precision.
+    pub fn with_precision(self, precision: usize) -> Self {
+        Format { precision, ..self }
+    }
+    /// Get this format with the given color options.
+    pub fn with_colors(self, colors: ColorFormat) -> Self {
+        Format { colors, ..self }
+    }
     pub fn is_compressed(&self) -> bool {
         self.style == Style::Compressed
     }
+    /// True if colors should be written in their shortest form.
+    ///
+    /// This is always the case in compressed style.
+    pub fn short_colors(&self) -> bool {
+        self.colors.shortest || self.is_compressed()
+    }
 }
 
 impl Default for Format {
@@ -21,6 +45,44 @@ impl Default for Format {
         Format {
             style: Style::Expanded,
             precision: 6,
+            colors: ColorFormat::default(),
+        }
+    }
+}
+
+/// Specifies how colors are written in css output.
+///
+/// The options apply to both expanded and compressed style.
+/// The default is to keep colors as written in the source, and to
+/// write other colors as names, hex or `rgba(r, g, b, a)`, which is
+/// supported by all browsers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorFormat {
+    /// Keep hex colors and color names as written in the source
+    /// (including case and number of hex digits), unless `shortest`
+    /// is set.
+    /// If false, such colors are written like calculated colors.
+    pub keep_original: bool,
+    /// Write colors in their shortest form, e.g. `#fff` rather than
+    /// `white` or `#ffffff`, also when written differently in the
+    /// source.
+    /// Calculated colors are always written in the shortest form in
+    /// compressed style.
+    pub shortest: bool,
+    /// Use the space-separated `rgb(r g b / a)` syntax rather than
+    /// `rgba(r, g, b, a)` for translucent colors.
+    pub modern_syntax: bool,
+    /// Write translucent colors as `#rrggbbaa` hex.
+    pub alpha_hex: bool,
+}
+
+impl Default for ColorFormat {
+    fn default() -> Self {
+        ColorFormat {
+            keep_original: true,
+            shortest: false,
+            modern_syntax: false,
+            alpha_hex: false,
         }
     }
 }
@@ -1,6 +1,9 @@
 //! Types describing how to format output.
+mod cssbuf;
 mod format;
+mod sourcemap;
 mod style;
 
-pub use format::{Format, Formatted};
+pub use format::{ColorFormat, Format, Formatted};
+pub use sourcemap::SourceMap;
 pub use style::Style;
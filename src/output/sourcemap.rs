@@ -0,0 +1,335 @@
+use super::cssbuf::Marks;
+use crate::source_pos::SourceFile;
+use std::env::current_dir;
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// A source map (version 3) for compiled css.
+///
+/// The source map maps each selector and property declaration in the
+/// css output to its position in the sass source.
+///
+/// See <https://sourcemaps.info/spec.html> for the format.
+#[derive(Debug)]
+pub struct SourceMap {
+    sources: Vec<Arc<SourceFile>>,
+    /// The names of the sources, as written in the map.
+    names: Vec<String>,
+    mappings: Vec<Mapping>,
+}
+
+/// A single mapping, all lines and columns starting at zero.
+#[derive(Clone, Copy, Debug)]
+struct Mapping {
+    gen_line: usize,
+    gen_col: usize,
+    source: usize,
+    line: usize,
+    col: usize,
+}
+
+impl SourceMap {
+    /// Create a source map for `css`, given the source positions of
+    /// byte offsets in it.
+    pub(crate) fn new(css: &[u8], mut marks: Marks) -> Self {
+        marks.sort_by_key(|&(at, _)| at);
+        let mut sources: Vec<Arc<SourceFile>> = Vec::new();
+        let mut line_starts: Vec<Vec<usize>> = Vec::new();
+        let mut mappings = Vec::with_capacity(marks.len());
+        let mut gen = Position::default();
+        for (at, pos) in marks {
+            if pos.file().name().is_empty() {
+                continue; // Not from a source file.
+            }
+            gen.advance(css, at);
+            let source = match sources
+                .iter()
+                .position(|s| s.name() == pos.file().name())
+            {
+                Some(i) => i,
+                None => {
+                    line_starts.push(line_starts_of(pos.file().data()));
+                    sources.push(pos.file().clone());
+                    sources.len() - 1
+                }
+            };
+            let starts = &line_starts[source];
+            let line = match starts.binary_search(&pos.offset()) {
+                Ok(line) => line,
+                Err(next) => next - 1,
+            };
+            let data = pos.file().data();
+            let col = utf16_len(&data[starts[line]..pos.offset()]);
+            mappings.push(Mapping {
+                gen_line: gen.line,
+                gen_col: gen.col,
+                source,
+                line,
+                col,
+            });
+        }
+        let names = sources.iter().map(|s| s.name().to_string()).collect();
+        SourceMap {
+            sources,
+            names,
+            mappings,
+        }
+    }
+
+    /// Get the names of the source files of this map.
+    pub fn sources(&self) -> Vec<&str> {
+        self.names.iter().map(|s| s.as_ref()).collect()
+    }
+
+    /// Make the names of the source files relative to `dir`.
+    ///
+    /// Sources in a source map are resolved relative to the map
+    /// itself, so `dir` should be the directory of the map file, or
+    /// of the css file if the map is embedded in it.
+    /// Relative names are taken as relative to the current directory.
+    pub fn relative_to(&mut self, dir: &Path) {
+        for name in &mut self.names {
+            if name != "-" {
+                *name = relative_path(name.as_ref(), dir);
+            }
+        }
+    }
+
+    /// Get this source map as json.
+    ///
+    /// The `file` is the name of the generated css file, if any.
+    /// If `with_content` is true, the contents of the source files
+    /// are included in the map.
+    pub fn to_json(&self, file: Option<&str>, with_content: bool) -> String {
+        let mut out = String::from("{\"version\":3");
+        if let Some(file) = file {
+            out.push_str(",\"file\":");
+            json_string(&mut out, file);
+        }
+        out.push_str(",\"sources\":[");
+        for (i, name) in self.names.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            json_string(&mut out, name);
+        }
+        out.push(']');
+        if with_content {
+            out.push_str(",\"sourcesContent\":[");
+            for (i, source) in self.sources.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                json_string(
+                    &mut out,
+                    &String::from_utf8_lossy(source.data()),
+                );
+            }
+            out.push(']');
+        }
+        out.push_str(",\"names\":[],\"mappings\":\"");
+        self.write_mappings(&mut out);
+        out.push_str("\"}");
+        out
+    }
+
+    /// Get this source map as a `data:` url, suitable for embedding in
+    /// the css output.
+    pub fn to_data_url(&self, file: Option<&str>) -> String {
+        format!(
+            "data:application/json;charset=utf-8;base64,{}",
+            base64(self.to_json(file, true).as_bytes()),
+        )
+    }
+
+    fn write_mappings(&self, out: &mut String) {
+        let mut line = 0;
+        let mut prev = Mapping {
+            gen_line: 0,
+            gen_col: 0,
+            source: 0,
+            line: 0,
+            col: 0,
+        };
+        let mut first_in_line = true;
+        for m in &self.mappings {
+            while line < m.gen_line {
+                out.push(';');
+                line += 1;
+                prev.gen_col = 0;
+                first_in_line = true;
+            }
+            if !first_in_line {
+                out.push(',');
+            }
+            first_in_line = false;
+            vlq(out, m.gen_col as isize - prev.gen_col as isize);
+            vlq(out, m.source as isize - prev.source as isize);
+            vlq(out, m.line as isize - prev.line as isize);
+            vlq(out, m.col as isize - prev.col as isize);
+            prev = *m;
+        }
+    }
+}
+
+/// A line and column in generated output, tracking a byte offset.
+#[derive(Default)]
+struct Position {
+    offset: usize,
+    line: usize,
+    col: usize,
+}
+
+impl Position {
+    /// Advance this position to byte offset `to` in `data`.
+    fn advance(&mut self, data: &[u8], to: usize) {
+        let part = &data[self.offset..to];
+        match part.iter().rposition(|c| *c == b'\n') {
+            Some(last_lf) => {
+                self.line += bytecount::count(part, b'\n');
+                self.col = utf16_len(&part[last_lf + 1..]);
+            }
+            None => self.col += utf16_len(part),
+        }
+        self.offset = to;
+    }
+}
+
+/// Get `path` relative to `dir`, with `/` as separator, as used in
+/// urls.
+fn relative_path(path: &Path, dir: &Path) -> String {
+    let absolute = |p: &Path| -> PathBuf {
+        match current_dir() {
+            Ok(cwd) => cwd.join(p),
+            Err(_) => p.into(),
+        }
+    };
+    let path = absolute(path);
+    let dir = absolute(dir);
+    let path = path
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .collect::<Vec<_>>();
+    let dir = dir
+        .components()
+        .filter(|c| *c != Component::CurDir)
+        .collect::<Vec<_>>();
+    let common = path.iter().zip(&dir).take_while(|(a, b)| a == b).count();
+    let mut result = vec![".."; dir.len() - common];
+    result.extend(
+        path[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_str().unwrap_or("?")),
+    );
+    result.join("/")
+}
+
+fn line_starts_of(data: &[u8]) -> Vec<usize> {
+    let mut starts = vec![0];
+    starts.extend(
+        data.iter()
+            .enumerate()
+            .filter(|&(_, c)| *c == b'\n')
+            .map(|(i, _)| i + 1),
+    );
+    starts
+}
+
+/// The length of utf-8 `data` in utf-16 code units, which is what
+/// columns in source maps are counted in.
+fn utf16_len(data: &[u8]) -> usize {
+    String::from_utf8_lossy(data).encode_utf16().count()
+}
+
+const BASE64: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Write `value` as a base64 vlq, as used in source map mappings.
+fn vlq(out: &mut String, value: isize) {
+    let mut v = if value < 0 {
+        ((-value as usize) << 1) | 1
+    } else {
+        (value as usize) << 1
+    };
+    loop {
+        let mut digit = v & 0b11111;
+        v >>= 5;
+        if v > 0 {
+            digit |= 0b100000;
+        }
+        out.push(BASE64[digit] as char);
+        if v == 0 {
+            break;
+        }
+    }
+}
+
+fn base64(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).cloned().unwrap_or(0),
+            chunk.get(2).cloned().unwrap_or(0),
+        ];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i)) & 0b111111] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn vlq_values() {
+        let mut out = String::new();
+        for v in &[0, 1, -1, 15, 16, -16, 123] {
+            vlq(&mut out, *v);
+            out.push(' ');
+        }
+        assert_eq!(out, "A C D e gB hB 2H ");
+    }
+
+    #[test]
+    fn relative_paths() {
+        let rel = |p: &str, d: &str| relative_path(p.as_ref(), d.as_ref());
+        assert_eq!(rel("src/a.scss", "src"), "a.scss");
+        assert_eq!(rel("src/a.scss", "dist/css"), "../../src/a.scss");
+        assert_eq!(rel("./a.scss", "."), "a.scss");
+        assert_eq!(rel("/x/y/a.scss", "/x/z"), "../y/a.scss");
+    }
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b"M"), "TQ==");
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"Man"), "TWFu");
+    }
+}
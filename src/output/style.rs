@@ -1,14 +1,21 @@
-use super::Format;
+use super::cssbuf::{CssBuf, Marks};
+use super::{Format, SourceMap};
 use crate::css::Value;
 use crate::error::Error;
 use crate::file_context::FileContext;
-use crate::parser::parse_scss_file;
-use crate::sass::{FormalArgs, Item};
+use crate::functions::get_builtin_module;
+use crate::logger;
+use crate::sass::{self, CallArgs, FormalArgs, Item};
 use crate::selectors::Selectors;
-use crate::variablescope::{Scope, ScopeImpl};
+use crate::source_pos::SourcePos;
+use crate::variablescope::{
+    needs_flow_scope, Content, GlobalScope, Scope, ScopeImpl,
+};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::Write;
 use std::str::FromStr;
+use std::sync::Arc;
 
 /// Selected target format.
 /// Only formats that are variants of this type are supported by rsass.
@@ -59,10 +66,41 @@ impl Format {
         file_context: &FileContext,
     ) -> Result<Vec<u8>, Error> {
         let mut result = CssWriter::new(*self);
+        self.write_root_items(items, globals, file_context, &mut result)?;
+        Ok(result.get_result()?.0)
+    }
+
+    /// Write a slice of sass items in this format, and get a source
+    /// map for the output.
+    pub fn write_root_mapped(
+        &self,
+        items: &[Item],
+        globals: &mut dyn Scope,
+        file_context: &FileContext,
+    ) -> Result<(Vec<u8>, SourceMap), Error> {
+        let mut result = CssWriter {
+            contents: CssBuf::new_mapped(),
+            ..CssWriter::new(*self)
+        };
+        self.write_root_items(items, globals, file_context, &mut result)?;
+        let (css, marks) = result.get_result()?;
+        let map = SourceMap::new(&css, marks);
+        Ok((css, map))
+    }
+
+    fn write_root_items(
+        &self,
+        items: &[Item],
+        globals: &mut dyn Scope,
+        file_context: &FileContext,
+        result: &mut CssWriter,
+    ) -> Result<(), Error> {
+        let _frame = logger::enter(None);
+        check_use_order(items)?;
         for item in items {
-            self.handle_root_item(item, globals, file_context, &mut result)?;
+            self.handle_root_item(item, globals, file_context, result)?;
         }
-        result.get_result()
+        Ok(())
     }
     fn handle_root_item(
         &self,
@@ -70,9 +108,20 @@ impl Format {
         scope: &mut dyn Scope,
         file_context: &FileContext,
         result: &mut CssWriter,
+    ) -> Result<(), Error> {
+        logger::at(item.pos());
+        self.do_root_item(item, scope, file_context, result)
+            .map_err(|e| e.at(item.pos()))
+    }
+    fn do_root_item(
+        &self,
+        item: &Item,
+        scope: &mut dyn Scope,
+        file_context: &FileContext,
+        result: &mut CssWriter,
     ) -> Result<(), Error> {
         match *item {
-            Item::Import(ref names, ref args) => {
+            Item::Import(ref names, ref args, _) => {
                 if args.is_null() {
                     for name in names {
                         let name = name.evaluate(scope)?;
@@ -82,7 +131,9 @@ impl Format {
                             if let Some((sub_context, file)) =
                                 file_context.find_file(x.as_ref())
                             {
-                                for item in parse_scss_file(&file)? {
+                                let items = sub_context.parse(&file)?;
+                                let _frame = logger::enter(Some("@import"));
+                                for item in items {
                                     self.handle_root_item(
                                         &item,
                                         scope,
@@ -90,7 +141,7 @@ impl Format {
                                         result,
                                     )?;
                                 }
-                            } else {
+                            } else if is_plain_import(x) {
                                 if (x.starts_with("url(") && x.ends_with(")"))
                                     || x.starts_with('/')
                                 {
@@ -116,6 +167,8 @@ impl Format {
                                         }
                                     )?;
                                 }
+                            } else {
+                                return Err(file_context.not_found(x));
                             }
                         } else {
                             write!(
@@ -138,20 +191,65 @@ impl Format {
                     }
                 }
             }
+            Item::Use {
+                ref url,
+                ref name,
+                ref with,
+                ..
+            } => {
+                let mut config = BTreeMap::new();
+                for (var, val) in with {
+                    config.insert(
+                        var.replace('-', "_"),
+                        val.do_evaluate(scope, true)?,
+                    );
+                }
+                let globals =
+                    scope.as_global_mut().ok_or_else(not_allowed)?;
+                let module = self.load_module(
+                    url,
+                    config,
+                    globals,
+                    file_context,
+                    result,
+                )?;
+                module.check_config()?;
+                globals.use_module(name, module)?;
+            }
+            Item::Forward {
+                ref url,
+                ref prefix,
+                ref show,
+                ref hide,
+                ..
+            } => {
+                let globals =
+                    scope.as_global_mut().ok_or_else(not_allowed)?;
+                let config = globals.forward_config();
+                let module = self.load_module(
+                    url,
+                    config,
+                    globals,
+                    file_context,
+                    result,
+                )?;
+                globals.config_used(&module);
+                globals.forward_module(
+                    module,
+                    prefix.as_ref().map(|p| p.as_ref()),
+                    show.as_ref().map(|s| s.as_ref()),
+                    hide,
+                );
+            }
             Item::VariableDeclaration {
                 ref name,
                 ref val,
                 ref default,
                 ref global,
+                ..
             } => {
                 let val = val.do_evaluate(scope, true)?;
-                if *default {
-                    scope.define_default(name, &val, *global);
-                } else if *global {
-                    scope.define_global(name, &val);
-                } else {
-                    scope.define(name, &val);
-                }
+                scope.declare_variable(name, &val, *default, *global)?;
             }
             Item::AtRoot {
                 ref selectors,
@@ -161,7 +259,7 @@ impl Format {
                     .eval(scope)?
                     .with_backref(scope.get_selectors().one());
                 let mut s1 = vec![];
-                let mut s2 = vec![];
+                let mut s2 = result.to_content().sub();
                 self.handle_body(
                     &mut s1,
                     &mut s2,
@@ -172,23 +270,23 @@ impl Format {
                 )?;
 
                 if !s1.is_empty() {
-                    if self.is_compressed() {
-                        write!(result.to_content(), "{:#}{{", selectors)?;
-                    } else {
-                        write!(result.to_content(), "{} {{", selectors)?;
-                    }
-                    self.write_items(result.to_content(), &s1, 2)?;
-                    write!(result.to_content(), "}}")?;
-                    self.do_indent(result.to_content(), 0)?;
-                }
-                if !s2.is_empty() {
-                    result.to_content().write_all(&s2)?;
+                    self.add_rule(
+                        result.to_content(),
+                        vec![],
+                        selectors,
+                        None,
+                        &s1,
+                        2,
+                        true,
+                    )?;
                 }
+                result.to_content().append(s2);
             }
             Item::AtRule {
                 ref name,
                 ref args,
                 ref body,
+                ..
             } => {
                 result.do_separate()?;
                 let args = args.evaluate(scope)?;
@@ -203,7 +301,7 @@ impl Format {
                         write!(result.to_content(), " {{")?;
                     }
                     let mut direct = vec![];
-                    let mut sub = vec![];
+                    let mut sub = media_buf(result.to_content(), name, &args);
                     self.handle_body(
                         &mut direct,
                         &mut sub,
@@ -212,13 +310,19 @@ impl Format {
                         file_context,
                         2,
                     )?;
-                    self.write_items(result.to_content(), &direct, 2)?;
-                    if !sub.is_empty() {
-                        if direct.is_empty() {
-                            result.do_indent(0)?;
-                        }
-                        result.to_content().write_all(&sub)?;
+                    let mut data = vec![];
+                    let content = result.to_content();
+                    let marks = self.write_items(
+                        &mut data,
+                        &direct,
+                        2,
+                        content.is_mapped(),
+                    )?;
+                    content.write_marked(&data, marks);
+                    if !sub.is_empty() && direct.is_empty() {
+                        result.do_indent(0)?;
                     }
+                    result.to_content().append(sub);
                     write!(result.to_content(), "}}")?;
                 } else {
                     write!(result.to_content(), ";")?;
@@ -233,61 +337,89 @@ impl Format {
             Item::MixinCall {
                 ref name,
                 ref args,
+                ref content_args,
                 ref body,
+                ..
             } => {
-                if let Some((m_args, m_body)) = scope.get_mixin(name) {
-                    let mut scope =
-                        m_args.eval(scope, &args.evaluate(scope, true)?)?;
-                    scope.define_mixin(
-                        "%%BODY%%",
-                        &FormalArgs::default(),
-                        body,
-                    );
+                if is_load_css(scope, name) {
+                    self.load_css(args, scope, file_context, result)?;
+                } else if let Some((m_args, m_body)) = scope.get_mixin(name) {
+                    let args = args.evaluate(scope, true)?;
+                    let defscope = mixin_scope(scope, name);
+                    let mut argscope = m_args.eval(&defscope, &args)?;
+                    argscope.set_call();
+                    argscope.set_content(Content {
+                        block: body.as_ref().map(|b| (content_args, &b[..])),
+                        scope,
+                    });
+                    let _frame = logger::enter(Some(&format!("{}()", name)));
                     for item in m_body {
                         self.handle_root_item(
                             &item,
-                            &mut scope,
+                            &mut argscope,
                             file_context,
                             result,
                         )?;
                     }
                 } else {
-                    return Err(Error::S(format!(
-                        "Unknown mixin {}({:?})",
-                        name, args
-                    )));
+                    return Err(scope
+                        .get_global_scope()
+                        .undefined_mixin(name));
                 }
             }
-            Item::Content => {
-                return Err(Error::S(
-                    "@content not allowed in global context".into(),
-                ));
+            Item::Content(ref args, _) => {
+                let content = scope.get_content().ok_or_else(|| {
+                    Error::S(
+                        "@content is only allowed within mixin declarations."
+                            .into(),
+                    )
+                })?;
+                if let Some((content_args, body)) = content.block {
+                    let args = args.evaluate(scope, true)?;
+                    let callscope = content_scope(&content, scope);
+                    let mut scope = content_args.eval(&callscope, &args)?;
+                    for item in body {
+                        self.handle_root_item(
+                            item,
+                            &mut scope,
+                            file_context,
+                            result,
+                        )?;
+                    }
+                }
             }
 
             Item::FunctionDeclaration { ref name, ref func } => {
                 scope.define_function(name, func.clone());
             }
-            Item::Return(_) => {
+            Item::Return(..) => {
                 return Err(Error::S(
                     "Return not allowed in global context".into(),
                 ));
             }
 
-            Item::IfStatement(ref cond, ref do_if, ref do_else) => {
+            Item::IfStatement(ref cond, ref do_if, ref do_else, _) => {
                 let cond = cond.evaluate(scope)?.is_true();
                 let items = if cond { do_if } else { do_else };
+                let mut flow;
+                let scope: &mut dyn Scope = if needs_flow_scope(items) {
+                    flow = ScopeImpl::sub_flow(scope);
+                    &mut flow
+                } else {
+                    scope
+                };
                 for item in items {
                     self.handle_root_item(item, scope, file_context, result)?;
                 }
             }
-            Item::Each(ref names, ref values, ref body) => {
-                for value in values.evaluate(scope)?.iter_items() {
-                    // TODO: No local sub-scope here?!?
+            Item::Each(ref names, ref values, ref body, _) => {
+                let mut scope = ScopeImpl::sub_flow(scope);
+                for value in values.evaluate(&scope)?.iter_items() {
                     scope.define_multi(names, &value);
                     for item in body {
                         self.handle_root_item(
                             item,
-                            scope,
+                            &mut scope,
                             file_context,
                             result,
                         )?;
@@ -300,12 +432,22 @@ impl Format {
                 ref to,
                 inclusive,
                 ref body,
+                ..
             } => {
                 let from = from.evaluate(scope)?.integer_value()?;
                 let to = to.evaluate(scope)?.integer_value()?;
-                let to = if inclusive { to + 1 } else { to };
+                let to = if inclusive {
+                    to.checked_add(1).ok_or_else(|| {
+                        Error::S(format!(
+                            "The @for bound {} is too large.",
+                            to
+                        ))
+                    })?
+                } else {
+                    to
+                };
+                let mut scope = ScopeImpl::sub_flow(scope);
                 for value in from..to {
-                    let mut scope = ScopeImpl::sub(scope);
                     scope.define(name, &Value::scalar(value));
                     for item in body {
                         self.handle_root_item(
@@ -317,25 +459,35 @@ impl Format {
                     }
                 }
             }
-            Item::Warn(ref value) => {
-                eprintln!(
-                    "WARNING: {}",
-                    value.evaluate(scope)?.format(*self)
-                );
+            Item::Warn(ref value, _) => {
+                let msg = value.evaluate(scope)?.unquote();
+                let msg = msg.format(*self).to_string();
+                scope.get_logger().warn(&msg, &logger::trace());
             }
-            Item::Error(ref value) => {
+            Item::Debug(ref value, ref pos) => {
+                let msg = value.evaluate(scope)?.unquote();
+                let msg = msg.format(*self).to_string();
+                scope.get_logger().debug(&msg, pos);
+            }
+            Item::Error(ref value, _) => {
                 return Err(Error::S(format!(
                     "Error: {}",
                     value.evaluate(scope)?.format(*self)
                 )));
             }
-            Item::While(ref cond, ref body) => {
-                let mut scope = ScopeImpl::sub(scope);
-                while cond.evaluate(&scope)?.is_true() {
+            Item::While(ref cond, ref body, _) => {
+                let mut flow;
+                let scope: &mut dyn Scope = if needs_flow_scope(body) {
+                    flow = ScopeImpl::sub_flow(scope);
+                    &mut flow
+                } else {
+                    scope
+                };
+                while cond.evaluate(scope)?.is_true() {
                     for item in body {
                         self.handle_root_item(
                             item,
-                            &mut scope,
+                            scope,
                             file_context,
                             result,
                         )?;
@@ -343,17 +495,23 @@ impl Format {
                 }
             }
 
-            Item::Rule(ref s, ref b) => {
+            Item::Rule(ref s, ref b, ref pos) => {
                 result.do_separate()?;
                 self.write_rule(
                     s,
                     b,
+                    pos,
                     result.to_content(),
                     scope,
                     file_context,
                     0,
                 )?;
             }
+            Item::Extend { .. } => {
+                return Err(Error::S(
+                    "@extend may only be used within style rules.".into(),
+                ));
+            }
             Item::NamespaceRule(..) => {
                 return Err(Error::S(
                     "Global namespaced property not allowed".into(),
@@ -372,18 +530,125 @@ impl Format {
         }
         Ok(())
     }
+
+    /// Load the module `url` (unless it is already loaded).
+    ///
+    /// The css output of the module is written to `result` when it is
+    /// first loaded.
+    fn load_module(
+        &self,
+        url: &str,
+        config: BTreeMap<String, Value>,
+        parent: &GlobalScope,
+        file_context: &FileContext,
+        result: &mut CssWriter,
+    ) -> Result<Arc<GlobalScope>, Error> {
+        if let Some(name) = url.strip_prefix("sass:") {
+            let module = get_builtin_module(name).ok_or_else(|| {
+                Error::S(format!("Invalid internal module \"{}\".", url))
+            })?;
+            if !config.is_empty() {
+                return Err(Error::S(
+                    "Built-in modules can't be configured.".into(),
+                ));
+            }
+            return Ok(module);
+        }
+        let (sub_context, file) = file_context
+            .find_file(url.as_ref())
+            .ok_or_else(|| file_context.not_found(url))?;
+        if let Some(module) = parent.start_loading(&file)? {
+            if !config.is_empty() {
+                return Err(Error::S(
+                    "This module was already loaded, so it can't be \
+                     configured using \"with\"."
+                        .into(),
+                ));
+            }
+            return Ok(module);
+        }
+        let mut module = parent.new_module(config);
+        let items = sub_context.parse(&file)?;
+        let _frame = logger::enter(Some("@use"));
+        check_use_order(&items)?;
+        for item in items {
+            self.handle_root_item(&item, &mut module, &sub_context, result)?;
+        }
+        let module = Arc::new(module);
+        parent.set_loaded(&file, module.clone());
+        Ok(module)
+    }
+
+    /// Handle `@include meta.load-css($url, $with: null)`.
+    ///
+    /// The css of the stylesheet `url` is written to `result`, nested
+    /// in the current selectors.
+    /// Unlike `@use`, the stylesheet is evaluated (and its css
+    /// written) each time it is loaded.
+    fn load_css(
+        &self,
+        args: &CallArgs,
+        scope: &dyn Scope,
+        file_context: &FileContext,
+        result: &mut CssWriter,
+    ) -> Result<(), Error> {
+        let args = args.evaluate(scope, true)?;
+        let formal = FormalArgs::new(
+            vec![
+                ("url".into(), None),
+                ("with".into(), Some(sass::Value::Null)),
+            ],
+            false,
+        );
+        let args = formal.eval(scope, &args)?;
+        let url = match args.get("url")? {
+            Value::Literal(url, _) => url,
+            v => return Err(Error::badarg("string", &v)),
+        };
+        let mut config = BTreeMap::new();
+        match args.get("with")? {
+            Value::Map(with) => {
+                for (name, value) in with {
+                    match name {
+                        Value::Literal(name, _) => {
+                            config.insert(name.replace('-', "_"), value);
+                        }
+                        name => return Err(Error::badarg("string", &name)),
+                    }
+                }
+            }
+            Value::Null => (),
+            v => return Err(Error::badarg("map", &v)),
+        }
+        let (sub_context, file) = file_context
+            .find_file(url.as_ref())
+            .ok_or_else(|| file_context.not_found(&url))?;
+        let mut module = scope
+            .get_global_scope()
+            .new_load_css(config, scope.get_selectors().clone());
+        let items = sub_context.parse(&file)?;
+        let _frame = logger::enter(Some("load-css()"));
+        check_use_order(&items)?;
+        for item in items {
+            self.handle_root_item(&item, &mut module, &sub_context, result)?;
+        }
+        module.check_config()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn write_rule(
         &self,
         selectors: &Selectors,
         body: &[Item],
-        out: &mut dyn Write,
+        pos: &SourcePos,
+        out: &mut CssBuf,
         scope: &mut dyn Scope,
         file_context: &FileContext,
         indent: usize,
     ) -> Result<(), Error> {
         let selectors = selectors.eval(scope)?.inside(scope.get_selectors());
         let mut direct = Vec::new();
-        let mut sub = Vec::new();
+        let mut sub = out.sub();
         self.handle_body(
             &mut direct,
             &mut sub,
@@ -393,366 +658,491 @@ impl Format {
             indent,
         )?;
         if !direct.is_empty() {
-            self.do_indent_no_lf(out, indent)?;
-            if self.is_compressed() {
-                write!(out, "{:#}{{", selectors)?;
-            } else {
-                write!(out, "{} {{", selectors)?;
-            }
-            self.write_items(out, &direct, indent + 2)?;
-            write!(out, "}}")?;
-            self.do_indent(out, 0)?;
+            let mut pre = vec![];
+            self.do_indent_no_lf(&mut pre, indent)?;
+            self.add_rule(
+                out,
+                pre,
+                selectors,
+                out.is_mapped().then(|| pos.clone()),
+                &direct,
+                indent + 2,
+                true,
+            )?;
+        }
+        out.append(sub);
+        Ok(())
+    }
+
+    /// Add a rule with `selectors` and `items` to `out`.
+    ///
+    /// The `pre` is written before the selectors, and if `end` is
+    /// true, a line break is written after the rule.
+    /// The `pos` is the source position of the rule, if known.
+    #[allow(clippy::too_many_arguments)]
+    fn add_rule(
+        &self,
+        out: &mut CssBuf,
+        pre: Vec<u8>,
+        selectors: Selectors,
+        pos: Option<SourcePos>,
+        items: &[CssBodyItem],
+        indent: usize,
+        end: bool,
+    ) -> Result<(), Error> {
+        let mut post = vec![];
+        if self.is_compressed() {
+            write!(post, "{{")?;
+        } else {
+            write!(post, " {{")?;
         }
-        out.write_all(&sub)?;
+        let base = post.len();
+        let marks =
+            self.write_items(&mut post, items, indent, out.is_mapped())?;
+        let marks = marks.into_iter().map(|(at, p)| (base + at, p)).collect();
+        write!(post, "}}")?;
+        if end {
+            self.do_indent(&mut post, 0)?;
+        }
+        out.add_rule(pre, selectors, post, pos, marks);
         Ok(())
     }
 
     fn handle_body(
         &self,
         direct: &mut Vec<CssBodyItem>,
-        sub: &mut dyn Write,
+        sub: &mut CssBuf,
         scope: &mut dyn Scope,
         body: &[Item],
         file_context: &FileContext,
         indent: usize,
     ) -> Result<(), Error> {
         for b in body {
-            match *b {
-                Item::Import(ref names, ref args) => {
-                    if args.is_null() {
-                        for name in names {
-                            let name = name.evaluate(scope)?;
-                            if let Value::Literal(ref x, _) = name {
-                                let (sub_context, file) =
-                                    file_context.file(x.as_ref());
-                                let items = parse_scss_file(&file)?;
-                                self.handle_body(
-                                    direct,
-                                    sub,
-                                    scope,
-                                    &items,
-                                    &sub_context,
-                                    0,
-                                )?;
-                            } else {
-                                write!(
-                                    sub, // TODO:  Should be topmost!
-                                    "@import {};{}",
-                                    name.format(*self),
-                                    if self.is_compressed() {
-                                        ""
-                                    } else {
-                                        "\n"
+            logger::at(b.pos());
+            self.handle_body_item(
+                direct,
+                sub,
+                scope,
+                b,
+                file_context,
+                indent,
+            )
+            .map_err(|e| e.at(b.pos()))?;
+        }
+        Ok(())
+    }
+
+    fn handle_body_item(
+        &self,
+        direct: &mut Vec<CssBodyItem>,
+        sub: &mut CssBuf,
+        scope: &mut dyn Scope,
+        b: &Item,
+        file_context: &FileContext,
+        indent: usize,
+    ) -> Result<(), Error> {
+        match *b {
+            Item::Import(ref names, ref args, _) => {
+                if args.is_null() {
+                    for name in names {
+                        let name = name.evaluate(scope)?;
+                        let found = match name {
+                            Value::Literal(ref x, _) => {
+                                match file_context.find_file(x.as_ref()) {
+                                    Some(found) => Some(found),
+                                    None if is_plain_import(x) => None,
+                                    None => {
+                                        return Err(file_context.not_found(x))
                                     }
-                                )?;
+                                }
                             }
-                        }
-                    } else {
-                        for name in names {
+                            _ => None,
+                        };
+                        if let Some((sub_context, file)) = found {
+                            let items = sub_context.parse(&file)?;
+                            let _frame = logger::enter(Some("@import"));
+                            self.handle_body(
+                                direct,
+                                sub,
+                                scope,
+                                &items,
+                                &sub_context,
+                                0,
+                            )?;
+                        } else {
                             write!(
                                 sub, // TODO:  Should be topmost!
-                                "@import {} {};{}",
-                                name.evaluate(scope)?.format(*self),
-                                args.evaluate(scope)?.format(*self),
+                                "@import {};{}",
+                                name.format(*self),
                                 if self.is_compressed() { "" } else { "\n" }
                             )?;
                         }
                     }
+                } else {
+                    for name in names {
+                        write!(
+                            sub, // TODO:  Should be topmost!
+                            "@import {} {};{}",
+                            name.evaluate(scope)?.format(*self),
+                            args.evaluate(scope)?.format(*self),
+                            if self.is_compressed() { "" } else { "\n" }
+                        )?;
+                    }
                 }
-                Item::VariableDeclaration {
-                    ref name,
-                    ref val,
-                    default,
-                    global,
-                } => {
-                    let val = val.do_evaluate(scope, true)?;
-                    if default {
-                        scope.define_default(name, &val, global);
-                    } else if global {
-                        scope.define_global(name, &val);
-                    } else {
-                        scope.define(name, &val);
+            }
+            Item::Use { .. } | Item::Forward { .. } => {
+                return Err(not_allowed());
+            }
+            Item::VariableDeclaration {
+                ref name,
+                ref val,
+                default,
+                global,
+                ..
+            } => {
+                let val = val.do_evaluate(scope, true)?;
+                scope.declare_variable(name, &val, default, global)?;
+            }
+            Item::AtRoot {
+                ref selectors,
+                ref body,
+            } => {
+                let selectors = selectors
+                    .eval(scope)?
+                    .with_backref(scope.get_selectors().one());
+                let mut s1 = vec![];
+                let mut s2 = sub.sub();
+                self.handle_body(
+                    &mut s1,
+                    &mut s2,
+                    &mut ScopeImpl::sub_selectors(scope, selectors.clone()),
+                    body,
+                    file_context,
+                    indent,
+                )?;
+
+                if !s1.is_empty() {
+                    let mut pre = vec![];
+                    if indent > 0 {
+                        self.do_indent(&mut pre, indent)?;
                     }
+                    self.add_rule(
+                        sub,
+                        pre,
+                        selectors,
+                        None,
+                        &s1,
+                        indent + 2,
+                        true,
+                    )?;
+                }
+                sub.append(s2);
+            }
+            Item::AtRule {
+                ref name,
+                ref args,
+                ref body,
+                ..
+            } => {
+                write!(sub, "@{}", name)?;
+                let args = args.evaluate(scope)?;
+                if !args.is_null() {
+                    write!(sub, " {}", args.format(*self))?;
                 }
-                Item::AtRoot {
-                    ref selectors,
-                    ref body,
-                } => {
-                    let selectors = selectors
-                        .eval(scope)?
-                        .with_backref(scope.get_selectors().one());
+                if let Some(ref body) = *body {
+                    if self.is_compressed() {
+                        write!(sub, "{{")?;
+                    } else {
+                        write!(sub, " {{")?;
+                    }
+
                     let mut s1 = vec![];
-                    let mut s2 = vec![];
+                    let mut s2 = media_buf(sub, name, &args);
                     self.handle_body(
                         &mut s1,
                         &mut s2,
-                        &mut ScopeImpl::sub_selectors(
-                            scope,
-                            selectors.clone(),
-                        ),
+                        &mut ScopeImpl::sub(scope),
                         body,
                         file_context,
-                        indent,
+                        2,
                     )?;
 
+                    let mut content = media_buf(sub, name, &args);
                     if !s1.is_empty() {
-                        if indent > 0 {
-                            self.do_indent(sub, indent)?;
-                        }
-                        if self.is_compressed() {
-                            write!(sub, "{:#}{{", selectors)?;
-                        } else {
-                            write!(sub, "{} {{", selectors)?;
-                        }
-                        self.write_items(sub, &s1, indent + 2)?;
-                        write!(sub, "}}")?;
-                        self.do_indent(sub, 0)?;
-                    }
-                    if !s2.is_empty() {
-                        sub.write_all(&s2)?;
-                    }
-                }
-                Item::AtRule {
-                    ref name,
-                    ref args,
-                    ref body,
-                } => {
-                    write!(sub, "@{}", name)?;
-                    let args = args.evaluate(scope)?;
-                    if !args.is_null() {
-                        write!(sub, " {}", args.format(*self))?;
-                    }
-                    if let Some(ref body) = *body {
-                        if self.is_compressed() {
-                            write!(sub, "{{")?;
-                        } else {
-                            write!(sub, " {{")?;
-                        }
-
-                        let mut s1 = vec![];
-                        let mut s2 = vec![];
-                        self.handle_body(
-                            &mut s1,
-                            &mut s2,
-                            &mut ScopeImpl::sub(scope),
-                            body,
-                            file_context,
-                            2,
+                        let mut pre = vec![];
+                        self.do_indent(&mut pre, 2)?;
+                        self.add_rule(
+                            &mut content,
+                            pre,
+                            scope.get_selectors().clone(),
+                            None,
+                            &s1,
+                            4,
+                            false,
                         )?;
-
-                        if !s1.is_empty() {
-                            self.do_indent(sub, 2)?;
-                            if self.is_compressed() {
-                                write!(sub, "{:#}{{", scope.get_selectors())?;
-                            } else {
-                                write!(sub, "{} {{", scope.get_selectors())?;
-                            }
-                            self.write_items(sub, &s1, 4)?;
-                            write!(sub, "}}")?;
-                        }
-                        self.do_indent(sub, 0)?;
-                        if !s2.is_empty() {
-                            sub.write_all(&s2)?;
-                        }
-                        write!(sub, "}}")?;
-                        self.do_indent(sub, 0)?;
-                    } else {
-                        write!(sub, ";")?;
                     }
+                    self.do_indent(&mut content, 0)?;
+                    content.append(s2);
+                    sub.append(content);
+                    write!(sub, "}}")?;
+                    self.do_indent(sub, 0)?;
+                } else {
+                    write!(sub, ";")?;
                 }
+            }
 
-                Item::MixinDeclaration {
-                    ref name,
-                    ref args,
-                    ref body,
-                } => {
-                    scope.define_mixin(name, args, body);
-                }
-                Item::MixinCall {
-                    ref name,
-                    ref args,
-                    ref body,
-                } => {
-                    if let Some((m_args, m_body)) = scope.get_mixin(name) {
-                        let mut argscope = m_args
-                            .eval(scope, &args.evaluate(scope, true)?)?;
-                        argscope.define_mixin(
-                            "%%BODY%%",
-                            &FormalArgs::default(),
-                            body,
-                        );
-                        self.handle_body(
-                            direct,
-                            sub,
-                            &mut argscope,
-                            &m_body,
-                            file_context,
-                            indent,
-                        )?;
-                    } else {
-                        direct.push(CssBodyItem::Comment(format!(
-                            "Unknown mixin {}({:?})",
-                            name, args
-                        )));
-                    }
+            Item::MixinDeclaration {
+                ref name,
+                ref args,
+                ref body,
+            } => {
+                scope.define_mixin(name, args, body);
+            }
+            Item::MixinCall {
+                ref name,
+                ref args,
+                ref content_args,
+                ref body,
+                ..
+            } => {
+                if is_load_css(scope, name) {
+                    let mut css = CssWriter {
+                        contents: sub.sub(),
+                        ..CssWriter::new(*self)
+                    };
+                    self.load_css(args, scope, file_context, &mut css)?;
+                    sub.append(css.contents);
+                } else if let Some((m_args, m_body)) = scope.get_mixin(name) {
+                    let args = args.evaluate(scope, true)?;
+                    let defscope = mixin_scope(scope, name);
+                    let mut argscope = m_args.eval(&defscope, &args)?;
+                    argscope.set_call();
+                    argscope.set_content(Content {
+                        block: body.as_ref().map(|b| (content_args, &b[..])),
+                        scope,
+                    });
+                    let _frame = logger::enter(Some(&format!("{}()", name)));
+                    self.handle_body(
+                        direct,
+                        sub,
+                        &mut argscope,
+                        &m_body,
+                        file_context,
+                        indent,
+                    )?;
+                } else {
+                    return Err(scope
+                        .get_global_scope()
+                        .undefined_mixin(name));
                 }
-                Item::Content => {
-                    if let Some((_args, m_body)) = scope.get_mixin("%%BODY%%")
-                    {
-                        self.handle_body(
-                            direct,
-                            sub,
-                            scope,
-                            &m_body,
-                            file_context,
-                            indent,
-                        )?;
-                    }
+            }
+            Item::Content(ref args, _) => {
+                let content = scope.get_content().ok_or_else(|| {
+                    Error::S(
+                        "@content is only allowed within mixin declarations."
+                            .into(),
+                    )
+                })?;
+                if let Some((content_args, body)) = content.block {
+                    let args = args.evaluate(scope, true)?;
+                    let callscope = content_scope(&content, scope);
+                    let mut scope = content_args.eval(&callscope, &args)?;
+                    self.handle_body(
+                        direct,
+                        sub,
+                        &mut scope,
+                        body,
+                        file_context,
+                        indent,
+                    )?;
                 }
+            }
 
-                Item::FunctionDeclaration { ref name, ref func } => {
-                    scope.define_function(name, func.clone());
-                }
-                Item::Return(_) => {
-                    return Err(Error::S(
-                        "Return not allowed in plain context".into(),
-                    ));
-                }
+            Item::FunctionDeclaration { ref name, ref func } => {
+                scope.define_function(name, func.clone());
+            }
+            Item::Return(..) => {
+                return Err(Error::S(
+                    "Return not allowed in plain context".into(),
+                ));
+            }
 
-                Item::IfStatement(ref cond, ref do_if, ref do_else) => {
-                    let cond = cond.evaluate(scope)?.is_true();
-                    let items = if cond { do_if } else { do_else };
+            Item::IfStatement(ref cond, ref do_if, ref do_else, _) => {
+                let cond = cond.evaluate(scope)?.is_true();
+                let items = if cond { do_if } else { do_else };
+                let mut flow;
+                let scope: &mut dyn Scope = if needs_flow_scope(items) {
+                    flow = ScopeImpl::sub_flow(scope);
+                    &mut flow
+                } else {
+                    scope
+                };
+                self.handle_body(direct, sub, scope, items, file_context, 0)?;
+            }
+            Item::Each(ref names, ref values, ref body, _) => {
+                let mut scope = ScopeImpl::sub_flow(scope);
+                for value in values.evaluate(&scope)?.iter_items() {
+                    scope.define_multi(&names, &value);
                     self.handle_body(
                         direct,
                         sub,
-                        &mut ScopeImpl::sub(scope),
-                        items,
+                        &mut scope,
+                        body,
                         file_context,
                         0,
                     )?;
                 }
-                Item::Each(ref names, ref values, ref body) => {
-                    for value in values.evaluate(scope)?.iter_items() {
-                        let mut scope = ScopeImpl::sub(scope);
-                        scope.define_multi(&names, &value);
-                        self.handle_body(
-                            direct,
-                            sub,
-                            &mut scope,
-                            body,
-                            file_context,
-                            0,
-                        )?;
-                    }
-                }
-                Item::For {
-                    ref name,
-                    ref from,
-                    ref to,
-                    inclusive,
-                    ref body,
-                } => {
-                    let from = from.evaluate(scope)?.integer_value()?;
-                    let to = to.evaluate(scope)?.integer_value()?;
-                    let to = if inclusive { to + 1 } else { to };
-                    for value in from..to {
-                        let mut scope = ScopeImpl::sub(scope);
-                        scope.define(name, &Value::scalar(value));
-                        self.handle_body(
-                            direct,
-                            sub,
-                            &mut scope,
-                            body,
-                            file_context,
-                            0,
-                        )?;
-                    }
-                }
-                Item::Warn(ref value) => {
-                    eprintln!(
-                        "WARNING: {}",
-                        value.evaluate(scope)?.format(*self)
-                    );
-                }
-                Item::Error(ref value) => {
-                    return Err(Error::S(format!(
-                        "Error: {}",
-                        value.evaluate(scope)?.format(*self),
-                    )));
-                }
-                Item::While(ref cond, ref body) => {
-                    let mut scope = ScopeImpl::sub(scope);
-                    while cond.evaluate(&scope)?.is_true() {
-                        self.handle_body(
-                            direct,
-                            sub,
-                            &mut scope,
-                            body,
-                            file_context,
-                            0,
-                        )?;
-                    }
-                }
-
-                Item::Rule(ref s, ref b) => {
-                    self.write_rule(s, b, sub, scope, file_context, indent)?;
+            }
+            Item::For {
+                ref name,
+                ref from,
+                ref to,
+                inclusive,
+                ref body,
+                ..
+            } => {
+                let from = from.evaluate(scope)?.integer_value()?;
+                let to = to.evaluate(scope)?.integer_value()?;
+                let to = if inclusive {
+                    to.checked_add(1).ok_or_else(|| {
+                        Error::S(format!(
+                            "The @for bound {} is too large.",
+                            to
+                        ))
+                    })?
+                } else {
+                    to
+                };
+                let mut scope = ScopeImpl::sub_flow(scope);
+                for value in from..to {
+                    scope.define(name, &Value::scalar(value));
+                    self.handle_body(
+                        direct,
+                        sub,
+                        &mut scope,
+                        body,
+                        file_context,
+                        0,
+                    )?;
                 }
-                Item::NamespaceRule(ref name, ref value, ref body) => {
-                    let value = value.evaluate(scope)?;
-                    let (name, _quotes) = name.evaluate(scope)?;
-                    if !value.is_null() {
-                        direct
-                            .push(CssBodyItem::Property(name.clone(), value));
-                    }
-                    let mut t = Vec::new();
+            }
+            Item::Warn(ref value, _) => {
+                let msg = value.evaluate(scope)?.unquote();
+                let msg = msg.format(*self).to_string();
+                scope.get_logger().warn(&msg, &logger::trace());
+            }
+            Item::Debug(ref value, ref pos) => {
+                let msg = value.evaluate(scope)?.unquote();
+                let msg = msg.format(*self).to_string();
+                scope.get_logger().debug(&msg, pos);
+            }
+            Item::Error(ref value, _) => {
+                return Err(Error::S(format!(
+                    "Error: {}",
+                    value.evaluate(scope)?.format(*self),
+                )));
+            }
+            Item::While(ref cond, ref body, _) => {
+                let mut flow;
+                let scope: &mut dyn Scope = if needs_flow_scope(body) {
+                    flow = ScopeImpl::sub_flow(scope);
+                    &mut flow
+                } else {
+                    scope
+                };
+                while cond.evaluate(scope)?.is_true() {
                     self.handle_body(
-                        &mut t,
+                        direct,
                         sub,
                         scope,
                         body,
                         file_context,
-                        indent,
+                        0,
                     )?;
-                    for item in t {
-                        direct.push(match item {
-                            CssBodyItem::Property(n, v) => {
-                                CssBodyItem::Property(
-                                    format!("{}-{}", name, n),
-                                    v,
-                                )
-                            }
-                            c => c,
-                        })
-                    }
                 }
-                Item::Property(ref name, ref value) => {
-                    let v = value.evaluate(scope)?;
-                    if !v.is_null() {
-                        let (name, _q) = name.evaluate(scope)?;
-                        direct.push(CssBodyItem::Property(name, v));
-                    }
+            }
+
+            Item::Rule(ref s, ref b, ref pos) => {
+                self.write_rule(s, b, pos, sub, scope, file_context, indent)?;
+            }
+            Item::Extend {
+                ref target,
+                optional,
+            } => {
+                let target = target.eval(scope)?;
+                sub.add_extend(scope.get_selectors(), &target, optional)?;
+            }
+            Item::NamespaceRule(ref name, ref value, ref body) => {
+                let value = value.evaluate(scope)?.valid_css()?;
+                let (name, _quotes) = name.evaluate(scope)?;
+                if !value.is_null() {
+                    direct.push(CssBodyItem::Property(
+                        name.clone(),
+                        value,
+                        None,
+                    ));
                 }
-                Item::Comment(ref c) => {
-                    if !self.is_compressed() {
-                        direct.push(CssBodyItem::Comment(c.clone()));
-                    }
+                let mut t = Vec::new();
+                self.handle_body(
+                    &mut t,
+                    sub,
+                    scope,
+                    body,
+                    file_context,
+                    indent,
+                )?;
+                for item in t {
+                    direct.push(match item {
+                        CssBodyItem::Property(n, v, pos) => {
+                            CssBodyItem::Property(
+                                format!("{}-{}", name, n),
+                                v,
+                                pos,
+                            )
+                        }
+                        c => c,
+                    })
                 }
-                Item::None => (),
             }
+            Item::Property(ref name, ref value, ref pos) => {
+                let v = value.evaluate(scope)?.valid_css()?;
+                if !v.is_null() {
+                    let (name, _q) = name.evaluate(scope)?;
+                    let pos = sub.is_mapped().then(|| pos.clone());
+                    direct.push(CssBodyItem::Property(name, v, pos));
+                }
+            }
+            Item::Comment(ref c) => {
+                if !self.is_compressed() {
+                    direct.push(CssBodyItem::Comment(c.clone()));
+                }
+            }
+            Item::None => (),
         }
         Ok(())
     }
 
+    /// Write `items` to `out`.
+    ///
+    /// If `mapped` is true, returns the source positions of the items,
+    /// relative to the start of the written data.
     fn write_items(
         &self,
         out: &mut dyn Write,
         items: &[CssBodyItem],
         indent: usize,
-    ) -> Result<(), Error> {
+        mapped: bool,
+    ) -> Result<Marks, Error> {
+        let mut marks = Vec::new();
         if !items.is_empty() {
             let mut buf = Vec::new();
             for item in items {
                 self.do_indent(&mut buf, indent)?;
+                if let CssBodyItem::Property(_, _, Some(ref pos)) = *item {
+                    if mapped {
+                        marks.push((buf.len(), pos.clone()));
+                    }
+                }
                 item.write(&mut buf, *self)?;
             }
             if self.is_compressed() && buf.last() == Some(&b';') {
@@ -761,7 +1151,7 @@ impl Format {
             out.write_all(&buf)?;
             self.do_indent(out, indent - 2)?;
         }
-        Ok(())
+        Ok(marks)
     }
 
     fn do_indent(
@@ -791,9 +1181,107 @@ impl Format {
     }
 }
 
+/// Get a scope for calling the mixin `name`, if it is defined in a
+/// module.
+///
+/// The mixin body should see the members of its own module, but the
+/// selectors of the calling scope.
+/// Get the scope for evaluating a content block.
+///
+/// The content block sees the variables of the scope where the mixin
+/// was called, but is nested in the selectors where `@content` is.
+fn content_scope<'a>(
+    content: &Content<'a>,
+    scope: &dyn Scope,
+) -> ScopeImpl<'a> {
+    ScopeImpl::sub_selectors(content.scope, scope.get_selectors().clone())
+}
+
+/// The scope to evaluate the mixin `name` in.
+///
+/// That is the scope where the mixin is defined, but with the
+/// selectors of the calling scope.
+fn mixin_scope<'a>(scope: &'a dyn Scope, name: &str) -> ScopeImpl<'a> {
+    ScopeImpl::sub_selectors(
+        scope.get_mixin_scope(name).unwrap_or(scope),
+        scope.get_selectors().clone(),
+    )
+}
+
+/// Returns true if the mixin `name` is `load-css` from `sass:meta`.
+fn is_load_css(scope: &dyn Scope, name: &str) -> bool {
+    match name.rsplit_once('.') {
+        Some((ns, name)) if name.replace('-', "_") == "load_css" => {
+            match (
+                scope.get_global_scope().get_module(ns),
+                get_builtin_module("meta"),
+            ) {
+                (Some(module), Some(meta)) => std::ptr::eq(module, &*meta),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn not_allowed() -> Error {
+    Error::S("This at-rule is not allowed here.".into())
+}
+
+/// Check that each `@use` in `items` comes before any other rules.
+///
+/// Only `@charset`, `@forward`, variable declarations and comments
+/// may come before a `@use`.
+fn check_use_order(items: &[Item]) -> Result<(), Error> {
+    let mut allowed = true;
+    for item in items {
+        match *item {
+            Item::Use { ref pos, .. } if !allowed => {
+                return Err(Error::S(
+                    "@use rules must be written before any other rules."
+                        .into(),
+                )
+                .at(Some(pos)));
+            }
+            Item::Use { .. }
+            | Item::Forward { .. }
+            | Item::VariableDeclaration { .. }
+            | Item::Comment(_)
+            | Item::None => (),
+            Item::AtRule { ref name, .. } if name == "charset" => (),
+            _ => allowed = false,
+        }
+    }
+    Ok(())
+}
+
+/// Check if an `@import` of `name` that is not found is a plain css
+/// import rather than an error.
+///
+/// Urls, protocol-relative urls and names of css files are plain
+/// imports.
+fn is_plain_import(name: &str) -> bool {
+    (name.starts_with("url(") && name.ends_with(')'))
+        || name.starts_with("//")
+        || name.starts_with("http://")
+        || name.starts_with("https://")
+        || name.ends_with(".css")
+}
+
+/// Get a buffer for the body of an at-rule.
+///
+/// For `@media`, the buffer is in the context of the media query.
+fn media_buf(outer: &CssBuf, name: &str, args: &Value) -> CssBuf {
+    if name == "media" {
+        outer.in_media(&args.format(Format::default()).to_string())
+    } else {
+        outer.sub()
+    }
+}
+
 struct CssWriter {
     imports: Vec<u8>,
-    contents: Vec<u8>,
+    contents: CssBuf,
     format: Format,
     separate: bool,
 }
@@ -802,15 +1290,16 @@ impl CssWriter {
     fn new(format: Format) -> Self {
         CssWriter {
             imports: Vec::new(),
-            contents: Vec::new(),
+            contents: CssBuf::new(),
             format,
             separate: false,
         }
     }
-    fn get_result(self) -> Result<Vec<u8>, Error> {
+    fn get_result(self) -> Result<(Vec<u8>, Marks), Error> {
         let mut result = vec![];
         let compressed = self.format.is_compressed();
-        if !self.imports.is_ascii() || !self.contents.is_ascii() {
+        let (contents, marks) = self.contents.render(self.format)?;
+        if !self.imports.is_ascii() || !contents.is_ascii() {
             if compressed {
                 // U+FEFF is byte order mark, used to show encoding.
                 result.extend_from_slice("\u{feff}".as_bytes());
@@ -819,25 +1308,29 @@ impl CssWriter {
             }
         }
         result.extend(self.imports);
-        result.extend(self.contents);
+        let base = result.len();
+        let marks = marks.into_iter().map(|(at, p)| (base + at, p)).collect();
+        result.extend(contents);
         if compressed && result.last() == Some(&b';') {
             result.pop();
         }
         if result.last().unwrap_or(&b'\n') != &b'\n' {
             writeln!(&mut result)?;
         }
-        Ok(result)
+        Ok((result, marks))
     }
 
     fn to_imports(&mut self) -> &mut impl Write {
         &mut self.imports
     }
-    fn to_content(&mut self) -> &mut impl Write {
+    fn to_content(&mut self) -> &mut CssBuf {
         &mut self.contents
     }
     fn do_separate(&mut self) -> Result<(), Error> {
         if self.separate {
-            self.do_indent(0)?;
+            if !self.format.is_compressed() {
+                self.contents.add_separator();
+            }
         } else {
             self.separate = true;
         }
@@ -857,7 +1350,7 @@ impl CssWriter {
 }
 
 enum CssBodyItem {
-    Property(String, Value),
+    Property(String, Value, Option<SourcePos>),
     Comment(String),
 }
 
@@ -868,7 +1361,7 @@ impl CssBodyItem {
         format: Format,
     ) -> std::io::Result<()> {
         match *self {
-            CssBodyItem::Property(ref name, ref val) => write!(
+            CssBodyItem::Property(ref name, ref val, _) => write!(
                 out,
                 "{}:{}{};",
                 name,
@@ -1,13 +1,14 @@
 use super::strings::name;
 use super::util::{ignore_comments, opt_spacelike};
 use super::value::space_list;
-use crate::sass::{CallArgs, FormalArgs, Value};
+use crate::sass::{CallArgs, FormalArgs};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::combinator::{map, opt};
+use nom::error::ErrorKind;
 use nom::multi::separated_list;
 use nom::sequence::{delimited, pair, preceded, terminated};
-use nom::IResult;
+use nom::{Err, IResult};
 
 pub fn formal_args(input: &[u8]) -> IResult<&[u8], FormalArgs> {
     let (input, _) = terminated(tag("("), opt_spacelike)(input)?;
@@ -22,7 +23,7 @@ pub fn formal_args(input: &[u8]) -> IResult<&[u8], FormalArgs> {
                     opt_spacelike,
                 )),
             ),
-            |(name, d)| (name.replace('-', "_"), d.unwrap_or(Value::Null)),
+            |(name, d)| (name.replace('-', "_"), d),
         ),
     )(input)?;
     let (input, _) = terminated(opt(tag(",")), opt_spacelike)(input)?;
@@ -33,23 +34,48 @@ pub fn formal_args(input: &[u8]) -> IResult<&[u8], FormalArgs> {
 
 pub fn call_args(input: &[u8]) -> IResult<&[u8], CallArgs> {
     let (input, _) = tag("(")(input)?;
-    let (input, v) = separated_list(
+    let (input, mut v) = separated_list(
         delimited(opt_spacelike, tag(","), opt_spacelike),
-        pair(
-            opt(delimited(
-                tag("$"),
-                map(name, |n: String| n.replace("-", "_")),
-                preceded(ignore_comments, tag(":")),
-            )),
-            alt((
-                space_list,
-                delimited(ignore_comments, space_list, ignore_comments),
-            )),
-        ),
+        alt((
+            map(terminated(space_list, tag("...")), |v| (None, v, true)),
+            map(
+                pair(
+                    opt(delimited(
+                        tag("$"),
+                        map(name, |n: String| n.replace("-", "_")),
+                        preceded(ignore_comments, tag(":")),
+                    )),
+                    alt((
+                        space_list,
+                        delimited(
+                            ignore_comments,
+                            space_list,
+                            ignore_comments,
+                        ),
+                    )),
+                ),
+                |(name, v)| (name, v, false),
+            ),
+        )),
     )(input)?;
     let (input, _) = preceded(
         opt(delimited(opt_spacelike, opt(tag(",")), opt_spacelike)),
         tag(")"),
     )(input)?;
-    Ok((input, CallArgs::new(v)))
+    // Only the last argument may be a rest argument, except that a
+    // rest argument may be followed by keyword rest argument.
+    let mut rest = match v.last() {
+        Some((_, _, true)) => v.pop().map(|(_, v, _)| v),
+        _ => None,
+    };
+    let mut kw_rest = None;
+    if let Some((_, _, true)) = v.last() {
+        kw_rest = rest;
+        rest = v.pop().map(|(_, v, _)| v);
+    }
+    if v.iter().any(|(_, _, rest)| *rest) {
+        return Err(Err::Error((input, ErrorKind::Verify)));
+    }
+    let v = v.into_iter().map(|(name, v, _)| (name, v)).collect();
+    Ok((input, CallArgs::new_with_rest(v, rest, kw_rest)))
 }
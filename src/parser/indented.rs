@@ -0,0 +1,541 @@
+//! Support for the indented syntax, used in `.sass` files.
+//!
+//! Indented sass is parsed by converting it to scss, which is then
+//! parsed by the ordinary scss parser.
+//! While converting, the source offset of each part of the scss is
+//! recorded, so that positions can be mapped back to the sass source.
+//! Scss syntax that is not valid in the indented syntax, like braces
+//! and semicolons, and inconsistent indentation are rejected.
+
+/// Offset pairs, each an offset in converted scss and the
+/// corresponding offset in the original sass source.
+///
+/// The pairs are sorted by scss offset.
+pub type Offsets = Vec<(usize, usize)>;
+
+/// A syntax error, as an offset in the sass source and a message.
+pub type SyntaxError = (usize, String);
+
+/// Convert indented sass `data` to scss.
+pub fn to_scss(data: &[u8]) -> Result<(Vec<u8>, Offsets), SyntaxError> {
+    let bom = b"\xef\xbb\xbf";
+    let (data, base) = match data.strip_prefix(bom) {
+        Some(data) => (data, bom.len()),
+        None => (data, 0),
+    };
+    let lines = logical_lines(&physical_lines(data, base))?;
+    let mut out = Converter::default();
+    let mut indentation = Indentation::default();
+    // The indentation of each open block and of its content.
+    let mut open: Vec<(usize, usize)> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        indentation.check(line)?;
+        while matches!(open.last(), Some(&(d, _)) if d >= line.indent) {
+            open.pop();
+            out.push_str("}\n");
+        }
+        let expected = open.last().map(|&(_, content)| content).unwrap_or(0);
+        if line.indent != expected {
+            return Err(indentation.inconsistent(line, expected));
+        }
+        let next = lines.get(i + 1).filter(|next| next.indent > line.indent);
+        line.write(&mut out, next.is_some());
+        if let Some(next) = next {
+            out.push_str(" {\n");
+            open.push((line.indent, next.indent));
+        } else if line.is_comment() {
+            out.push_str("\n");
+        } else {
+            out.push_str(";\n");
+        }
+    }
+    for _ in open {
+        out.push_str("}\n");
+    }
+    Ok((out.data, out.offsets))
+}
+
+/// The kind of indentation used in a document.
+///
+/// Indentation may be either tabs or spaces, but not both.
+#[derive(Default)]
+struct Indentation {
+    tabs: Option<bool>,
+}
+
+impl Indentation {
+    /// Check the indentation of `line`.
+    ///
+    /// The first indented line decides if tabs or spaces are used.
+    fn check(&mut self, line: &Logical) -> Result<(), SyntaxError> {
+        let start = line.parts[0].0 - line.indent;
+        let tabs = line.whitespace.contains(&b'\t');
+        let spaces = line.whitespace.contains(&b' ');
+        let err = |msg: &str| Err((start, msg.to_string()));
+        match (tabs, spaces, self.tabs) {
+            (true, true, _) => err("Tabs and spaces may not be mixed."),
+            (true, false, Some(false)) => err("Expected spaces, was tabs."),
+            (false, true, Some(true)) => err("Expected tabs, was spaces."),
+            (false, false, _) => Ok(()),
+            _ => {
+                self.tabs = Some(tabs);
+                Ok(())
+            }
+        }
+    }
+
+    /// An error for `line`, which should be indented `expected` steps.
+    fn inconsistent(&self, line: &Logical, expected: usize) -> SyntaxError {
+        let start = line.parts[0].0 - line.indent;
+        if expected == 0 && line.indent > 0 {
+            let msg =
+                "Indenting at the beginning of the document is illegal.";
+            return (start, msg.into());
+        }
+        let kind = if self.tabs == Some(true) {
+            "tab"
+        } else {
+            "space"
+        };
+        let plural = if expected == 1 { "" } else { "s" };
+        let msg = format!(
+            "Inconsistent indentation, expected {} {}{}.",
+            expected, kind, plural,
+        );
+        (start, msg)
+    }
+}
+
+/// Map an `offset` in converted scss back to the sass source.
+pub fn source_offset(offsets: &[(usize, usize)], offset: usize) -> usize {
+    match offsets.binary_search_by_key(&offset, |&(scss, _)| scss) {
+        Ok(i) => offsets[i].1,
+        Err(0) => offset,
+        Err(i) => {
+            let (scss, sass) = offsets[i - 1];
+            sass + (offset - scss)
+        }
+    }
+}
+
+/// Scss output, with a record of where its parts came from.
+#[derive(Default)]
+struct Converter {
+    data: Vec<u8>,
+    offsets: Offsets,
+}
+
+impl Converter {
+    /// Write `part` of the source, found at `offset`.
+    fn push_source(&mut self, offset: usize, part: &[u8]) {
+        self.offsets.push((self.data.len(), offset));
+        self.data.extend_from_slice(part);
+    }
+    /// Write syntax that is not part of the source.
+    fn push_str(&mut self, s: &str) {
+        self.data.extend_from_slice(s.as_bytes());
+    }
+}
+
+/// A line of source, without indentation and trailing space.
+struct Line<'a> {
+    indent: usize,
+    offset: usize,
+    content: &'a [u8],
+    /// The line including indentation.
+    full: &'a [u8],
+}
+
+fn physical_lines(data: &[u8], base: usize) -> Vec<Line<'_>> {
+    let mut result = Vec::new();
+    let mut offset = base;
+    for line in data.split(|c| *c == b'\n') {
+        let indent = line
+            .iter()
+            .take_while(|c| **c == b' ' || **c == b'\t')
+            .count();
+        let end = line
+            .iter()
+            .rposition(|c| !c.is_ascii_whitespace())
+            .map(|e| e + 1)
+            .unwrap_or(indent);
+        result.push(Line {
+            indent,
+            offset: offset + indent,
+            content: &line[indent..end.max(indent)],
+            full: &line[..end.max(indent)],
+        });
+        offset += line.len() + 1;
+    }
+    result
+}
+
+/// A statement of indented sass, possibly spanning multiple lines.
+struct Logical<'a> {
+    indent: usize,
+    /// The indentation of the first line.
+    whitespace: &'a [u8],
+    kind: Kind,
+    parts: Vec<(usize, &'a [u8])>,
+}
+
+#[derive(PartialEq)]
+enum Kind {
+    Code,
+    Comment,
+}
+
+/// Combine physical lines to statements.
+///
+/// Silent comments are removed, and each loud comment becomes a
+/// single statement.
+/// A line that ends with a comma, or has unclosed parentheses or
+/// brackets, is continued on the next line.
+fn logical_lines<'a>(
+    lines: &[Line<'a>],
+) -> Result<Vec<Logical<'a>>, SyntaxError> {
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = &lines[i];
+        i += 1;
+        if line.content.is_empty() {
+            continue;
+        }
+        let nested = |lines: &[Line], i: usize| {
+            lines[i..]
+                .iter()
+                .take_while(|l| {
+                    l.content.is_empty() || l.indent > line.indent
+                })
+                .count()
+        };
+        if line.content.starts_with(b"//") {
+            i += nested(lines, i);
+        } else if line.content.starts_with(b"/*") {
+            let n = nested(lines, i);
+            let mut parts = vec![(line.offset, line.content)];
+            for l in lines[i..i + n].iter().filter(|l| !l.content.is_empty())
+            {
+                // Keep indentation relative to the start of the comment.
+                let extra = l.indent - line.indent;
+                parts.push((l.offset - extra, &l.full[line.indent..]));
+            }
+            i += n;
+            result.push(Logical {
+                indent: line.indent,
+                whitespace: &line.full[..line.indent],
+                kind: Kind::Comment,
+                parts,
+            });
+        } else {
+            let (end, mut depth) = scan(line.content, 0)
+                .map_err(|(at, msg)| (line.offset + at, msg))?;
+            let mut parts = vec![(line.offset, &line.content[..end])];
+            while (depth > 0 || parts.last().unwrap().1.ends_with(b","))
+                && i < lines.len()
+            {
+                let l = &lines[i];
+                i += 1;
+                if !l.content.is_empty() {
+                    let (end, d) = scan(l.content, depth)
+                        .map_err(|(at, msg)| (l.offset + at, msg))?;
+                    depth = d;
+                    parts.push((l.offset, &l.content[..end]));
+                }
+            }
+            result.push(Logical {
+                indent: line.indent,
+                whitespace: &line.full[..line.indent],
+                kind: Kind::Code,
+                parts,
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Scan a line of code, starting at a given nesting `depth`.
+///
+/// Returns the end of the code, before any trailing silent comment,
+/// and the nesting depth after the line.
+/// Semicolons and braces, except for those of interpolations, are
+/// errors unless quoted or in parentheses.
+fn scan(
+    line: &[u8],
+    mut depth: usize,
+) -> Result<(usize, usize), SyntaxError> {
+    let mut quote = None;
+    let mut url = false;
+    let mut interpolation = 0;
+    let mut i = 0;
+    while i < line.len() {
+        let c = line[i];
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) if c == b'\\' => i += 1,
+            Some(_) => (),
+            None => match c {
+                b'"' | b'\'' => quote = Some(c),
+                b'\\' => i += 1,
+                b'(' | b'[' => {
+                    url = line[..i].ends_with(b"url");
+                    depth += 1;
+                }
+                b')' | b']' => {
+                    url = false;
+                    depth = depth.saturating_sub(1);
+                }
+                b'{' if line[..i].ends_with(b"#") => {
+                    interpolation += 1;
+                }
+                b'}' if interpolation > 0 => interpolation -= 1,
+                b'{' | b'}' if depth == 0 => {
+                    let msg = "braces aren't allowed in the indented syntax.";
+                    return Err((i, msg.into()));
+                }
+                b';' if depth == 0 && interpolation == 0 => {
+                    let msg =
+                        "semicolons aren't allowed in the indented syntax.";
+                    return Err((i, msg.into()));
+                }
+                b'/' if !url && line[i..].starts_with(b"//") => {
+                    let end = line[..i]
+                        .iter()
+                        .rposition(|c| !c.is_ascii_whitespace())
+                        .map(|e| e + 1)
+                        .unwrap_or(0);
+                    return Ok((end, depth));
+                }
+                _ => (),
+            },
+        }
+        i += 1;
+    }
+    Ok((line.len(), depth))
+}
+
+impl<'a> Logical<'a> {
+    fn is_comment(&self) -> bool {
+        self.kind == Kind::Comment
+    }
+
+    /// The last line of this statement.
+    fn last_line(&self) -> &'a [u8] {
+        self.parts[self.parts.len() - 1].1
+    }
+
+    fn write(&self, out: &mut Converter, is_header: bool) {
+        if self.is_comment() {
+            self.write_comment(out);
+            return;
+        }
+        let (offset, first) = self.parts[0];
+        if let Some(rest) = first.strip_prefix(b"=") {
+            out.push_str("@mixin ");
+            out.push_source(offset + 1, trim_start(rest));
+        } else if let Some(rest) = first.strip_prefix(b"+").filter(
+            |r| matches!(r.first(), Some(c) if !c.is_ascii_whitespace()),
+        ) {
+            out.push_str("@include ");
+            out.push_source(offset + 1, rest);
+        } else if let Some(rest) = first.strip_prefix(b"@import ") {
+            out.push_source(offset, b"@import ");
+            write_import_urls(out, offset + 8, rest);
+        } else if let Some((name, value)) =
+            old_property(first).filter(|_| !is_header)
+        {
+            out.push_source(offset + 1, name);
+            out.push_str(": ");
+            out.push_source(offset + first.len() - value.len(), value);
+        } else {
+            out.push_source(offset, first);
+        }
+        for &(offset, part) in &self.parts[1..] {
+            out.push_str("\n");
+            out.push_source(offset, part);
+        }
+    }
+
+    fn write_comment(&self, out: &mut Converter) {
+        for (i, &(offset, part)) in self.parts.iter().enumerate() {
+            if i > 0 {
+                out.push_str("\n");
+            }
+            out.push_source(offset, part);
+        }
+        if !self.last_line().ends_with(b"*/") {
+            out.push_str(" */");
+        }
+    }
+}
+
+/// Write the urls of an `@import`, quoting plain urls.
+///
+/// In the indented syntax, the file urls of an import need not be
+/// quoted.
+fn write_import_urls(out: &mut Converter, mut offset: usize, urls: &[u8]) {
+    for (i, url) in urls.split(|c| *c == b',').enumerate() {
+        if i > 0 {
+            out.push_source(offset, b",");
+            offset += 1;
+        }
+        let trimmed = trim_start(url);
+        let start = offset + url.len() - trimmed.len();
+        let trimmed = &trimmed[..trimmed
+            .iter()
+            .rposition(|c| !c.is_ascii_whitespace())
+            .map(|e| e + 1)
+            .unwrap_or(0)];
+        let plain = !trimmed.is_empty()
+            && !trimmed.starts_with(b"\"")
+            && !trimmed.starts_with(b"'")
+            && !trimmed.starts_with(b"url(")
+            && !trimmed.contains(&b' ');
+        if plain {
+            out.push_str(if i > 0 { " \"" } else { "\"" });
+            out.push_source(start, trimmed);
+            out.push_str("\"");
+        } else {
+            if i > 0 {
+                out.push_str(" ");
+            }
+            out.push_source(start, trimmed);
+        }
+        offset += url.len();
+    }
+}
+
+/// Check for a property in the old `:name value` syntax.
+fn old_property(line: &[u8]) -> Option<(&[u8], &[u8])> {
+    let rest = line.strip_prefix(b":")?;
+    let name_len = rest
+        .iter()
+        .take_while(|c| c.is_ascii_alphanumeric() || **c == b'-')
+        .count();
+    let (name, value) = rest.split_at(name_len);
+    if name.is_empty() || !value.first()?.is_ascii_whitespace() {
+        return None;
+    }
+    Some((name, trim_start(value)))
+}
+
+fn trim_start(data: &[u8]) -> &[u8] {
+    let start = data
+        .iter()
+        .position(|c| !c.is_ascii_whitespace())
+        .unwrap_or(data.len());
+    &data[start..]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{source_offset, to_scss};
+
+    fn check(sass: &str, scss: &str) {
+        let (result, _) = to_scss(sass.as_bytes()).unwrap();
+        assert_eq!(String::from_utf8(result).unwrap(), scss);
+    }
+
+    #[test]
+    fn nested_rules() {
+        check(
+            "a\n  color: red\n  b\n    x: y\nc\n  z: 1\n",
+            "a {\ncolor: red;\nb {\nx: y;\n}\n}\nc {\nz: 1;\n}\n",
+        );
+    }
+
+    #[test]
+    fn comments() {
+        check(
+            "// silent\n  still silent\n/* loud\n   comment\na\n  b: c // d\n",
+            "/* loud\n   comment */\na {\nb: c;\n}\n",
+        );
+    }
+
+    #[test]
+    fn mixins() {
+        check(
+            "=m($a)\n  b: $a\nc\n  +m(1)\n",
+            "@mixin m($a) {\nb: $a;\n}\nc {\n@include m(1);\n}\n",
+        );
+    }
+
+    #[test]
+    fn multiline_selector_and_args() {
+        check("a,\nb\n  c: f(1,\n    2)\n", "a,\nb {\nc: f(1,\n2);\n}\n");
+    }
+
+    #[test]
+    fn unquoted_import() {
+        check(
+            "@import foo, \"bar\", baz.css\n",
+            "@import \"foo\", \"bar\", \"baz.css\";\n",
+        );
+    }
+
+    #[test]
+    fn old_property_syntax() {
+        check("a\n  :color red\n", "a {\ncolor: red;\n}\n");
+    }
+
+    #[test]
+    fn url_is_not_comment() {
+        check(
+            "a\n  b: url(http://x.y/z)\n",
+            "a {\nb: url(http://x.y/z);\n}\n",
+        );
+    }
+
+    fn check_err(sass: &str, offset: usize, message: &str) {
+        assert_eq!(
+            to_scss(sass.as_bytes()).unwrap_err(),
+            (offset, message.to_string()),
+        );
+    }
+
+    #[test]
+    fn semicolons() {
+        let msg = "semicolons aren't allowed in the indented syntax.";
+        check_err("a\n  b: c;\n", 8, msg);
+        check_err("a\n  b: c; d: e\n", 8, msg);
+        check("a\n  b: url(x;y) \"z;\"\n", "a {\nb: url(x;y) \"z;\";\n}\n");
+    }
+
+    #[test]
+    fn braces() {
+        let msg = "braces aren't allowed in the indented syntax.";
+        check_err("a {\n  b: c\n}\n", 2, msg);
+        check_err("a\n  b: c }\n", 9, msg);
+        check("a-#{$x}\n  b: #{c}\n", "a-#{$x} {\nb: #{c};\n}\n");
+    }
+
+    #[test]
+    fn mixed_indentation() {
+        check_err("a\n \tb: c\n", 2, "Tabs and spaces may not be mixed.");
+        check_err("a\n  b: c\nd\n\te: f\n", 11, "Expected spaces, was tabs.");
+        check_err("a\n\tb: c\nd\n  e: f\n", 10, "Expected tabs, was spaces.");
+        check("a\n\tb\n\t\tc: d\n", "a {\nb {\nc: d;\n}\n}\n");
+    }
+
+    #[test]
+    fn inconsistent_indentation() {
+        check_err(
+            "a\n    b: c\n  d: e\n",
+            11,
+            "Inconsistent indentation, expected 4 spaces.",
+        );
+        check_err(
+            "  a\n    b: c\n",
+            0,
+            "Indenting at the beginning of the document is illegal.",
+        );
+    }
+
+    #[test]
+    fn offsets() {
+        let (scss, offsets) = to_scss(b"a\n  color: red\n").unwrap();
+        let at = scss.iter().position(|c| *c == b'c').unwrap();
+        assert_eq!(source_offset(&offsets, at), 4);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod formalargs;
+mod indented;
 pub mod selectors;
 mod strings;
 mod unit;
@@ -12,29 +13,33 @@ use self::util::{
     comment2, ignore_comments, ignore_space, opt_spacelike, spacelike,
 };
 use self::value::{
-    dictionary, function_call, single_value, value_expression,
+    dictionary, function_call, single_value, space_list, value_expression,
 };
 use crate::error::{ErrPos, Error};
 use crate::functions::SassFunction;
+use crate::importer::{FsImporter, Importer};
 #[cfg(test)]
 use crate::sass::{CallArgs, FormalArgs};
 use crate::sass::{Item, Value};
 use crate::selectors::Selectors;
+use crate::source_pos::{SourceFile, SourcePos};
 use crate::value::ListSeparator;
 #[cfg(test)]
 use crate::value::{Number, Rgba, Unit};
 use nom::branch::alt;
-use nom::bytes::complete::{is_a, is_not, tag};
+use nom::bytes::complete::{is_a, is_not, tag, tag_no_case};
 use nom::character::complete::one_of;
-use nom::combinator::{all_consuming, map, map_res, opt, peek, value};
+use nom::combinator::{
+    all_consuming, map, map_opt, map_res, not, opt, peek, value,
+};
 use nom::error::ErrorKind;
 use nom::multi::{many0, many_till, separated_list, separated_nonempty_list};
 use nom::sequence::{delimited, pair, preceded, terminated};
 use nom::{Err, IResult};
-use std::fs::File;
-use std::io::Read;
+use std::cell::RefCell;
 use std::path::Path;
 use std::str::{from_utf8, Utf8Error};
+use std::sync::Arc;
 
 /// Parse a scss value.
 ///
@@ -49,14 +54,13 @@ pub fn parse_value_data(data: &[u8]) -> Result<Value, Error> {
 ///
 /// Returns a vec of the top level items of the file (or an error message).
 pub fn parse_scss_file(file: &Path) -> Result<Vec<Item>, Error> {
-    let mut f = File::open(file).map_err(|e| Error::Input(file.into(), e))?;
-    let mut data = vec![];
-    f.read_to_end(&mut data)
-        .map_err(|e| Error::Input(file.into(), e))?;
-    parse_scss_data(&data).map_err(|(pos, kind)| Error::ParseError {
-        file: file.to_string_lossy().into(),
-        pos: ErrPos::pos_of(pos, &data),
-        kind,
+    let source = read_source(file)?;
+    parse_source(&source, source.data(), &[]).map_err(|(pos, kind)| {
+        Error::ParseError {
+            file: file.to_string_lossy().into(),
+            pos: ErrPos::pos_of(pos, source.data()),
+            kind,
+        }
     })
 }
 
@@ -66,6 +70,170 @@ pub fn parse_scss_file(file: &Path) -> Result<Vec<Item>, Error> {
 pub fn parse_scss_data(
     data: &[u8],
 ) -> Result<Vec<Item>, (usize, Option<ErrorKind>)> {
+    let source = Arc::new(SourceFile::new("-".into(), data.to_vec()));
+    parse_source(&source, source.data(), &[])
+}
+
+/// Parse a file in the indented sass syntax.
+///
+/// Returns a vec of the top level items of the file (or an error message).
+pub fn parse_sass_file(file: &Path) -> Result<Vec<Item>, Error> {
+    parse_indented(&read_source(file)?)
+}
+
+/// Parse data in the indented sass syntax from a buffer.
+///
+/// The result is the same kind of items as returned by
+/// [`parse_scss_data`] for the corresponding scss data.
+/// Error positions are in `data`.
+pub fn parse_sass_data(
+    data: &[u8],
+) -> Result<Vec<Item>, (usize, Option<ErrorKind>)> {
+    let source = Arc::new(SourceFile::new("-".into(), data.to_vec()));
+    let (scss, offsets) =
+        indented::to_scss(source.data()).map_err(|(pos, _)| (pos, None))?;
+    parse_converted(&source, &scss, &offsets)
+}
+
+/// Parse a sass or scss file, depending on the file name extension.
+///
+/// Files named `*.sass` are parsed in the indented syntax, all other
+/// files are parsed as scss.
+pub fn parse_file(file: &Path) -> Result<Vec<Item>, Error> {
+    parse_loaded(file, FsImporter::new().load(file)?)
+}
+
+/// Parse the loaded `data` of a file, in the syntax given by its name.
+pub(crate) fn parse_loaded(
+    file: &Path,
+    data: Vec<u8>,
+) -> Result<Vec<Item>, Error> {
+    let source =
+        Arc::new(SourceFile::new(file.to_string_lossy().into(), data));
+    if is_sass_file(file) {
+        parse_indented(&source)
+    } else {
+        parse_source(&source, source.data(), &[]).map_err(|(pos, kind)| {
+            Error::ParseError {
+                file: file.to_string_lossy().into(),
+                pos: ErrPos::pos_of(pos, source.data()),
+                kind,
+            }
+        })
+    }
+}
+
+fn is_sass_file(file: &Path) -> bool {
+    file.extension().map(|e| e == "sass").unwrap_or(false)
+}
+
+fn read_source(file: &Path) -> Result<Arc<SourceFile>, Error> {
+    let data = FsImporter::new().load(file)?;
+    Ok(Arc::new(SourceFile::new(
+        file.to_string_lossy().into(),
+        data,
+    )))
+}
+
+/// Parse a source file in the indented syntax.
+///
+/// Syntax that is only valid in scss is an error, located in the
+/// source.
+fn parse_indented(source: &Arc<SourceFile>) -> Result<Vec<Item>, Error> {
+    let (scss, offsets) =
+        indented::to_scss(source.data()).map_err(|(pos, msg)| {
+            Error::S(msg).at(Some(&SourcePos::new(source.clone(), pos)))
+        })?;
+    parse_converted(source, &scss, &offsets).map_err(|(pos, kind)| {
+        Error::ParseError {
+            file: source.name().into(),
+            pos: ErrPos::pos_of(pos, source.data()),
+            kind,
+        }
+    })
+}
+
+/// Parse `scss` converted from the indented syntax in `source`.
+///
+/// Error positions are in the source.
+fn parse_converted(
+    source: &Arc<SourceFile>,
+    scss: &[u8],
+    offsets: &[(usize, usize)],
+) -> Result<Vec<Item>, (usize, Option<ErrorKind>)> {
+    parse_source(source, scss, offsets)
+        .map_err(|(pos, kind)| (indented::source_offset(offsets, pos), kind))
+}
+
+thread_local! {
+    /// The source currently being parsed, to find the positions of items.
+    static SOURCE: RefCell<Option<Parsing>> = RefCell::new(None);
+}
+
+/// A source that is being parsed.
+struct Parsing {
+    file: Arc<SourceFile>,
+    /// Address and length of the data being parsed.
+    start: usize,
+    len: usize,
+    /// Offsets in `file` of parts of the data, if the data is not the
+    /// file content itself.
+    offsets: indented::Offsets,
+}
+
+/// Parse the scss `data` of a source file.
+///
+/// The `data` is the content of `source`, or is converted from it
+/// as described by `offsets`.
+fn parse_source(
+    source: &Arc<SourceFile>,
+    data: &[u8],
+    offsets: &[(usize, usize)],
+) -> Result<Vec<Item>, (usize, Option<ErrorKind>)> {
+    let parsing = Parsing {
+        file: source.clone(),
+        start: data.as_ptr() as usize,
+        len: data.len(),
+        offsets: offsets.to_vec(),
+    };
+    let outer = SOURCE.with(|s| s.replace(Some(parsing)));
+    let result = parse_data(data);
+    SOURCE.with(|s| s.replace(outer));
+    result
+}
+
+/// Get the position of `input` in the source currently being parsed.
+fn source_pos(input: &[u8]) -> SourcePos {
+    SOURCE.with(|s| match *s.borrow() {
+        Some(ref p) => {
+            let at = input.as_ptr() as usize;
+            if at >= p.start && at <= p.start + p.len {
+                let offset = if p.offsets.is_empty() {
+                    at - p.start
+                } else {
+                    indented::source_offset(&p.offsets, at - p.start)
+                        .min(p.file.data().len())
+                };
+                SourcePos::new(p.file.clone(), offset)
+            } else {
+                SourcePos::unknown()
+            }
+        }
+        None => SourcePos::unknown(),
+    })
+}
+
+/// Get `pos` spanning to where `input` starts.
+fn end_at(pos: SourcePos, input: &[u8]) -> SourcePos {
+    let end = source_pos(input);
+    if Arc::ptr_eq(end.file(), pos.file()) {
+        pos.with_end(end.offset())
+    } else {
+        pos
+    }
+}
+
+fn parse_data(data: &[u8]) -> Result<Vec<Item>, (usize, Option<ErrorKind>)> {
     match sassfile(data) {
         Ok((b"", items)) => Ok(items),
         Ok((rest, _styles)) => Err((data.len() - rest.len(), None)),
@@ -93,37 +261,51 @@ fn sassfile(input: &[u8]) -> IResult<&[u8], Vec<Item>> {
 }
 
 fn top_level_item(input: &[u8]) -> IResult<&[u8], Item> {
+    let pos = source_pos(input);
     let (input, tag) = alt((
         tag("$"),
         tag("/*"),
-        tag("@each"),
-        tag("@error"),
-        tag("@for"),
-        tag("@function"),
-        tag("@if"),
-        tag("@import"),
-        tag("@include"),
-        tag("@mixin"),
-        tag("@warn"),
-        tag("@while"),
+        alt((
+            terminated(tag("@debug"), not(name)),
+            tag("@each"),
+            tag("@error"),
+            tag("@extend"),
+            tag("@forward"),
+            tag("@for"),
+            tag("@function"),
+            tag("@if"),
+            tag("@import"),
+            tag("@include"),
+            tag("@mixin"),
+            tag("@use"),
+            tag("@warn"),
+            tag("@while"),
+        )),
         tag("@"),
         tag(""),
     ))(input)?;
     match tag {
-        b"$" => variable_declaration2(input),
+        b"$" => variable_declaration2(input, pos),
         b"/*" => comment_item(input),
-        b"@each" => each_loop2(input),
-        b"@error" => error2(input),
-        b"@for" => for_loop2(input),
+        b"@debug" => debug2(input, pos),
+        b"@each" => each_loop2(input, pos),
+        b"@error" => error2(input, pos),
+        b"@extend" => extend2(input),
+        b"@forward" => forward2(input, pos),
+        b"@for" => for_loop2(input, pos),
         b"@function" => function_declaration2(input),
-        b"@if" => if_statement2(input),
-        b"@import" => import2(input),
-        b"@include" => mixin_call2(input),
+        b"@if" => if_statement2(input, pos),
+        b"@import" => import2(input, pos),
+        b"@include" => mixin_call2(input, pos),
         b"@mixin" => mixin_declaration2(input),
-        b"@warn" => warn2(input),
-        b"@while" => while_loop2(input),
-        b"@" => at_rule2(input),
-        b"" => rule(input),
+        b"@use" => use2(input, pos),
+        b"@warn" => warn2(input, pos),
+        b"@while" => while_loop2(input, pos),
+        b"@" => at_rule2(input, pos),
+        b"" => alt((
+            |input| module_variable_declaration(input, pos.clone()),
+            |input| rule(input, pos.clone()),
+        ))(input),
         _ => unreachable!(),
     }
 }
@@ -137,10 +319,11 @@ fn comment_item(input: &[u8]) -> IResult<&[u8], Item> {
     Ok((rest, Item::Comment(comment)))
 }
 
-fn rule(input: &[u8]) -> IResult<&[u8], Item> {
-    map(pair(rule_start, body_block2), |(selectors, body)| {
-        Item::Rule(selectors, body)
-    })(input)
+fn rule(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
+    let (input, selectors) = rule_start(input)?;
+    let pos = end_at(pos, input);
+    let (input, body) = body_block2(input)?;
+    Ok((input, Item::Rule(selectors, body, pos)))
 }
 
 fn rule_start(input: &[u8]) -> IResult<&[u8], Selectors> {
@@ -148,50 +331,68 @@ fn rule_start(input: &[u8]) -> IResult<&[u8], Selectors> {
 }
 
 fn body_item(input: &[u8]) -> IResult<&[u8], Item> {
+    let pos = source_pos(input);
     let (input, tag) = alt((
         tag("$"),
         tag("/*"),
         tag(";"),
-        tag("@at-root"),
-        tag("@content"),
-        tag("@each"),
-        tag("@error"),
-        tag("@for"),
-        tag("@function"),
-        tag("@if"),
-        tag("@import"),
-        tag("@include"),
-        tag("@mixin"),
-        tag("@return"),
-        tag("@warn"),
-        tag("@while"),
+        alt((
+            tag("@at-root"),
+            tag("@content"),
+            terminated(tag("@debug"), not(name)),
+            tag("@each"),
+            tag("@error"),
+            tag("@extend"),
+            tag("@forward"),
+            tag("@for"),
+            tag("@function"),
+            tag("@if"),
+            tag("@import"),
+            tag("@include"),
+            tag("@mixin"),
+            tag("@return"),
+            tag("@use"),
+            tag("@warn"),
+            tag("@while"),
+        )),
         tag("@"),
         tag(""),
     ))(input)?;
     match tag {
-        b"$" => variable_declaration2(input),
+        b"$" => variable_declaration2(input, pos),
         b"/*" => comment_item(input),
         b";" => Ok((input, Item::None)),
         b"@at-root" => at_root2(input),
-        b"@content" => content_stmt2(input),
-        b"@error" => error2(input),
-        b"@each" => each_loop2(input),
-        b"@for" => for_loop2(input),
+        b"@content" => content_stmt2(input, pos),
+        b"@debug" => debug2(input, pos),
+        b"@error" => error2(input, pos),
+        b"@extend" => extend2(input),
+        b"@each" => each_loop2(input, pos),
+        b"@forward" => forward2(input, pos),
+        b"@for" => for_loop2(input, pos),
         b"@function" => function_declaration2(input),
-        b"@if" => if_statement2(input),
-        b"@import" => import2(input),
-        b"@include" => mixin_call2(input),
+        b"@if" => if_statement2(input, pos),
+        b"@import" => import2(input, pos),
+        b"@include" => mixin_call2(input, pos),
         b"@mixin" => mixin_declaration2(input),
-        b"@return" => return_stmt2(input),
-        b"@warn" => warn2(input),
-        b"@while" => while_loop2(input),
-        b"@" => at_rule2(input),
+        b"@return" => return_stmt2(input, pos),
+        b"@use" => use2(input, pos),
+        b"@warn" => warn2(input, pos),
+        b"@while" => while_loop2(input, pos),
+        b"@" => at_rule2(input, pos),
         b"" => {
+            if let Ok(result) =
+                module_variable_declaration(input, pos.clone())
+            {
+                return Ok(result);
+            }
             let (input, selectors) = opt(rule_start)(input)?;
             match selectors {
-                Some(selectors) => map(body_block2, |body| {
-                    Item::Rule(selectors.clone(), body)
-                })(input),
+                Some(selectors) => {
+                    let pos = end_at(pos, input);
+                    let (input, body) = body_block2(input)?;
+                    Ok((input, Item::Rule(selectors, body, pos)))
+                }
                 None => property_or_namespace_rule(input),
             }
         }
@@ -200,21 +401,110 @@ fn body_item(input: &[u8]) -> IResult<&[u8], Item> {
 }
 
 /// What follows the `@import` tag.
-fn import2(input: &[u8]) -> IResult<&[u8], Item> {
-    map(
+fn import2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
+    let (rest, (import, args)) = delimited(
+        tag(" "),
+        pair(
+            separated_list(preceded(tag(","), ignore_comments), single_value),
+            opt(media_args),
+        ),
+        preceded(opt(ignore_space), tag(";")),
+    )(input)?;
+    let pos = end_at(pos, rest);
+    Ok((rest, Item::Import(import, args.unwrap_or(Value::Null), pos)))
+}
+
+/// What follows the `@use` tag.
+fn use2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
+    let (input, url) = preceded(spacelike, module_url)(input)?;
+    let (input, namespace) = opt(preceded(
+        delimited(spacelike, tag("as"), spacelike),
+        alt((map(tag("*"), |_| "*".to_string()), name)),
+    ))(input)?;
+    let (input, with) = opt(preceded(
+        delimited(spacelike, tag("with"), opt_spacelike),
         delimited(
-            tag(" "),
-            pair(
+            terminated(tag("("), opt_spacelike),
+            terminated(
                 separated_list(
-                    preceded(tag(","), ignore_comments),
-                    single_value,
+                    delimited(opt_spacelike, tag(","), opt_spacelike),
+                    pair(
+                        preceded(tag("$"), name),
+                        preceded(
+                            delimited(opt_spacelike, tag(":"), opt_spacelike),
+                            space_list,
+                        ),
+                    ),
                 ),
-                opt(media_args),
+                opt(preceded(opt_spacelike, tag(","))),
             ),
-            preceded(opt(ignore_space), tag(";")),
+            preceded(opt_spacelike, tag(")")),
         ),
-        |(import, args)| Item::Import(import, args.unwrap_or(Value::Null)),
-    )(input)
+    ))(input)?;
+    let pos = end_at(pos, input);
+    let (input, _) = preceded(opt_spacelike, opt(tag(";")))(input)?;
+    Ok((
+        input,
+        Item::Use {
+            name: namespace.unwrap_or_else(|| default_namespace(&url)),
+            url,
+            with: with.unwrap_or_default(),
+            pos,
+        },
+    ))
+}
+
+/// What follows the `@forward` tag.
+fn forward2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
+    let (input, url) = preceded(spacelike, module_url)(input)?;
+    let (input, prefix) = opt(preceded(
+        delimited(spacelike, tag("as"), spacelike),
+        terminated(name, tag("*")),
+    ))(input)?;
+    let member_list = |input| {
+        separated_nonempty_list(
+            delimited(opt_spacelike, tag(","), opt_spacelike),
+            alt((map(preceded(tag("$"), name), |n| format!("${}", n)), name)),
+        )(input)
+    };
+    let (input, show) = opt(preceded(
+        delimited(spacelike, tag("show"), spacelike),
+        member_list,
+    ))(input)?;
+    let (input, hide) = opt(preceded(
+        delimited(spacelike, tag("hide"), spacelike),
+        member_list,
+    ))(input)?;
+    let pos = end_at(pos, input);
+    let (input, _) = preceded(opt_spacelike, opt(tag(";")))(input)?;
+    Ok((
+        input,
+        Item::Forward {
+            url,
+            prefix,
+            show,
+            hide: hide.unwrap_or_default(),
+            pos,
+        },
+    ))
+}
+
+/// The quoted url of a module in `@use` or `@forward`.
+fn module_url(input: &[u8]) -> IResult<&[u8], String> {
+    map_opt(alt((sass_string_dq, sass_string_sq)), |s| {
+        s.single_raw().map(String::from)
+    })(input)
+}
+
+/// The namespace of a module used without an explicit `as` clause.
+///
+/// This is the last component of the url, without any leading
+/// underscore or file extension.
+fn default_namespace(url: &str) -> String {
+    let name = url.rsplit(&['/', ':'][..]).next().unwrap_or(url);
+    let name = name.trim_start_matches('_');
+    let name = name.split('.').next().unwrap_or(name);
+    name.to_string()
 }
 
 /// What follows the `@at-root` tag.
@@ -233,13 +523,33 @@ fn at_root2(input: &[u8]) -> IResult<&[u8], Item> {
 
 #[cfg(test)] // TODO: Or remove this?
 fn mixin_call(input: &[u8]) -> IResult<&[u8], Item> {
-    preceded(tag("@include"), mixin_call2)(input)
+    let pos = source_pos(input);
+    let (input, _) = tag("@include")(input)?;
+    mixin_call2(input, pos)
 }
 
 /// What follows the `@include` tag.
-fn mixin_call2(input: &[u8]) -> IResult<&[u8], Item> {
-    let (input, name) = delimited(spacelike, name, opt_spacelike)(input)?;
+fn mixin_call2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
+    let (input, name) = delimited(
+        spacelike,
+        map(
+            pair(name, opt(preceded(tag("."), name))),
+            |(a, b)| match b {
+                Some(b) => format!("{}.{}", a, b),
+                None => a,
+            },
+        ),
+        opt_spacelike,
+    )(input)?;
     let (input, args) = terminated(opt(call_args), opt_spacelike)(input)?;
+    let (input, content_args) = terminated(
+        opt(preceded(
+            terminated(tag_no_case("using"), opt_spacelike),
+            formal_args,
+        )),
+        opt_spacelike,
+    )(input)?;
+    let pos = end_at(pos, input);
     let (input, body) = terminated(
         opt(body_block),
         terminated(opt_spacelike, opt(tag(";"))),
@@ -249,15 +559,18 @@ fn mixin_call2(input: &[u8]) -> IResult<&[u8], Item> {
         Item::MixinCall {
             name,
             args: args.unwrap_or_default(),
-            body: body.unwrap_or_default(),
+            content_args: content_args.unwrap_or_default(),
+            body,
+            pos,
         },
     ))
 }
 
 /// What follows an `@` sign (unless specifically handled).
-fn at_rule2(input: &[u8]) -> IResult<&[u8], Item> {
+fn at_rule2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, name) = name(input)?;
     let (input, args) = opt(media_args)(input)?;
+    let pos = end_at(pos, input);
     let (input, body) = preceded(
         opt(ignore_space),
         alt((
@@ -272,6 +585,7 @@ fn at_rule2(input: &[u8]) -> IResult<&[u8], Item> {
             name,
             args: args.unwrap_or(Value::Null),
             body,
+            pos,
         },
     ))
 }
@@ -338,16 +652,21 @@ fn test_media_args_2() {
 
 #[cfg(test)] // TODO: Or remove this?
 fn if_statement(input: &[u8]) -> IResult<&[u8], Item> {
-    preceded(tag("@if"), if_statement2)(input)
+    let pos = source_pos(input);
+    let (input, _) = tag("@if")(input)?;
+    if_statement2(input, pos)
 }
 
 fn if_statement_inner(input: &[u8]) -> IResult<&[u8], Item> {
-    preceded(tag("if"), if_statement2)(input)
+    let pos = source_pos(input);
+    let (input, _) = tag("if")(input)?;
+    if_statement2(input, pos)
 }
 
-fn if_statement2(input: &[u8]) -> IResult<&[u8], Item> {
+fn if_statement2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, cond) =
         delimited(spacelike, value_expression, opt_spacelike)(input)?;
+    let pos = end_at(pos, input);
     let (input, body) = body_block(input)?;
     let (input, else_body) = opt(preceded(
         delimited(opt_spacelike, tag("@else"), opt_spacelike),
@@ -355,12 +674,12 @@ fn if_statement2(input: &[u8]) -> IResult<&[u8], Item> {
     ))(input)?;
     Ok((
         input,
-        Item::IfStatement(cond, body, else_body.unwrap_or_default()),
+        Item::IfStatement(cond, body, else_body.unwrap_or_default(), pos),
     ))
 }
 
 /// The part of an each look that follows the `@each`.
-fn each_loop2(input: &[u8]) -> IResult<&[u8], Item> {
+fn each_loop2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, names) = preceded(
         spacelike,
         separated_nonempty_list(
@@ -373,12 +692,13 @@ fn each_loop2(input: &[u8]) -> IResult<&[u8], Item> {
         value_expression,
         spacelike,
     )(input)?;
+    let pos = end_at(pos, input);
     let (input, body) = body_block(input)?;
-    Ok((input, Item::Each(names, values, body)))
+    Ok((input, Item::Each(names, values, body, pos)))
 }
 
 /// A for loop after the initial `@for`.
-fn for_loop2(input: &[u8]) -> IResult<&[u8], Item> {
+fn for_loop2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, name) =
         delimited(preceded(spacelike, tag("$")), name, spacelike)(input)?;
     let (input, from) = delimited(
@@ -391,6 +711,7 @@ fn for_loop2(input: &[u8]) -> IResult<&[u8], Item> {
         spacelike,
     )(input)?;
     let (input, to) = terminated(single_value, opt_spacelike)(input)?;
+    let pos = end_at(pos, input);
     let (input, body) = body_block(input)?;
     Ok((
         input,
@@ -400,27 +721,46 @@ fn for_loop2(input: &[u8]) -> IResult<&[u8], Item> {
             to: Box::new(to),
             inclusive,
             body,
+            pos,
         },
     ))
 }
 
-fn warn2(input: &[u8]) -> IResult<&[u8], Item> {
+fn debug2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
+    let (input, arg) =
+        delimited(opt_spacelike, value_expression, opt(tag(";")))(input)?;
+    Ok((input, Item::Debug(arg, end_at(pos, input))))
+}
+
+fn warn2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, arg) =
         delimited(spacelike, value_expression, opt(tag(";")))(input)?;
-    Ok((input, Item::Warn(arg)))
+    Ok((input, Item::Warn(arg, end_at(pos, input))))
 }
 
-fn error2(input: &[u8]) -> IResult<&[u8], Item> {
+fn error2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, arg) =
         delimited(spacelike, value_expression, opt(tag(";")))(input)?;
-    Ok((input, Item::Error(arg)))
+    Ok((input, Item::Error(arg, end_at(pos, input))))
 }
 
-fn while_loop2(input: &[u8]) -> IResult<&[u8], Item> {
+/// What follows the `@extend` tag.
+fn extend2(input: &[u8]) -> IResult<&[u8], Item> {
+    let (input, target) =
+        delimited(spacelike, selectors, opt_spacelike)(input)?;
+    let (input, optional) = terminated(
+        map(opt(tag("!optional")), |o| o.is_some()),
+        pair(opt_spacelike, opt(tag(";"))),
+    )(input)?;
+    Ok((input, Item::Extend { target, optional }))
+}
+
+fn while_loop2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, cond) =
         delimited(spacelike, value_expression, spacelike)(input)?;
+    let pos = end_at(pos, input);
     let (input, body) = body_block(input)?;
-    Ok((input, Item::While(cond, body)))
+    Ok((input, Item::While(cond, body, pos)))
 }
 
 #[cfg(test)] // TODO: Or remove this?
@@ -455,21 +795,25 @@ fn function_declaration2(input: &[u8]) -> IResult<&[u8], Item> {
     ))
 }
 
-fn return_stmt2(input: &[u8]) -> IResult<&[u8], Item> {
+fn return_stmt2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, v) =
         delimited(opt_spacelike, value_expression, opt_spacelike)(input)?;
     let (input, _) = opt(tag(";"))(input)?;
-    Ok((input, Item::Return(v)))
+    Ok((input, Item::Return(v, end_at(pos, input))))
 }
 
-/// The "rest" of an `@content` statement is just an optional terminator
-fn content_stmt2(input: &[u8]) -> IResult<&[u8], Item> {
+/// What follows the `@content` tag: optional arguments and an optional
+/// terminator.
+fn content_stmt2(input: &[u8], pos: SourcePos) -> IResult<&[u8], Item> {
     let (input, _) = opt_spacelike(input)?;
+    let (input, args) = terminated(opt(call_args), opt_spacelike)(input)?;
     let (input, _) = opt(tag(";"))(input)?;
-    Ok((input, Item::Content))
+    let pos = end_at(pos, input);
+    Ok((input, Item::Content(args.unwrap_or_default(), pos)))
 }
 
 fn property_or_namespace_rule(input: &[u8]) -> IResult<&[u8], Item> {
+    let pos = source_pos(input);
     let (input, name) = terminated(
         sass_string,
         delimited(opt_spacelike, tag(":"), opt_spacelike),
@@ -477,6 +821,7 @@ fn property_or_namespace_rule(input: &[u8]) -> IResult<&[u8], Item> {
 
     let (input, val) =
         opt(terminated(value_expression, opt_spacelike))(input)?;
+    let pos = end_at(pos, input);
 
     let (input, next) = if val.is_some() {
         alt((tag("{"), tag(";"), tag("")))(input)?
@@ -492,7 +837,7 @@ fn property_or_namespace_rule(input: &[u8]) -> IResult<&[u8], Item> {
     };
     let (input, _) = opt_spacelike(input)?;
 
-    Ok((input, ns_or_prop_item(name, val, body)))
+    Ok((input, ns_or_prop_item(name, val, body, pos)))
 }
 
 use crate::sass::SassString;
@@ -500,11 +845,12 @@ fn ns_or_prop_item(
     name: SassString,
     value: Option<Value>,
     body: Option<Vec<Item>>,
+    pos: SourcePos,
 ) -> Item {
     if let Some(body) = body {
         Item::NamespaceRule(name, value.unwrap_or(Value::Null), body)
     } else if let Some(value) = value {
-        Item::Property(name, value)
+        Item::Property(name, value, pos)
     } else {
         unreachable!()
     }
@@ -527,24 +873,53 @@ fn body_block2(input: &[u8]) -> IResult<&[u8], Vec<Item>> {
 
 #[cfg(test)] // TODO: Or remove this?
 fn variable_declaration(input: &[u8]) -> IResult<&[u8], Item> {
-    preceded(tag("$"), variable_declaration2)(input)
+    let pos = source_pos(input);
+    let (input, _) = tag("$")(input)?;
+    variable_declaration2(input, pos)
 }
 
-fn variable_declaration2(input: &[u8]) -> IResult<&[u8], Item> {
+/// A declaration of a variable in a used module, `ns.$name: value;`.
+///
+/// The name of the declared variable is `ns.name`.
+fn module_variable_declaration(
+    input: &[u8],
+    pos: SourcePos,
+) -> IResult<&[u8], Item> {
+    let (input, ns) = terminated(name, tag(".$"))(input)?;
+    variable_declaration3(input, pos, Some(ns))
+}
+
+fn variable_declaration2(
+    input: &[u8],
+    pos: SourcePos,
+) -> IResult<&[u8], Item> {
+    variable_declaration3(input, pos, None)
+}
+
+fn variable_declaration3(
+    input: &[u8],
+    pos: SourcePos,
+    ns: Option<String>,
+) -> IResult<&[u8], Item> {
     let (input, name) = terminated(
         name,
         delimited(opt_spacelike, tag(":"), opt_spacelike),
     )(input)?;
+    let name = match ns {
+        Some(ns) => format!("{}.{}", ns, name),
+        None => name,
+    };
     let (input, val) = terminated(value_expression, opt_spacelike)(input)?;
-    let (input, default) = terminated(
-        map(opt(tag("!default")), |d| d.is_some()),
-        opt_spacelike,
-    )(input)?;
-    let (input, global) = terminated(
-        map(opt(tag("!global")), |g| g.is_some()),
+    // The flags may be given in any order, and even repeated.
+    let (input, flags) = many0(terminated(
+        alt((tag("!default"), tag("!global"))),
         opt_spacelike,
-    )(input)?;
-    let (input, _) = terminated(tag(";"), opt_spacelike)(input)?;
+    ))(input)?;
+    let default = flags.iter().any(|f| f == b"!default");
+    let global = flags.iter().any(|f| f == b"!global");
+    let (input, _) = tag(";")(input)?;
+    let pos = end_at(pos, input);
+    let (input, _) = opt_spacelike(input)?;
     Ok((
         input,
         Item::VariableDeclaration {
@@ -552,6 +927,7 @@ fn variable_declaration2(input: &[u8]) -> IResult<&[u8], Item> {
             val,
             default,
             global,
+            pos,
         },
     ))
 }
@@ -566,7 +942,7 @@ fn input_to_string(s: &[u8]) -> Result<String, Utf8Error> {
 
 #[cfg(test)]
 fn percentage(v: isize) -> Value {
-    Value::Numeric(Number::from(v), Unit::Percent)
+    Value::Numeric(Number::from(v), Unit::Percent.into())
 }
 
 #[cfg(test)]
@@ -584,9 +960,15 @@ fn if_with_no_else() {
                 Value::True,
                 vec![Item::Rule(
                     selectors(b"p").unwrap().1,
-                    vec![Item::Property("color".into(), Value::black())],
+                    vec![Item::Property(
+                        "color".into(),
+                        Value::black(),
+                        SourcePos::unknown(),
+                    )],
+                    SourcePos::unknown(),
                 )],
-                vec![]
+                vec![],
+                SourcePos::unknown(),
             )
         ))
     )
@@ -601,7 +983,9 @@ fn test_mixin_call_noargs() {
             Item::MixinCall {
                 name: "foo".to_string(),
                 args: CallArgs::new(vec![]),
-                body: vec![],
+                content_args: FormalArgs::default(),
+                body: None,
+                pos: SourcePos::unknown(),
             }
         ))
     )
@@ -619,7 +1003,9 @@ fn test_mixin_call_pos_args() {
                     (None, string("bar")),
                     (None, string("baz")),
                 ]),
-                body: vec![],
+                content_args: FormalArgs::default(),
+                body: None,
+                pos: SourcePos::unknown(),
             }
         ))
     )
@@ -637,7 +1023,32 @@ fn test_mixin_call_named_args() {
                     (Some("x".into()), string("bar")),
                     (Some("y".into()), string("baz")),
                 ]),
-                body: vec![],
+                content_args: FormalArgs::default(),
+                body: None,
+                pos: SourcePos::unknown(),
+            }
+        ))
+    )
+}
+
+#[test]
+fn test_mixin_call_using() {
+    assert_eq!(
+        mixin_call(b"@include foo using ($x) {\n  @content($x);\n}\n"),
+        Ok((
+            &b""[..],
+            Item::MixinCall {
+                name: "foo".to_string(),
+                args: CallArgs::default(),
+                content_args: FormalArgs::new(
+                    vec![("x".into(), None)],
+                    false
+                ),
+                body: Some(vec![Item::Content(
+                    CallArgs::new(vec![(None, Value::Variable("x".into()))]),
+                    SourcePos::unknown(),
+                )]),
+                pos: SourcePos::unknown(),
             }
         ))
     )
@@ -666,7 +1077,7 @@ fn test_mixin_declaration() {
             &b"\n"[..],
             Item::MixinDeclaration {
                 name: "foo".into(),
-                args: FormalArgs::new(vec![("x".into(), Value::Null)], false),
+                args: FormalArgs::new(vec![("x".into(), None)], false),
                 body: vec![Item::Property(
                     "foo-bar".into(),
                     Value::List(
@@ -675,6 +1086,7 @@ fn test_mixin_declaration() {
                         false,
                         false,
                     ),
+                    SourcePos::unknown(),
                 )],
             }
         ))
@@ -698,19 +1110,25 @@ fn test_mixin_declaration_default_and_subrules() {
                 name: "bar".into(),
                 args: FormalArgs::new(
                     vec![
-                        ("a".into(), Value::Null),
-                        ("b".into(), string("flug")),
+                        ("a".into(), None),
+                        ("b".into(), Some(string("flug"))),
                     ],
                     false
                 ),
                 body: vec![
-                    Item::Property("foo-bar".into(), string("baz")),
+                    Item::Property(
+                        "foo-bar".into(),
+                        string("baz"),
+                        SourcePos::unknown(),
+                    ),
                     Item::Rule(
                         selectors(b"foo, bar").unwrap().1,
                         vec![Item::Property(
                             "property".into(),
                             Value::Variable("b".into()),
+                            SourcePos::unknown(),
                         )],
+                        SourcePos::unknown(),
                     ),
                 ],
             }
@@ -727,6 +1145,7 @@ fn test_simple_property() {
             Item::Property(
                 "color".into(),
                 Value::Color(Rgba::from_rgb(255, 0, 0), Some("red".into())),
+                SourcePos::unknown(),
             )
         ))
     )
@@ -746,6 +1165,7 @@ fn test_property_2() {
                     false,
                     false
                 ),
+                SourcePos::unknown(),
             )
         ))
     )
@@ -762,6 +1182,7 @@ fn test_variable_declaration_simple() {
                 val: string("bar"),
                 default: false,
                 global: false,
+                pos: SourcePos::unknown(),
             }
         ))
     )
@@ -783,6 +1204,7 @@ fn test_variable_declaration_global() {
                 ),
                 default: false,
                 global: true,
+                pos: SourcePos::unknown(),
             }
         ))
     )
@@ -804,6 +1226,76 @@ fn test_variable_declaration_default() {
                 ),
                 default: true,
                 global: false,
+                pos: SourcePos::unknown(),
+            }
+        ))
+    )
+}
+
+#[test]
+fn test_use_default_namespace() {
+    assert_eq!(
+        top_level_item(b"@use \"foo/_bar.scss\";\n"),
+        Ok((
+            &b"\n"[..],
+            Item::Use {
+                url: "foo/_bar.scss".into(),
+                name: "bar".into(),
+                with: vec![],
+                pos: SourcePos::unknown(),
+            }
+        ))
+    )
+}
+
+#[test]
+fn test_use_as_with() {
+    assert_eq!(
+        top_level_item(b"@use 'lib' as l with ($a: x, $b-c: y,);\n"),
+        Ok((
+            &b"\n"[..],
+            Item::Use {
+                url: "lib".into(),
+                name: "l".into(),
+                with: vec![
+                    ("a".into(), string("x")),
+                    ("b-c".into(), string("y"))
+                ],
+                pos: SourcePos::unknown(),
+            }
+        ))
+    )
+}
+
+#[test]
+fn test_forward_prefix_show() {
+    assert_eq!(
+        top_level_item(b"@forward \"lib\" as lib-* show lib-a, $lib-b;\n"),
+        Ok((
+            &b"\n"[..],
+            Item::Forward {
+                url: "lib".into(),
+                prefix: Some("lib-".into()),
+                show: Some(vec!["lib-a".into(), "$lib-b".into()]),
+                hide: vec![],
+                pos: SourcePos::unknown(),
+            }
+        ))
+    )
+}
+
+#[test]
+fn test_mixin_call_namespaced() {
+    assert_eq!(
+        mixin_call(b"@include lib.foo;\n"),
+        Ok((
+            &b"\n"[..],
+            Item::MixinCall {
+                name: "lib.foo".to_string(),
+                args: CallArgs::new(vec![]),
+                content_args: FormalArgs::default(),
+                body: None,
+                pos: SourcePos::unknown(),
             }
         ))
     )
@@ -1,11 +1,16 @@
 use super::input_to_string;
-use super::strings::{sass_string, sass_string_dq, sass_string_sq};
+use super::strings::{
+    sass_string, sass_string_dq, sass_string_sq, selector_name,
+    string_part_interpolation,
+};
 use super::util::{opt_spacelike, spacelike2};
+use crate::sass::{SassString, StringPart};
 use crate::selectors::{Selector, SelectorPart, Selectors};
+use crate::value::Quotes;
 use nom::branch::alt;
 use nom::bytes::complete::{is_a, tag};
 use nom::character::complete::one_of;
-use nom::combinator::{map, map_res, opt, value};
+use nom::combinator::{map, map_res, not, opt, value, verify};
 use nom::multi::{many1, separated_nonempty_list};
 use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
@@ -28,13 +33,19 @@ pub fn selector(input: &[u8]) -> IResult<&[u8], Selector> {
 
 fn selector_part(input: &[u8]) -> IResult<&[u8], SelectorPart> {
     alt((
+        map(preceded(tag("."), name_string), SelectorPart::Class),
+        map(
+            preceded(terminated(tag("#"), not(tag("{"))), name_string),
+            SelectorPart::Id,
+        ),
+        map(preceded(tag("%"), name_string), SelectorPart::Placeholder),
+        type_or_universal,
         map(sass_string, SelectorPart::Simple),
-        value(SelectorPart::Simple("*".into()), tag("*")),
         map(
             preceded(
                 tag("::"),
                 pair(
-                    sass_string,
+                    name_string,
                     opt(delimited(tag("("), selectors, tag(")"))),
                 ),
             ),
@@ -44,7 +55,7 @@ fn selector_part(input: &[u8]) -> IResult<&[u8], SelectorPart> {
             preceded(
                 tag(":"),
                 pair(
-                    sass_string,
+                    name_string,
                     opt(delimited(tag("("), selectors, tag(")"))),
                 ),
             ),
@@ -111,6 +122,47 @@ fn selector_part(input: &[u8]) -> IResult<&[u8], SelectorPart> {
     ))(input)
 }
 
+/// A type selector or a universal selector, with optional namespace.
+fn type_or_universal(input: &[u8]) -> IResult<&[u8], SelectorPart> {
+    let (input, ns) = opt(terminated(
+        alt((
+            type_name,
+            value("*".into(), tag("*")),
+            value("".into(), tag("")),
+        )),
+        terminated(tag("|"), not(tag("="))),
+    ))(input)?;
+    let (input, name) =
+        alt((map(type_name, Some), value(None, tag("*"))))(input)?;
+    Ok((
+        input,
+        match name {
+            Some(name) => SelectorPart::Type { ns, name },
+            None => SelectorPart::Universal { ns },
+        },
+    ))
+}
+
+/// A name that may be a type name, i.e. does not start with a digit.
+fn type_name(input: &[u8]) -> IResult<&[u8], SassString> {
+    verify(name_string, |name: &SassString| {
+        !name
+            .single_raw()
+            .and_then(|s| s.chars().next())
+            .map(|c| c.is_ascii_digit())
+            .unwrap_or(false)
+    })(input)
+}
+
+/// The name of a class, id, type etc, possibly with interpolation.
+fn name_string(input: &[u8]) -> IResult<&[u8], SassString> {
+    let (input, parts) = many1(alt((
+        string_part_interpolation,
+        map(selector_name, StringPart::Raw),
+    )))(input)?;
+    Ok((input, SassString::new(parts, Quotes::None)))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -123,7 +175,10 @@ mod test {
             selector(b"foo "),
             Ok((
                 &b""[..],
-                Selector(vec![SelectorPart::Simple("foo".into())])
+                Selector(vec![SelectorPart::Type {
+                    ns: None,
+                    name: "foo".into()
+                }])
             ))
         )
     }
@@ -131,7 +186,13 @@ mod test {
     fn escaped_simple_selector() {
         assert_eq!(
             selector(b"\\E9m "),
-            Ok((&b""[..], Selector(vec![SelectorPart::Simple("ém".into())])))
+            Ok((
+                &b""[..],
+                Selector(vec![SelectorPart::Type {
+                    ns: None,
+                    name: "ém".into()
+                }])
+            ))
         )
     }
 
@@ -142,9 +203,15 @@ mod test {
             Ok((
                 &b""[..],
                 Selector(vec![
-                    SelectorPart::Simple("foo".into()),
+                    SelectorPart::Type {
+                        ns: None,
+                        name: "foo".into()
+                    },
                     SelectorPart::Descendant,
-                    SelectorPart::Simple("bar".into()),
+                    SelectorPart::Type {
+                        ns: None,
+                        name: "bar".into()
+                    },
                 ])
             ))
         )
@@ -157,9 +224,15 @@ mod test {
             Ok((
                 &b""[..],
                 Selector(vec![
-                    SelectorPart::Simple("foo".into()),
+                    SelectorPart::Type {
+                        ns: None,
+                        name: "foo".into()
+                    },
                     SelectorPart::RelOp(b'>'),
-                    SelectorPart::Simple("bar".into()),
+                    SelectorPart::Type {
+                        ns: None,
+                        name: "bar".into()
+                    },
                 ])
             ))
         )
@@ -204,7 +277,10 @@ mod test {
             Ok((
                 &b""[..],
                 Selector(vec![
-                    SelectorPart::Simple("figure".into()),
+                    SelectorPart::Type {
+                        ns: None,
+                        name: "figure".into()
+                    },
                     SelectorPart::Pseudo {
                         name: "before".into(),
                         arg: None,
@@ -221,10 +297,81 @@ mod test {
             Ok((
                 &b""[..],
                 Selectors::new(vec![
-                    Selector(vec![SelectorPart::Simple("foo".into())]),
-                    Selector(vec![SelectorPart::Simple("bar".into())]),
+                    Selector(vec![SelectorPart::Type {
+                        ns: None,
+                        name: "foo".into()
+                    }]),
+                    Selector(vec![SelectorPart::Type {
+                        ns: None,
+                        name: "bar".into()
+                    }]),
+                ])
+            ))
+        )
+    }
+
+    #[test]
+    fn compound_selector() {
+        assert_eq!(
+            selector(b"a.foo#bar%baz "),
+            Ok((
+                &b""[..],
+                Selector(vec![
+                    SelectorPart::Type {
+                        ns: None,
+                        name: "a".into(),
+                    },
+                    SelectorPart::Class("foo".into()),
+                    SelectorPart::Id("bar".into()),
+                    SelectorPart::Placeholder("baz".into()),
                 ])
             ))
         )
     }
+    #[test]
+    fn namespaced_selectors() {
+        assert_eq!(
+            selector(b"svg|rect *|* "),
+            Ok((
+                &b""[..],
+                Selector(vec![
+                    SelectorPart::Type {
+                        ns: Some("svg".into()),
+                        name: "rect".into(),
+                    },
+                    SelectorPart::Descendant,
+                    SelectorPart::Universal {
+                        ns: Some("*".into()),
+                    },
+                ])
+            ))
+        )
+    }
+    #[test]
+    fn keyframe_selector() {
+        assert_eq!(
+            selector(b"12.5% "),
+            Ok((
+                &b""[..],
+                Selector(vec![SelectorPart::Simple("12.5%".into())])
+            ))
+        )
+    }
+    #[test]
+    fn pseudo_with_selector_arg() {
+        assert_eq!(
+            selector(b":not(.a .b) "),
+            Ok((
+                &b""[..],
+                Selector(vec![SelectorPart::Pseudo {
+                    name: "not".into(),
+                    arg: Some(Selectors::new(vec![Selector(vec![
+                        SelectorPart::Class("a".into()),
+                        SelectorPart::Descendant,
+                        SelectorPart::Class("b".into()),
+                    ])])),
+                }])
+            ))
+        )
+    }
 }
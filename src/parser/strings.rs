@@ -32,11 +32,7 @@ pub fn special_function_misc(input: &[u8]) -> IResult<&[u8], SassString> {
         alt((
             map(
                 tuple((
-                    opt(delimited(
-                        tag("-"),
-                        alt((tag("moz"), tag("webkit"), tag("ms"))),
-                        tag("-"),
-                    )),
+                    opt(delimited(tag("-"), alphanumeric1, tag("-"))),
                     alt((
                         tag("calc"),
                         tag("element"),
@@ -155,7 +151,7 @@ pub fn special_url(input: &[u8]) -> IResult<&[u8], SassString> {
     let (input, mut parts) = many1(alt((
         string_part_interpolation,
         map(selector_string, StringPart::Raw),
-        map(map_res(is_a(":.!/"), input_to_string), StringPart::Raw),
+        map(map_res(is_a(":.!/;"), input_to_string), StringPart::Raw),
     )))(input)?;
     let (input, _end) = tag(")")(input)?;
     parts.insert(0, "url(".into());
@@ -199,7 +195,7 @@ pub fn sass_string_sq(input: &[u8]) -> IResult<&[u8], SassString> {
     Ok((input, SassString::new(parts, Quotes::Single)))
 }
 
-fn string_part_interpolation(input: &[u8]) -> IResult<&[u8], StringPart> {
+pub fn string_part_interpolation(input: &[u8]) -> IResult<&[u8], StringPart> {
     let (input, expr) =
         delimited(tag("#{"), value_expression, tag("}"))(input)?;
     Ok((input, StringPart::Interpolation(expr)))
@@ -231,6 +227,29 @@ fn selector_string(input: &[u8]) -> IResult<&[u8], String> {
     )(input)
 }
 
+/// A name in a selector, like the `foo` of the class selector `.foo`.
+///
+/// Unlike `selector_string`, this does not include `.`, `#`, `%` or
+/// `|`, so the simple selectors of a compound selector can be parsed
+/// separately.
+pub fn selector_name(input: &[u8]) -> IResult<&[u8], String> {
+    fold_many1(
+        alt((
+            map(name_char, String::from),
+            map(tag("\\ "), |_| "\\ ".to_string()),
+            map(tag("\\\""), |_| "\\\"".to_string()),
+            map(tag("\\\'"), |_| "\\\'".to_string()),
+            map(tag("\\\\"), |_| "\\\\".to_string()),
+            map(escaped_char, |c| format!("{}", c)),
+        )),
+        String::new(),
+        |mut acc: String, item: String| {
+            acc.push_str(&item);
+            acc
+        },
+    )(input)
+}
+
 fn selector_plain_part(input: &[u8]) -> IResult<&[u8], &str> {
     map_res(is_not("\r\n\t >$\"'\\#+*/()[]{}:;,=!&@"), input_to_str)(input)
 }
@@ -1,48 +1,140 @@
 use crate::value::Unit;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while1};
+use nom::combinator::{map, recognize, value};
+use nom::multi::many0;
+use nom::sequence::{pair, terminated};
 use nom::IResult;
+use std::str::from_utf8;
 
+/// Parse the unit of a number.
+///
+/// Units that are not known css units are kept as
+/// [`Unit::Unknown`](crate::Unit::Unknown).
 pub fn unit(input: &[u8]) -> IResult<&[u8], Unit> {
-    Ok(match input {
+    alt((
+        value(Unit::Percent, tag("%")),
+        map(unit_name, known_unit),
+        value(Unit::None, tag("")),
+    ))(input)
+}
+
+/// An identifier, except that a hyphen is only included if it is
+/// followed by a letter (so `2px-1px` is a subtraction).
+fn unit_name(input: &[u8]) -> IResult<&[u8], &str> {
+    let (rest, name) = recognize(pair(
+        take_while1(is_start_char),
+        many0(alt((
+            take_while1(is_name_char),
+            recognize(terminated(tag("-"), take_while1(is_start_char))),
+        ))),
+    ))(input)?;
+    // The chars are checked above, so the name is valid utf8.
+    Ok((rest, from_utf8(name).unwrap_or_default()))
+}
+
+fn is_start_char(c: u8) -> bool {
+    c.is_ascii_alphabetic() || c == b'_' || c >= 0x80
+}
+
+fn is_name_char(c: u8) -> bool {
+    is_start_char(c) || c.is_ascii_digit()
+}
+
+fn known_unit(name: &str) -> Unit {
+    match name {
         // Distance units, <length> type
-        v if v.starts_with(b"em") => (&v[2..], Unit::Em),
-        v if v.starts_with(b"ex") => (&v[2..], Unit::Ex),
-        v if v.starts_with(b"ch") => (&v[2..], Unit::Ch),
-        v if v.starts_with(b"rem") => (&v[3..], Unit::Rem),
-        v if v.starts_with(b"vw") => (&v[2..], Unit::Vw),
-        v if v.starts_with(b"vh") => (&v[2..], Unit::Vh),
-        v if v.starts_with(b"vmin") => (&v[4..], Unit::Vmin),
-        v if v.starts_with(b"vmax") => (&v[4..], Unit::Vmax),
-        v if v.starts_with(b"cm") => (&v[2..], Unit::Cm),
-        v if v.starts_with(b"mm") => (&v[2..], Unit::Mm),
-        v if v.starts_with(b"q") => (&v[1..], Unit::Q),
-        v if v.starts_with(b"in") => (&v[2..], Unit::In),
-        v if v.starts_with(b"pt") => (&v[2..], Unit::Pt),
-        v if v.starts_with(b"pc") => (&v[2..], Unit::Pc),
-        v if v.starts_with(b"px") => (&v[2..], Unit::Px),
+        "em" => Unit::Em,
+        "ex" => Unit::Ex,
+        "ch" => Unit::Ch,
+        "rem" => Unit::Rem,
+        "vw" => Unit::Vw,
+        "vh" => Unit::Vh,
+        "vmin" => Unit::Vmin,
+        "vmax" => Unit::Vmax,
+        "cm" => Unit::Cm,
+        "mm" => Unit::Mm,
+        "Q" | "q" => Unit::Q,
+        "in" => Unit::In,
+        "pt" => Unit::Pt,
+        "pc" => Unit::Pc,
+        "px" => Unit::Px,
 
         // <angle> type
-        v if v.starts_with(b"deg") => (&v[3..], Unit::Deg),
-        v if v.starts_with(b"grad") => (&v[4..], Unit::Grad),
-        v if v.starts_with(b"rad") => (&v[3..], Unit::Rad),
-        v if v.starts_with(b"turn") => (&v[4..], Unit::Turn),
+        "deg" => Unit::Deg,
+        "grad" => Unit::Grad,
+        "rad" => Unit::Rad,
+        "turn" => Unit::Turn,
 
         // <time> type
-        v if v.starts_with(b"s") => (&v[1..], Unit::S),
-        v if v.starts_with(b"ms") => (&v[2..], Unit::Ms),
+        "s" => Unit::S,
+        "ms" => Unit::Ms,
 
         // <frequency> type
-        v if v.starts_with(b"Hz") => (&v[2..], Unit::Hz),
-        v if v.starts_with(b"kHz") => (&v[3..], Unit::Khz),
+        "Hz" => Unit::Hz,
+        "kHz" => Unit::Khz,
 
         // <resolution>
-        v if v.starts_with(b"dpi") => (&v[3..], Unit::Dpi),
-        v if v.starts_with(b"dpcm") => (&v[4..], Unit::Dpcm),
-        v if v.starts_with(b"dppx") => (&v[4..], Unit::Dppx),
+        "dpi" => Unit::Dpi,
+        "dpcm" => Unit::Dpcm,
+        "dppx" => Unit::Dppx,
+        "x" => Unit::X,
 
         // Special units
-        v if v.starts_with(b"fr") => (&v[2..], Unit::Fr),
-        v if v.starts_with(b"%") => (&v[1..], Unit::Percent),
+        "fr" => Unit::Fr,
+
+        name => Unit::Unknown(name.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known() {
+        assert_eq!(unit(b"px;"), Ok((&b";"[..], Unit::Px)));
+        assert_eq!(unit(b"% "), Ok((&b" "[..], Unit::Percent)));
+    }
+
+    #[test]
+    fn quarter_millimeter() {
+        assert_eq!(unit(b"Q;"), Ok((&b";"[..], Unit::Q)));
+        assert_eq!(unit(b"q;"), Ok((&b";"[..], Unit::Q)));
+        assert_eq!(Unit::Q.to_string(), "Q");
+    }
+
+    #[test]
+    fn resolution_x() {
+        assert_eq!(unit(b"x,"), Ok((&b","[..], Unit::X)));
+        assert_eq!(Unit::X.to_string(), "x");
+        assert_eq!(Unit::X.conversion_factor(&Unit::Dppx), Some(1.into()));
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            unit(b"foo;"),
+            Ok((&b";"[..], Unit::Unknown("foo".into())))
+        );
+        assert_eq!(
+            unit(b"pxx "),
+            Ok((&b" "[..], Unit::Unknown("pxx".into())))
+        );
+    }
+
+    #[test]
+    fn hyphen() {
+        assert_eq!(unit(b"px-2px"), Ok((&b"-2px"[..], Unit::Px)));
+        assert_eq!(unit(b"px-$a"), Ok((&b"-$a"[..], Unit::Px)));
+        assert_eq!(
+            unit(b"foo-bar "),
+            Ok((&b" "[..], Unit::Unknown("foo-bar".into())))
+        );
+    }
 
-        v => (v, Unit::None),
-    })
+    #[test]
+    fn none() {
+        assert_eq!(unit(b" px"), Ok((&b" px"[..], Unit::None)));
+    }
 }
@@ -6,21 +6,21 @@ use super::strings::{
 use super::unit::unit;
 use super::util::{opt_spacelike, spacelike2};
 use super::{input_to_string, sass_string};
+use crate::css::CalcName;
 use crate::ordermap::OrderMap;
 use crate::sass::{SassString, Value};
-use crate::value::{ListSeparator, Number, Operator, Rgba};
+use crate::value::{ListSeparator, NumValue, Number, Operator, Rgba};
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{
-    alphanumeric1, multispace0, multispace1, one_of,
+    alphanumeric1, digit1, multispace0, multispace1, one_of,
 };
-use nom::combinator::{map, map_res, not, opt, peek, recognize, value};
-use nom::multi::{
-    fold_many0, fold_many1, many0, many_m_n, separated_nonempty_list,
+use nom::combinator::{
+    map, map_opt, map_res, not, opt, peek, recognize, value, verify,
 };
+use nom::multi::{fold_many0, many0, many_m_n, separated_nonempty_list};
 use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
-use num_rational::Rational;
 use std::str::from_utf8;
 
 pub fn value_expression(input: &[u8]) -> IResult<&[u8], Value> {
@@ -43,7 +43,8 @@ pub fn value_expression(input: &[u8]) -> IResult<&[u8], Value> {
 pub fn space_list(input: &[u8]) -> IResult<&[u8], Value> {
     let (input, first) = se_or_ext_string(input)?;
     let (input, list) = fold_many0(
-        pair(multispace0, se_or_ext_string),
+        // A trailing `...` marks a rest argument, it is not a list item.
+        pair(multispace0, preceded(not(tag("...")), se_or_ext_string)),
         (vec![first], false),
         |(mut list, mut unreq): (Vec<Value>, bool), (s, item)| {
             let mut appended = false;
@@ -232,9 +233,11 @@ fn simple_value(input: &[u8]) -> IResult<&[u8], Value> {
         bracket_list,
         number,
         variable,
+        namespaced_variable,
         hex_color,
         value(Value::Null, tag("null")),
         map(special_url, Value::Literal),
+        calculation,
         special_function,
         // Really ugly special case ... sorry.
         value(Value::Literal("-null".into()), tag("-null")),
@@ -295,57 +298,61 @@ fn bracket_list(input: &[u8]) -> IResult<&[u8], Value> {
 }
 
 fn number(input: &[u8]) -> IResult<&[u8], Value> {
-    let (input, (sign, (lead_zero, num), unit)) = tuple((
+    let (input, (sign, (lead_zero, num), exp, unit)) = tuple((
         opt(alt((tag("-"), tag("+")))),
         alt((
-            map(pair(decimal_integer, opt(decimal_decimals)), |(n, d)| {
-                (true, if let Some(d) = d { n + d } else { n })
+            map_opt(
+                pair(digit1, opt(preceded(tag("."), digit1))),
+                |(n, d)| {
+                    Some((true, NumValue::from_decimal(n, d.unwrap_or(b""))?))
+                },
+            ),
+            map_opt(preceded(tag("."), digit1), |d| {
+                Some((false, NumValue::from_decimal(b"", d)?))
             }),
-            map(decimal_decimals, |dec| (false, dec)),
+        )),
+        opt(preceded(
+            alt((tag("e"), tag("E"))),
+            recognize(pair(opt(alt((tag("-"), tag("+")))), digit1)),
         )),
         unit,
     ))(input)?;
+    let num = match exp {
+        Some(exp) => {
+            // Exponents that does not fit in i64 are far out of range
+            // anyway, so just use a large exponent.
+            let exp = from_utf8(exp)
+                .ok()
+                .and_then(|e| e.parse().ok())
+                .unwrap_or(if exp[0] == b'-' { -1000 } else { 1000 });
+            &num * &NumValue::pow10(exp)
+        }
+        None => num,
+    };
     Ok((
         input,
         Value::Numeric(
             Number {
-                value: if sign == Some(b"-") { -num } else { num },
+                value: if sign == Some(b"-") { -&num } else { num },
                 plus_sign: sign == Some(b"+"),
                 lead_zero,
             },
-            unit,
+            unit.into(),
         ),
     ))
 }
 
-pub fn decimal_integer(input: &[u8]) -> IResult<&[u8], Rational> {
-    map(
-        fold_many1(
-            // Note: We should use bytes directly, one_of returns a char.
-            one_of("0123456789"),
-            0,
-            |r, d| r * 10 + isize::from(d as u8 - b'0'),
-        ),
-        Rational::from_integer,
-    )(input)
-}
-
-pub fn decimal_decimals(input: &[u8]) -> IResult<&[u8], Rational> {
-    map(
-        preceded(
-            tag("."),
-            fold_many1(one_of("0123456789"), (0, 1), |(r, n), d| {
-                (r * 10 + isize::from(d as i8 - b'0' as i8), n * 10)
-            }),
-        ),
-        |(r, d)| Rational::new(r, d),
-    )(input)
-}
-
 pub fn variable(input: &[u8]) -> IResult<&[u8], Value> {
     map(preceded(tag("$"), name), Value::Variable)(input)
 }
 
+/// A variable from a module, like `ns.$name`.
+fn namespaced_variable(input: &[u8]) -> IResult<&[u8], Value> {
+    map(pair(name, preceded(tag(".$"), name)), |(ns, name)| {
+        Value::Variable(format!("{}.{}", ns, name))
+    })(input)
+}
+
 fn hex_color(input: &[u8]) -> IResult<&[u8], Value> {
     let (rest, rgba) = delimited(
         tag("#"),
@@ -388,6 +395,96 @@ pub fn unary_op(input: &[u8]) -> IResult<&[u8], Value> {
     )(input)
 }
 
+/// A css calculation, like `calc(100% - $gutter)` or `min($a, 2vw)`.
+///
+/// This fails if the arguments are not valid calculation
+/// expressions, so the function can be parsed as a special string or
+/// a plain function call instead.
+fn calculation(input: &[u8]) -> IResult<&[u8], Value> {
+    let (input, (name, args)) = verify(
+        pair(
+            terminated(
+                alt((
+                    value(CalcName::Calc, tag_no_case("calc")),
+                    value(CalcName::Min, tag_no_case("min")),
+                    value(CalcName::Max, tag_no_case("max")),
+                    value(CalcName::Clamp, tag_no_case("clamp")),
+                )),
+                terminated(tag("("), opt_spacelike),
+            ),
+            terminated(separated_nonempty_list(comma, calc_sum), end_paren),
+        ),
+        |(name, args): &(CalcName, Vec<Value>)| match name {
+            CalcName::Calc => args.len() == 1,
+            CalcName::Clamp => args.len() == 3,
+            CalcName::Min | CalcName::Max => true,
+        },
+    )(input)?;
+    Ok((input, Value::Calculation(name, args)))
+}
+
+fn calc_sum(input: &[u8]) -> IResult<&[u8], Value> {
+    let (input, a) = calc_product(input)?;
+    fold_many0(
+        pair(
+            delimited(
+                spacelike2,
+                alt((
+                    value(Operator::Plus, tag("+")),
+                    value(Operator::Minus, tag("-")),
+                )),
+                spacelike2,
+            ),
+            calc_product,
+        ),
+        a,
+        |a, (op, b)| Value::BinOp(Box::new(a), true, op, true, Box::new(b)),
+    )(input)
+}
+
+fn calc_product(input: &[u8]) -> IResult<&[u8], Value> {
+    let (input, a) = calc_value(input)?;
+    fold_many0(
+        pair(
+            delimited(
+                opt_spacelike,
+                alt((
+                    value(Operator::Multiply, tag("*")),
+                    value(Operator::Div, tag("/")),
+                )),
+                opt_spacelike,
+            ),
+            calc_value,
+        ),
+        a,
+        |a, (op, b)| Value::BinOp(Box::new(a), true, op, true, Box::new(b)),
+    )(input)
+}
+
+fn calc_value(input: &[u8]) -> IResult<&[u8], Value> {
+    alt((
+        map(
+            delimited(
+                terminated(tag("("), opt_spacelike),
+                calc_sum,
+                end_paren,
+            ),
+            |v| Value::Paren(Box::new(v), false),
+        ),
+        calculation,
+        number,
+        variable,
+        namespaced_variable,
+        map(
+            preceded(tag("-"), alt((variable, namespaced_variable))),
+            |v| Value::UnaryOp(Operator::Minus, Box::new(v)),
+        ),
+        map(special_function_misc, Value::Literal),
+        function_call,
+        map(sass_string, Value::Literal),
+    ))(input)
+}
+
 fn special_function(input: &[u8]) -> IResult<&[u8], Value> {
     map(
         alt((special_function_misc, special_function_minmax)),
@@ -456,7 +553,7 @@ mod test {
     use super::*;
     use crate::sass::CallArgs;
     use crate::sass::Value::*;
-    use crate::value::Unit;
+    use crate::value::UnitSet;
     use crate::variablescope::GlobalScope;
     use nom::combinator::all_consuming;
     use num_rational::Rational;
@@ -477,11 +574,11 @@ mod test {
             "+4;",
             Numeric(
                 Number {
-                    value: Rational::new(4, 1),
+                    value: Rational::new(4, 1).into(),
                     plus_sign: true,
                     lead_zero: true,
                 },
-                Unit::None,
+                UnitSet::scalar(),
             ),
         )
     }
@@ -496,11 +593,11 @@ mod test {
             ".34;",
             Numeric(
                 Number {
-                    value: Rational::new(34, 100),
+                    value: Rational::new(34, 100).into(),
                     plus_sign: false,
                     lead_zero: false,
                 },
-                Unit::None,
+                UnitSet::scalar(),
             ),
         )
     }
@@ -510,11 +607,11 @@ mod test {
             "-.34;",
             Numeric(
                 Number {
-                    value: Rational::new(-34, 100),
+                    value: Rational::new(-34, 100).into(),
                     plus_sign: false,
                     lead_zero: false,
                 },
-                Unit::None,
+                UnitSet::scalar(),
             ),
         )
     }
@@ -524,17 +621,17 @@ mod test {
             "+.34;",
             Numeric(
                 Number {
-                    value: Rational::new(34, 100), // actually 17/50
+                    value: Rational::new(34, 100).into(), // actually 17/50
                     plus_sign: true,
                     lead_zero: false,
                 },
-                Unit::None,
+                UnitSet::scalar(),
             ),
         )
     }
 
     fn number(nom: isize, denom: isize) -> Value {
-        Numeric(Number::from(Rational::new(nom, denom)), Unit::None)
+        Numeric(Number::from(Rational::new(nom, denom)), UnitSet::scalar())
     }
 
     #[test]
@@ -555,6 +652,11 @@ mod test {
         check_expr("$red;", Variable("red".into()))
     }
 
+    #[test]
+    fn simple_value_namespaced_variable() {
+        check_expr("math.$pi;", Variable("math.pi".into()))
+    }
+
     #[test]
     fn paren_literal() {
         check_expr("(rad);", Paren(Box::new(Literal("rad".into())), false))
@@ -1,5 +1,6 @@
 use crate::css;
 use crate::error::Error;
+use crate::ordermap::OrderMap;
 use crate::sass::Value;
 use crate::variablescope::Scope;
 use std::default::Default;
@@ -8,20 +9,38 @@ use std::default::Default;
 ///
 /// Each argument has a Value.  Arguments may be named.
 /// If the optional name is None, the argument is positional.
+/// The arguments may end with a rest argument (as in `foo($args...)`),
+/// a list, map or argument list that is expanded to several
+/// arguments, optionally followed by a map of keyword arguments (as in
+/// `foo($list..., $map...)`).
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct CallArgs(Vec<(Option<String>, Value)>);
+pub struct CallArgs(
+    Vec<(Option<String>, Value)>,
+    Option<Box<Value>>,
+    Option<Box<Value>>,
+);
 
 impl CallArgs {
     pub fn new(v: Vec<(Option<String>, Value)>) -> Self {
-        CallArgs(v)
+        CallArgs(v, None, None)
+    }
+
+    /// Create call arguments with a rest argument and/or a keyword
+    /// rest argument.
+    pub fn new_with_rest(
+        v: Vec<(Option<String>, Value)>,
+        rest: Option<Value>,
+        kw_rest: Option<Value>,
+    ) -> Self {
+        CallArgs(v, rest.map(Box::new), kw_rest.map(Box::new))
     }
 
     pub fn from_value(v: Value) -> Self {
         match v {
             Value::List(v, _, false, _) => {
-                CallArgs(v.into_iter().map(|v| (None, v)).collect())
+                CallArgs::new(v.into_iter().map(|v| (None, v)).collect())
             }
-            v => CallArgs(vec![(None, v)]),
+            v => CallArgs::new(vec![(None, v)]),
         }
     }
 
@@ -46,18 +65,85 @@ impl CallArgs {
         scope: &dyn Scope,
         arithmetic: bool,
     ) -> Result<css::CallArgs, Error> {
-        let args = self.0
+        self.evaluate_with(scope, arithmetic, |v| {
+            v.do_evaluate(scope, arithmetic)
+        })
+    }
+
+    /// Evaluate the arguments, using `eval` for each argument that is
+    /// given explicitly (i.e. not by a rest argument).
+    pub(crate) fn evaluate_with<F>(
+        &self,
+        scope: &dyn Scope,
+        arithmetic: bool,
+        eval: F,
+    ) -> Result<css::CallArgs, Error>
+    where
+        F: Fn(&Value) -> Result<css::Value, Error>,
+    {
+        let mut args = self.0
                 .iter()
                 .map(|&(ref n, ref v)| -> Result<(Option<String>, css::Value), Error> {
-                    Ok((n.clone(), v.do_evaluate(scope, arithmetic)?))
+                    Ok((n.clone(), eval(v)?))
                 })
                 .collect::<Result<Vec<_>, Error>>()?;
+        if let Some(rest) = &self.1 {
+            // Positional arguments from the rest argument go after
+            // the other positional arguments, before any named.
+            let pos = args.iter().take_while(|(n, _)| n.is_none()).count();
+            let (positional, named) =
+                match rest.do_evaluate(scope, arithmetic)? {
+                    css::Value::ArgList(rest) => (
+                        rest.positional(),
+                        rest.named()
+                            .map(|(n, v)| (Some(n.to_string()), v.clone()))
+                            .collect(),
+                    ),
+                    css::Value::Map(map) => (vec![], keyword_args(map)?),
+                    css::Value::List(v, _, _) => (v, vec![]),
+                    v => (vec![v], vec![]),
+                };
+            args.splice(pos..pos, positional.into_iter().map(|v| (None, v)));
+            args.extend(named);
+        }
+        if let Some(kw_rest) = &self.2 {
+            match kw_rest.do_evaluate(scope, arithmetic)? {
+                css::Value::Map(map) => args.extend(keyword_args(map)?),
+                css::Value::List(ref v, ..) if v.is_empty() => (),
+                v => {
+                    return Err(Error::S(format!(
+                        "Variable keyword arguments must be a map (was {}).",
+                        v.format(Default::default()),
+                    )))
+                }
+            }
+        }
         Ok(css::CallArgs(args))
     }
 }
 
+/// Convert a map spread as arguments (as in `foo($map...)`) to named
+/// arguments.
+fn keyword_args(
+    map: OrderMap<css::Value, css::Value>,
+) -> Result<Vec<(Option<String>, css::Value)>, Error> {
+    map.iter()
+        .map(|(k, v)| match k {
+            css::Value::Literal(name, _) => {
+                Ok((Some(name.replace('-', "_")), v.clone()))
+            }
+            k => Err(Error::S(format!(
+                "Variable keyword argument map must have string keys.\n\
+                 {} is not a string in {}.",
+                k.format(Default::default()),
+                css::Value::Map(map.clone()).format(Default::default()),
+            ))),
+        })
+        .collect()
+}
+
 impl Default for CallArgs {
     fn default() -> Self {
-        CallArgs(vec![])
+        CallArgs::new(vec![])
     }
 }
@@ -1,7 +1,6 @@
 use crate::css;
 use crate::error::Error;
 use crate::sass::Value;
-use crate::value::ListSeparator;
 use crate::variablescope::{Scope, ScopeImpl};
 use std::default::Default;
 
@@ -9,46 +8,100 @@ use std::default::Default;
 ///
 /// The arguments are ordered (so they have a position).
 /// Each argument also has a name and may have a default value.
+/// An argument without a default value is required.
+/// If the declaration is variadic (as in `@mixin foo($args...)`),
+/// the last argument is the rest argument.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct FormalArgs(Vec<(String, Value)>, bool);
+pub struct FormalArgs(Vec<(String, Option<Value>)>, bool);
 
 impl FormalArgs {
-    pub fn new(a: Vec<(String, Value)>, is_varargs: bool) -> Self {
+    pub fn new(a: Vec<(String, Option<Value>)>, is_varargs: bool) -> Self {
         FormalArgs(a, is_varargs)
     }
 
+    /// Evaluate these formal arguments with the given actual
+    /// arguments.
+    ///
+    /// Returns a scope where each formal argument is defined.
+    /// It is an error if a required argument is missing, or if there
+    /// are too many positional arguments or a named argument does not
+    /// match any formal argument (unless this is variadic).
     pub fn eval<'a>(
         &self,
         scope: &'a dyn Scope,
         args: &css::CallArgs,
     ) -> Result<ScopeImpl<'a>, Error> {
         let mut argscope = ScopeImpl::sub(scope);
-        let n = self.0.len();
-        for (i, &(ref name, ref default)) in self.0.iter().enumerate() {
-            if let Some(value) = args
-                .iter()
-                .find(|&&(ref k, ref _v)| k.as_ref() == Some(name))
-                .map(|&(ref _k, ref v)| v)
-            {
+        let positional = args.positional();
+        let n = if self.1 {
+            self.0.len() - 1
+        } else {
+            self.0.len()
+        };
+        for (i, &(ref name, ref default)) in self.0[..n].iter().enumerate() {
+            let named = args.named().find(|(k, _)| k == name).map(|a| a.1);
+            if let Some(value) = positional.get(i) {
+                if named.is_some() {
+                    return Err(Error::S(format!(
+                        "Argument ${} was passed both by position and by \
+                         name.",
+                        name.replace('_', "-"),
+                    )));
+                }
                 argscope.define(name, value);
-            } else if self.1 && i + 1 == n && args.len() > n {
-                let args = args
-                    .iter()
-                    .skip(i)
-                    .map(|&(_, ref v)| v.clone())
-                    .collect();
-                argscope.define(
-                    name,
-                    &css::Value::List(args, ListSeparator::Comma, false),
-                );
+            } else if let Some(value) = named {
+                argscope.define(name, value);
+            } else if let Some(default) = default {
+                let v = default.do_evaluate(&argscope, true)?;
+                argscope.define(name, &v);
             } else {
-                match args.get(i) {
-                    Some(&(None, ref v)) => argscope.define(name, v),
-                    _ => {
-                        let v = default.do_evaluate(&argscope, true)?;
-                        argscope.define(name, &v)
-                    }
-                };
+                return Err(Error::S(format!(
+                    "Missing argument ${}.",
+                    name.replace('_', "-"),
+                )));
+            }
+        }
+        let is_unknown = |k: &str| !self.0[..n].iter().any(|(f, _)| f == k);
+        if let Some((name, _)) = self.0.get(n) {
+            // The rest argument gets all remaining positional
+            // arguments, and all named arguments that do not
+            // match a formal argument.
+            let rest = positional
+                .into_iter()
+                .skip(n)
+                .map(|v| (None, v))
+                .chain(
+                    args.named()
+                        .filter(|(k, _)| is_unknown(k))
+                        .map(|(k, v)| (Some(k.to_string()), v.clone())),
+                )
+                .collect();
+            argscope.define(name, &css::Value::ArgList(css::CallArgs(rest)));
+        } else {
+            if positional.len() > n {
+                return Err(Error::S(format!(
+                    "Only {} argument{} allowed, but {} {} passed.",
+                    n,
+                    if n == 1 { "" } else { "s" },
+                    positional.len(),
+                    if positional.len() == 1 { "was" } else { "were" },
+                )));
+            }
+            let unknown = args
+                .named()
+                .filter(|(k, _)| is_unknown(k))
+                .map(|(k, _)| format!("${}", k.replace('_', "-")))
+                .collect::<Vec<_>>();
+            if let Some((last, init)) = unknown.split_last() {
+                return Err(Error::S(if init.is_empty() {
+                    format!("No argument named {}.", last)
+                } else {
+                    format!(
+                        "No arguments named {} or {}.",
+                        init.join(", "),
+                        last,
+                    )
+                }));
             }
         }
         Ok(argscope)
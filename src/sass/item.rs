@@ -1,17 +1,44 @@
 use crate::functions::SassFunction;
 use crate::sass::{CallArgs, FormalArgs, SassString, Value};
 use crate::selectors::Selectors;
+use crate::source_pos::SourcePos;
 
 /// Every sass file is a sequence of sass items.
 /// Scoping items contains further sequences of items.
+///
+/// Most items keep the source position where they start, to
+/// locate errors and for source maps.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Item {
-    Import(Vec<Value>, Value),
+    Import(Vec<Value>, Value, SourcePos),
+    /// An `@use` rule, loading a module.
+    Use {
+        url: String,
+        /// The namespace for the module members, or `"*"` to make
+        /// them available without namespace.
+        name: String,
+        /// Configuration for `!default` variables in the module.
+        with: Vec<(String, Value)>,
+        pos: SourcePos,
+    },
+    /// An `@forward` rule, making members of another module available
+    /// to users of this module.
+    Forward {
+        url: String,
+        prefix: Option<String>,
+        /// Members to forward, if not all.
+        /// Variable names are given with a leading `$`.
+        show: Option<Vec<String>>,
+        /// Members not to forward.
+        hide: Vec<String>,
+        pos: SourcePos,
+    },
     VariableDeclaration {
         name: String,
         val: Value,
         default: bool,
         global: bool,
+        pos: SourcePos,
     },
     AtRoot {
         selectors: Selectors,
@@ -21,43 +48,86 @@ pub enum Item {
         name: String,
         args: Value,
         body: Option<Vec<Item>>,
+        pos: SourcePos,
+    },
+    Error(Value, SourcePos),
+    /// An `@extend` of the target selectors by the current rule.
+    Extend {
+        target: Selectors,
+        optional: bool,
     },
-    Error(Value),
 
     MixinDeclaration {
         name: String,
         args: FormalArgs,
         body: Vec<Item>,
     },
+    /// An `@include` of a mixin.
     MixinCall {
         name: String,
         args: CallArgs,
-        body: Vec<Item>,
+        /// The arguments of the content block, as declared by `using`.
+        content_args: FormalArgs,
+        /// The content block, if any.
+        body: Option<Vec<Item>>,
+        pos: SourcePos,
     },
-    Content,
+    /// An `@content` rule, with arguments for the content block.
+    Content(CallArgs, SourcePos),
 
     FunctionDeclaration {
         name: String,
         func: SassFunction,
     },
-    Return(Value),
+    Return(Value, SourcePos),
 
-    IfStatement(Value, Vec<Item>, Vec<Item>),
+    IfStatement(Value, Vec<Item>, Vec<Item>, SourcePos),
     /// The value may be or evaluate to a list.
-    Each(Vec<String>, Value, Vec<Item>),
+    Each(Vec<String>, Value, Vec<Item>, SourcePos),
     For {
         name: String,
         from: Box<Value>,
         to: Box<Value>,
         inclusive: bool,
         body: Vec<Item>,
+        pos: SourcePos,
     },
-    While(Value, Vec<Item>),
+    While(Value, Vec<Item>, SourcePos),
 
-    Rule(Selectors, Vec<Item>),
+    /// A rule with selectors, a body and the source position of
+    /// the rule.
+    Rule(Selectors, Vec<Item>, SourcePos),
     NamespaceRule(SassString, Value, Vec<Item>),
-    Property(SassString, Value),
+    /// A property declaration, with its source position.
+    Property(SassString, Value, SourcePos),
     Comment(String),
-    Warn(Value),
+    Warn(Value, SourcePos),
+    Debug(Value, SourcePos),
     None,
 }
+
+impl Item {
+    /// Get the source position of this item, if it has one.
+    pub fn pos(&self) -> Option<&SourcePos> {
+        match self {
+            Item::Import(_, _, pos)
+            | Item::Use { pos, .. }
+            | Item::Forward { pos, .. }
+            | Item::VariableDeclaration { pos, .. }
+            | Item::AtRule { pos, .. }
+            | Item::Error(_, pos)
+            | Item::MixinCall { pos, .. }
+            | Item::Content(_, pos)
+            | Item::Return(_, pos)
+            | Item::IfStatement(_, _, _, pos)
+            | Item::Each(_, _, _, pos)
+            | Item::For { pos, .. }
+            | Item::While(_, _, pos)
+            | Item::Rule(_, _, pos)
+            | Item::Property(_, _, pos)
+            | Item::Warn(_, pos)
+            | Item::Debug(_, pos) => Some(pos),
+            _ => None,
+        }
+    }
+}
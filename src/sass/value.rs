@@ -1,9 +1,9 @@
-use crate::css;
+use crate::css::{self, CalcName, CalcValue, Calculation};
 use crate::error::Error;
-use crate::functions::get_builtin_function;
+use crate::logger;
 use crate::ordermap::OrderMap;
 use crate::sass::{CallArgs, SassString};
-use crate::value::{ListSeparator, Number, Operator, Quotes, Rgba, Unit};
+use crate::value::{ListSeparator, Number, Operator, Quotes, Rgba, UnitSet};
 use crate::variablescope::Scope;
 use num_rational::Rational;
 use num_traits::Zero;
@@ -18,9 +18,8 @@ pub enum Value {
     Literal(SassString),
     /// A comma- or space separated list of values, with or without brackets.
     List(Vec<Value>, ListSeparator, bool, bool),
-    /// A Numeric value is a rational value with a Unit (which may be
-    /// Unit::None) and flags.
-    Numeric(Number, Unit),
+    /// A Numeric value is a number with units (which may be none).
+    Numeric(Number, UnitSet),
     /// "(a/b) and a/b differs semantically.  Parens means the value
     /// should be evaluated numerically if possible, without parens /
     /// is not allways division.
@@ -43,11 +42,16 @@ pub enum Value {
     /// A unicode range for font selections. U+NN, U+N?, U+NN-MM.
     /// The string is the entire value, including the "U+" tag.
     UnicodeRange(String),
+    /// A css calculation, like `calc(100% - $gutter)`.
+    ///
+    /// The arguments are evaluated as calculation expressions, where
+    /// operations are kept if they can't be simplified.
+    Calculation(CalcName, Vec<Value>),
 }
 
 impl Value {
     pub fn scalar(v: isize) -> Self {
-        Value::Numeric(Number::from(v), Unit::None)
+        Value::Numeric(Number::from(v), UnitSet::scalar())
     }
     pub fn bool(v: bool) -> Self {
         if v {
@@ -120,7 +124,7 @@ impl Value {
                 }
             }
             Value::Color(ref rgba, ref name) => {
-                Ok(css::Value::Color(rgba.clone(), name.clone()))
+                Ok(css::Value::Color(rgba.clone().into(), name.clone()))
             }
             Value::Variable(ref name) => {
                 Ok(scope.get(name)?.into_calculated())
@@ -140,14 +144,31 @@ impl Value {
                 Ok(css::Value::List(items, s.clone(), b))
             }
             Value::Call(ref name, ref args) => {
-                let args = args.evaluate(scope, true)?;
+                let args = if name.single_raw().map_or(false, is_color_fn) {
+                    args.evaluate_with(scope, true, |v| {
+                        v.eval_keeping_calculations(scope, true)
+                    })?
+                } else {
+                    args.evaluate(scope, true)?
+                };
                 if let Some(name) = name.single_raw() {
+                    let _frame = logger::enter(Some(&format!("{}()", name)));
                     match scope.call_function(name, &args) {
-                        Some(value) => Ok(value?),
-                        None => get_builtin_function(name)
+                        Some(value) => value,
+                        None => scope
+                            .get_function(name)
                             .map(|f| f.call(scope, &args))
                             .unwrap_or_else(|| {
-                                Ok(css::Value::Call(name.to_string(), args))
+                                if name.contains('.') {
+                                    Err(scope
+                                        .get_global_scope()
+                                        .undefined_function(name))
+                                } else {
+                                    Ok(css::Value::Call(
+                                        name.to_string(),
+                                        args,
+                                    ))
+                                }
                             }),
                     }
                 } else {
@@ -191,7 +212,7 @@ impl Value {
                         (aa, b)
                     }
                 };
-                Ok(op.eval(a.clone(), b.clone()).unwrap_or_else(|| {
+                Ok(op.eval(a.clone(), b.clone())?.unwrap_or_else(|| {
                     css::Value::BinOp(
                         Box::new(a),
                         s1,
@@ -241,6 +262,103 @@ impl Value {
             Value::UnicodeRange(ref s) => {
                 Ok(css::Value::UnicodeRange(s.clone()))
             }
+            Value::Calculation(name, ref args) => {
+                Calculation::create(name, calc_args(args, scope)?)
+            }
+        }
+    }
+
+    /// Evaluate an argument to a legacy color function.
+    ///
+    /// Calculations are kept as calculations even if they could be
+    /// simplified to a number, so that the color function keeps them
+    /// as is, like `var()` arguments, e.g. `rgb(calc(1), 2, 3)`.
+    fn eval_keeping_calculations(
+        &self,
+        scope: &dyn Scope,
+        arithmetic: bool,
+    ) -> Result<css::Value, Error> {
+        match *self {
+            Value::Calculation(name, ref args) => {
+                Ok(css::Value::Calculation(Calculation::new(
+                    name,
+                    calc_args(args, scope)?,
+                )))
+            }
+            Value::List(ref v, ref s, b, needs_requote) => {
+                let items = v
+                    .iter()
+                    .map(|v| -> Result<css::Value, Error> {
+                        let v = v.eval_keeping_calculations(scope, false)?;
+                        if needs_requote {
+                            Ok(v.unrequote())
+                        } else {
+                            Ok(v)
+                        }
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(css::Value::List(items, s.clone(), b))
+            }
+            Value::BinOp(ref a, s1, Operator::Div, s2, ref b)
+                if a.is_calculation() || b.is_calculation() =>
+            {
+                Ok(css::Value::BinOp(
+                    Box::new(a.eval_keeping_calculations(scope, arithmetic)?),
+                    s1,
+                    Operator::Div,
+                    s2,
+                    Box::new(b.eval_keeping_calculations(scope, arithmetic)?),
+                ))
+            }
+            ref v => v.do_evaluate(scope, arithmetic),
         }
     }
+
+    fn is_calculation(&self) -> bool {
+        match self {
+            Value::Calculation(..) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns true for the legacy color functions, that keeps
+/// calculation arguments as is.
+fn is_color_fn(name: &str) -> bool {
+    match name {
+        "rgb" | "rgba" | "hsl" | "hsla" => true,
+        _ => false,
+    }
+}
+
+/// Evaluate the arguments of a calculation.
+fn calc_args(
+    args: &[Value],
+    scope: &dyn Scope,
+) -> Result<Vec<CalcValue>, Error> {
+    args.iter().map(|arg| calc_value(arg, scope)).collect()
+}
+
+/// Evaluate a value in a calculation.
+///
+/// Operations are evaluated as calculation operations, anything
+/// else is evaluated as a normal value.
+fn calc_value(value: &Value, scope: &dyn Scope) -> Result<CalcValue, Error> {
+    match *value {
+        Value::BinOp(ref a, _, ref op, _, ref b) => CalcValue::operation(
+            calc_value(a, scope)?,
+            op.clone(),
+            calc_value(b, scope)?,
+        ),
+        Value::Paren(ref v, _) => calc_value(v, scope),
+        Value::Calculation(name, ref args) => CalcValue::from_value(
+            Calculation::create_nested(name, calc_args(args, scope)?)?,
+        ),
+        Value::UnaryOp(Operator::Minus, ref v) => CalcValue::operation(
+            CalcValue::Number(Number::from(-1), UnitSet::scalar()),
+            Operator::Multiply,
+            calc_value(v, scope)?,
+        ),
+        ref v => CalcValue::from_value(v.do_evaluate(scope, true)?),
+    }
 }
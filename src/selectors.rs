@@ -4,8 +4,11 @@
 //! is a `Selectors` object which contains two `Selector` objects, one
 //! for `p.foo` and one for `.foo p`.
 //!
-//! This _may_ change to a something like a tree of operators with
-//! leafs of simple selectors in some future release.
+//! Each `Selector` is a sequence of simple selectors (like `p` and
+//! `.foo`) and combinators.
+//! A run of simple selectors without combinators between them is a
+//! compound selector, so `p.foo` is one compound selector of the type
+//! selector `p` and the class selector `.foo`.
 use crate::css::Value;
 use crate::error::Error;
 use crate::sass::SassString;
@@ -56,20 +59,8 @@ impl Selectors {
     }
     /// Create a sass `Value` representing this set of selectors.
     pub fn to_value(&self) -> Value {
-        let content = self
-            .s
-            .iter()
-            .map(|s: &Selector| {
-                Value::List(
-                    format!("{}", s)
-                        .split_whitespace()
-                        .map(|p| Value::Literal(p.to_string(), Quotes::None))
-                        .collect(),
-                    ListSeparator::Space,
-                    false,
-                )
-            })
-            .collect::<Vec<_>>();
+        let content =
+            self.s.iter().map(Selector::to_value).collect::<Vec<_>>();
         let sep = if content.len() == 1 {
             ListSeparator::Space
         } else {
@@ -104,15 +95,37 @@ impl Selector {
         Selector(vec![])
     }
 
+    /// The specificity of this selector.
+    pub fn specificity(&self) -> u32 {
+        self.0.iter().map(SelectorPart::specificity).sum()
+    }
+
+    /// Append `other` to this selector, as done by `selector-append`.
+    ///
+    /// So `.a` and `.b` gives `.a.b`, and `.a` and `-b` gives `.a-b`.
+    pub fn append(&self, other: &Selector) -> Selector {
+        let mut backref = vec![SelectorPart::BackRef];
+        backref.extend(other.0.iter().cloned());
+        self.join(&Selector(backref), &Selector::root())
+    }
+
     fn join(&self, other: &Selector, alt_context: &Selector) -> Selector {
         let mut split = other.0.splitn(2, |p| p == &SelectorPart::BackRef);
         let o1 = split.next().unwrap();
         if let Some(o2) = split.next() {
             let mut result = o1.to_vec();
-            if self.0.is_empty() {
-                result.extend(alt_context.0.iter().cloned());
-            } else {
-                result.extend(self.0.iter().cloned());
+            let parent = if self.0.is_empty() { alt_context } else { self };
+            result.extend(parent.0.iter().cloned());
+            let mut o2 = o2;
+            // A name directly after the backref is a suffix, as in `&-foo`.
+            if let (
+                Some(last),
+                Some(SelectorPart::Type { ns: None, ref name }),
+            ) = (result.last_mut(), o2.first())
+            {
+                if !parent.0.is_empty() && last.add_suffix(name) {
+                    o2 = &o2[1..];
+                }
             }
             result.extend(o2.iter().cloned());
             Selector(result)
@@ -128,6 +141,39 @@ impl Selector {
         }
     }
 
+    /// Create a sass `Value` representing this selector.
+    ///
+    /// The value is a space separated list, where each compound
+    /// selector and each combinator is an unquoted string.
+    pub fn to_value(&self) -> Value {
+        let mut items = Vec::new();
+        let mut compound = String::new();
+        for part in &self.0 {
+            let combinator = match *part {
+                SelectorPart::Descendant => None,
+                SelectorPart::RelOp(c) => Some(c),
+                ref part => {
+                    compound.push_str(&part.to_string());
+                    continue;
+                }
+            };
+            if !compound.is_empty() {
+                items.push(Value::Literal(compound.clone(), Quotes::None));
+                compound.clear();
+            }
+            if let Some(c) = combinator {
+                items.push(Value::Literal(
+                    char::from(c).to_string(),
+                    Quotes::None,
+                ));
+            }
+        }
+        if !compound.is_empty() {
+            items.push(Value::Literal(compound, Quotes::None));
+        }
+        Value::List(items, ListSeparator::Space, false)
+    }
+
     fn eval(&self, scope: &dyn Scope) -> Result<Selector, Error> {
         self.0
             .iter()
@@ -140,7 +186,23 @@ impl Selector {
 /// A selector consist of a sequence of these parts.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SelectorPart {
-    /// A simple selector, eg a class, id or element name.
+    /// A type (element name) selector, like `p` or `svg|rect`.
+    ///
+    /// The namespace is empty for `|p` and `*` for `*|p`.
+    Type {
+        ns: Option<SassString>,
+        name: SassString,
+    },
+    /// The universal selector, `*`, optionally with a namespace.
+    Universal { ns: Option<SassString> },
+    /// A class selector; `.name`.
+    Class(SassString),
+    /// An id selector; `#name`.
+    Id(SassString),
+    /// A placeholder selector; `%name`.
+    Placeholder(SassString),
+    /// A simple selector that could not be classified, like the
+    /// keyframe selector `50%`.
     ///
     /// Note that a Simple selector can hide a more complex selector
     /// through string interpolation.
@@ -173,14 +235,71 @@ pub enum SelectorPart {
 }
 
 impl SelectorPart {
-    fn is_operator(&self) -> bool {
+    pub(crate) fn is_operator(&self) -> bool {
+        matches!(*self, SelectorPart::Descendant | SelectorPart::RelOp(_))
+    }
+
+    /// True for pseudo-elements, including css2 pseudo-elements
+    /// written with a single colon, like `:before`.
+    pub(crate) fn is_pseudo_element(&self) -> bool {
+        match *self {
+            SelectorPart::PseudoElement { .. } => true,
+            SelectorPart::Pseudo { ref name, .. } => matches!(
+                name.single_raw(),
+                Some("after")
+                    | Some("before")
+                    | Some("first-line")
+                    | Some("first-letter")
+            ),
+            _ => false,
+        }
+    }
+
+    /// The specificity of this simple selector.
+    ///
+    /// Ids count as 10000, classes, attributes and pseudo-classes as
+    /// 100 and types and pseudo-elements as 1.
+    pub fn specificity(&self) -> u32 {
         match *self {
-            SelectorPart::Descendant | SelectorPart::RelOp(_) => true,
-            SelectorPart::Simple(_)
+            SelectorPart::Id(_) => 10000,
+            SelectorPart::Type { .. } => 1,
+            ref p if p.is_pseudo_element() => 1,
+            SelectorPart::Pseudo {
+                ref name,
+                arg: Some(ref arg),
+            } => match name.single_raw() {
+                // These take the specificity of their argument.
+                Some("not") | Some("is") | Some("matches") | Some("has") => {
+                    arg.s.iter().map(Selector::specificity).max().unwrap_or(0)
+                }
+                Some("where") => 0,
+                _ => 100,
+            },
+            SelectorPart::Class(_)
+            | SelectorPart::Placeholder(_)
             | SelectorPart::Attribute { .. }
-            | SelectorPart::PseudoElement { .. }
-            | SelectorPart::Pseudo { .. }
-            | SelectorPart::BackRef => false,
+            | SelectorPart::Pseudo { .. } => 100,
+            _ => 0,
+        }
+    }
+
+    /// Add `suffix` to the name of this simple selector, as for `&-foo`.
+    ///
+    /// Returns false if this kind of selector can't have a suffix.
+    fn add_suffix(&mut self, suffix: &SassString) -> bool {
+        match *self {
+            SelectorPart::Type { ref mut name, .. }
+            | SelectorPart::Class(ref mut name)
+            | SelectorPart::Id(ref mut name)
+            | SelectorPart::Placeholder(ref mut name)
+            | SelectorPart::Simple(ref mut name) => {
+                match suffix.single_raw() {
+                    Some(raw) => name.append_str(raw),
+                    None => name.append(suffix),
+                }
+                true
+            }
+            _ => false,
         }
     }
 
@@ -197,6 +316,26 @@ impl SelectorPart {
                 val: val.evaluate2(scope)?,
                 modifier: *modifier,
             }),
+            SelectorPart::Type { ref ns, ref name } => {
+                Ok(SelectorPart::Type {
+                    ns: eval_ns(ns, scope)?,
+                    name: name.evaluate2(scope)?,
+                })
+            }
+            SelectorPart::Universal { ref ns } => {
+                Ok(SelectorPart::Universal {
+                    ns: eval_ns(ns, scope)?,
+                })
+            }
+            SelectorPart::Class(ref v) => {
+                Ok(SelectorPart::Class(v.evaluate2(scope)?))
+            }
+            SelectorPart::Id(ref v) => {
+                Ok(SelectorPart::Id(v.evaluate2(scope)?))
+            }
+            SelectorPart::Placeholder(ref v) => {
+                Ok(SelectorPart::Placeholder(v.evaluate2(scope)?))
+            }
             SelectorPart::Simple(ref v) => {
                 Ok(SelectorPart::Simple(v.evaluate2(scope)?))
             }
@@ -225,6 +364,13 @@ impl SelectorPart {
     }
 }
 
+fn eval_ns(
+    ns: &Option<SassString>,
+    scope: &dyn Scope,
+) -> Result<Option<SassString>, Error> {
+    ns.as_ref().map(|ns| ns.evaluate2(scope)).transpose()
+}
+
 // TODO:  This shoule probably be on Formatted<Selectors> instead.
 impl fmt::Display for Selectors {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
@@ -266,6 +412,21 @@ impl fmt::Display for Selector {
 impl fmt::Display for SelectorPart {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
         match *self {
+            SelectorPart::Type { ref ns, ref name } => {
+                if let Some(ns) = ns {
+                    write!(out, "{}|", ns)?;
+                }
+                write!(out, "{}", name)
+            }
+            SelectorPart::Universal { ref ns } => {
+                if let Some(ns) = ns {
+                    write!(out, "{}|", ns)?;
+                }
+                write!(out, "*")
+            }
+            SelectorPart::Class(ref s) => write!(out, ".{}", s),
+            SelectorPart::Id(ref s) => write!(out, "#{}", s),
+            SelectorPart::Placeholder(ref s) => write!(out, "%{}", s),
             SelectorPart::Simple(ref s) => write!(out, "{}", s),
             SelectorPart::Descendant => write!(out, " "),
             SelectorPart::RelOp(ref c) => {
@@ -327,16 +488,23 @@ impl fmt::Display for SelectorPart {
 mod test {
     use super::*;
 
+    fn foo() -> Selector {
+        Selector(vec![SelectorPart::Type {
+            ns: None,
+            name: "foo".into(),
+        }])
+    }
+
     #[test]
     fn root_join() {
-        let s = Selector(vec![SelectorPart::Simple("foo".into())]);
+        let s = foo();
         assert_eq!(Selector::root().join(&s, &Selector::root()), s)
     }
 
     #[test]
     fn simple_join() {
-        let s = Selector(vec![SelectorPart::Simple("foo".into())]).join(
-            &Selector(vec![SelectorPart::Simple(".bar".into())]),
+        let s = foo().join(
+            &Selector(vec![SelectorPart::Class("bar".into())]),
             &Selector::root(),
         );
         assert_eq!(format!("{}", s), "foo .bar")
@@ -344,13 +512,45 @@ mod test {
 
     #[test]
     fn backref_join() {
-        let s = Selector(vec![SelectorPart::Simple("foo".into())]).join(
+        let s = foo().join(
             &Selector(vec![
                 SelectorPart::BackRef,
-                SelectorPart::Simple(".bar".into()),
+                SelectorPart::Class("bar".into()),
             ]),
             &Selector::root(),
         );
         assert_eq!(format!("{}", s), "foo.bar")
     }
+
+    #[test]
+    fn backref_suffix_join() {
+        let s = Selector(vec![SelectorPart::Class("foo".into())]).join(
+            &Selector(vec![
+                SelectorPart::BackRef,
+                SelectorPart::Type {
+                    ns: None,
+                    name: "-bar".into(),
+                },
+            ]),
+            &Selector::root(),
+        );
+        assert_eq!(s, Selector(vec![SelectorPart::Class("foo-bar".into())]))
+    }
+
+    #[test]
+    fn specificity() {
+        let s = Selector(vec![
+            SelectorPart::Type {
+                ns: None,
+                name: "a".into(),
+            },
+            SelectorPart::Id("b".into()),
+            SelectorPart::Class("c".into()),
+            SelectorPart::Pseudo {
+                name: "hover".into(),
+                arg: None,
+            },
+        ]);
+        assert_eq!(s.specificity(), 10201)
+    }
 }
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+/// The name and content of a parsed source file.
+#[derive(Debug)]
+pub struct SourceFile {
+    name: String,
+    data: Vec<u8>,
+}
+
+impl SourceFile {
+    /// Create a new source file with a given name and content.
+    pub fn new(name: String, data: Vec<u8>) -> Self {
+        SourceFile { name, data }
+    }
+    /// The name of this file, as given when it was loaded.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The content of this file.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// A position in a source file.
+///
+/// Parsed items that produce css output keep track of where they
+/// were found, so that the output can be mapped back to its source.
+/// The position may also know where the head of the item (e.g. a
+/// declaration or the selectors of a rule) ends.
+#[derive(Clone)]
+pub struct SourcePos {
+    file: Arc<SourceFile>,
+    offset: usize,
+    end: usize,
+}
+
+impl SourcePos {
+    /// Create a position for a byte `offset` in `file`.
+    pub fn new(file: Arc<SourceFile>, offset: usize) -> Self {
+        SourcePos {
+            file,
+            offset,
+            end: offset,
+        }
+    }
+
+    /// Get this position, spanning to byte offset `end` in the file.
+    pub(crate) fn with_end(mut self, end: usize) -> Self {
+        self.end = end.max(self.offset);
+        self
+    }
+
+    /// A position in an unnamed, empty source.
+    ///
+    /// This is used for items that are not parsed from a source file.
+    pub fn unknown() -> Self {
+        SourcePos::new(Arc::new(SourceFile::new(String::new(), vec![])), 0)
+    }
+
+    /// The file of this position.
+    pub fn file(&self) -> &Arc<SourceFile> {
+        &self.file
+    }
+
+    /// The byte offset of this position in the source file.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte offset where the item at this position ends.
+    ///
+    /// This is the same as the [`offset`](Self::offset) if the end is
+    /// not known.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Get the line (starting at 1) and column (starting at 1, in
+    /// characters) of this position.
+    pub fn line_col(&self) -> (usize, usize) {
+        let before = &self.file.data[..self.offset];
+        let line_start = before
+            .iter()
+            .rposition(|c| *c == b'\n')
+            .map(|p| p + 1)
+            .unwrap_or(0);
+        (
+            1 + bytecount::count(before, b'\n'),
+            1 + bytecount::num_chars(&before[line_start..]),
+        )
+    }
+}
+
+impl fmt::Display for SourcePos {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        let (line, col) = self.line_col();
+        write!(out, "{}:{}:{}", self.file.name, line, col)
+    }
+}
+
+impl fmt::Debug for SourcePos {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "SourcePos({:?}, {})", self.file.name, self.offset)
+    }
+}
+
+impl PartialEq for SourcePos {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset && self.file.name == other.file.name
+    }
+}
+impl Eq for SourcePos {}
+
+impl PartialOrd for SourcePos {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for SourcePos {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.file.name, self.offset).cmp(&(&other.file.name, other.offset))
+    }
+}
@@ -17,11 +17,6 @@ fn main() -> Result<(), Error> {
         &base,
         "spec",
         &[
-            "core_functions/selector/extend", // not supported
-            "core_functions/selector/is_superselector", // not supported
-            "core_functions/selector/unify",  // not supported
-            "directives/forward", // `@forward` is not supported at all
-            "directives/use",     // `@use` is not supported at all
             "libsass-closed-issues/issue_185/mixin.hrx", // stack overflow
             "libsass-todo-issues/issue_221262.hrx", // stack overflow
             "libsass-todo-issues/issue_221292.hrx", // stack overflow
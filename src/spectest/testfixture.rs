@@ -76,10 +76,9 @@ impl TestFixture {
                 if let Some(precision) = precision {
                     writeln!(
                         rs,
-                        "    let format = rsass::output::Format {{ \
-                         style: rsass::output::Style::Expanded, \
-                         precision: {} \
-                         }};",
+                        "    let format = rsass::output::Format::default()\
+                         \n        .with_style(rsass::output::Style::Expanded)\
+                         \n        .with_precision({});",
                         precision,
                     )?;
                 }
@@ -116,10 +115,11 @@ impl TestFixture {
         match &self.expectation {
             ExpectedError(_) => Some("Error tests not supported yet"),
             ExpectedCSS(ref expected) => {
-                let format = Format {
-                    style: Style::Expanded,
-                    precision: self.options.precision.unwrap_or(6) as usize,
-                };
+                let format = Format::default()
+                    .with_style(Style::Expanded)
+                    .with_precision(
+                        self.options.precision.unwrap_or(6) as usize
+                    );
                 match rsass(&self.input, format) {
                     Ok(ref actual) => {
                         if expected == actual {
@@ -1,15 +1,105 @@
 //! Color names from <https://www.w3.org/TR/css3-color/>
 #![allow(clippy::unreadable_literal)]
 
+use super::{ColorSpace, SpaceColor};
 use crate::output::{Format, Formatted};
 use crate::value::Number;
 use lazy_static::lazy_static;
 use num_rational::Rational;
 use num_traits::{One, Signed, Zero};
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::{self, Display};
 use std::ops::{Add, Div, Sub};
 
+/// A color value.
+///
+/// Colors from the legacy color syntax (hex colors, color names and
+/// the `rgb()` and `hsl()` functions) are kept as exact [`Rgba`].
+/// Colors in other color spaces, like `lab(...)` or
+/// `color(display-p3 ...)`, are kept in their color space.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Color {
+    Rgba(Rgba),
+    Space(SpaceColor),
+}
+
+impl Color {
+    /// Get this color as a legacy rgba color.
+    ///
+    /// Colors outside of the srgb gamut are clipped.
+    pub fn to_rgba(&self) -> Cow<'_, Rgba> {
+        match self {
+            Color::Rgba(rgba) => Cow::Borrowed(rgba),
+            Color::Space(color) => Cow::Owned(color.to_rgba()),
+        }
+    }
+    /// Get this color in its color space.
+    ///
+    /// For a legacy color, the space is `rgb`.
+    pub fn to_space_color(&self) -> SpaceColor {
+        match self {
+            Color::Rgba(rgba) => rgba.into(),
+            Color::Space(color) => color.clone(),
+        }
+    }
+    /// The color space of this color.
+    pub fn space(&self) -> ColorSpace {
+        match self {
+            Color::Rgba(_) => ColorSpace::Rgb,
+            Color::Space(color) => color.space(),
+        }
+    }
+    pub fn is_legacy(&self) -> bool {
+        self.space().is_legacy()
+    }
+    /// Check if this color is equal to `other`, as by the sass `==`
+    /// operator.
+    ///
+    /// Legacy colors are compared as rgb, other colors are only equal
+    /// to colors in the same space.  Channels are compared with a
+    /// small tolerance, so that rounding errors from conversions
+    /// between color spaces are ignored.
+    pub fn equals(&self, other: &Color) -> bool {
+        match (self, other) {
+            (Color::Rgba(a), Color::Rgba(b)) if a == b => true,
+            (a, b) => a.to_space_color().fuzzy_eq(&b.to_space_color()),
+        }
+    }
+    pub fn format(&self, format: Format) -> Formatted<'_, Color> {
+        Formatted {
+            value: self,
+            format,
+        }
+    }
+}
+
+impl From<Rgba> for Color {
+    fn from(rgba: Rgba) -> Color {
+        Color::Rgba(rgba)
+    }
+}
+
+/// Colors in a legacy color space becomes [`Rgba`] colors.
+impl From<SpaceColor> for Color {
+    fn from(color: SpaceColor) -> Color {
+        if color.space().is_legacy() {
+            Color::Rgba(color.to_rgba())
+        } else {
+            Color::Space(color)
+        }
+    }
+}
+
+impl<'a> Display for Formatted<'a, Color> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self.value {
+            Color::Rgba(rgba) => rgba.format(self.format).fmt(out),
+            Color::Space(color) => color.format(self.format).fmt(out),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Rgba {
     pub red: Rational,
@@ -222,40 +312,46 @@ impl<'a> Sub<&'a Rgba> for &'a Rgba {
 
 impl<'a> Display for Formatted<'a, Rgba> {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
-        // The byte-version of alpha is not used here.
-        let (r, g, b, _a) = self.value.to_bytes();
-        let a = self.value.alpha;
-        if a >= Rational::one() {
+        let (r, g, b, a) = self.value.to_bytes();
+        let colors = self.format.colors;
+        let shortest = self.format.short_colors();
+        let opaque = self.value.alpha >= Rational::one();
+        if opaque || colors.alpha_hex {
+            let bytes: &[u8] =
+                if opaque { &[r, g, b] } else { &[r, g, b, a] };
             // E.g. #ff00cc can be written #f0c in css.
             // 0xff / 0x11 = 0xf.
-            let short = r % 0x11 == 0 && g % 0x11 == 0 && b % 0x11 == 0;
-            let hex_len = if short { 4 } else { 7 };
+            let hex: String =
+                if shortest && bytes.iter().all(|b| b % 0x11 == 0) {
+                    bytes.iter().map(|b| format!("{:x}", b / 0x11)).collect()
+                } else {
+                    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+                };
+            let hex = format!("#{}", hex);
             if let Some(name) = self.value.name() {
-                if !(self.format.is_compressed() && name.len() > hex_len) {
+                if !(shortest && name.len() > hex.len()) {
                     return name.fmt(out);
                 }
             }
-            if self.format.is_compressed() && short {
-                write!(out, "#{:x}{:x}{:x}", r / 0x11, g / 0x11, b / 0x11)
-            } else {
-                write!(out, "#{:02x}{:02x}{:02x}", r, g, b)
-            }
-        } else if self.format.is_compressed() && self.value.all_zero() {
-            write!(out, "transparent")
-        } else if self.format.is_compressed() {
-            // Note: libsass does not use the format for the alpha like this.
-            let a = Number::from(a);
-            write!(out, "rgba({},{},{},{})", r, g, b, a.format(self.format))
+            out.write_str(&hex)
+        } else if shortest && self.value.all_zero() {
+            out.write_str("transparent")
         } else {
-            let a = Number::from(a);
-            write!(
-                out,
-                "rgba({}, {}, {}, {})",
-                r,
-                g,
-                b,
-                a.format(self.format)
-            )
+            // The byte-version of alpha is not used here.
+            let a = Number::from(self.value.alpha);
+            let a = a.format(self.format);
+            match (colors.modern_syntax, self.format.is_compressed()) {
+                (true, true) => write!(out, "rgb({} {} {}/{})", r, g, b, a),
+                (true, false) => {
+                    write!(out, "rgb({} {} {} / {})", r, g, b, a)
+                }
+                // Note: libsass does not use the format for the alpha
+                // like this.
+                (false, true) => write!(out, "rgba({},{},{},{})", r, g, b, a),
+                (false, false) => {
+                    write!(out, "rgba({}, {}, {}, {})", r, g, b, a)
+                }
+            }
         }
     }
 }
@@ -0,0 +1,801 @@
+//! Colors in the color spaces of
+//! [css color level 4](https://www.w3.org/TR/css-color-4/).
+//!
+//! All conversions between spaces go through the xyz space with a d65
+//! white point.
+#![allow(clippy::unreadable_literal, clippy::excessive_precision)]
+
+use super::{NumValue, Number, Rgba};
+use crate::output::{Format, Formatted};
+use num_rational::Rational;
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+
+/// A color space, as used in `color.to-space` or `color(...)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSpace {
+    Rgb,
+    Hsl,
+    Hwb,
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    XyzD50,
+    XyzD65,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Get a color space by its (case insensitive) css name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_ref() {
+            "rgb" => Some(ColorSpace::Rgb),
+            "hsl" => Some(ColorSpace::Hsl),
+            "hwb" => Some(ColorSpace::Hwb),
+            "srgb" => Some(ColorSpace::Srgb),
+            "srgb-linear" => Some(ColorSpace::SrgbLinear),
+            "display-p3" => Some(ColorSpace::DisplayP3),
+            "xyz-d50" => Some(ColorSpace::XyzD50),
+            "xyz" | "xyz-d65" => Some(ColorSpace::XyzD65),
+            "lab" => Some(ColorSpace::Lab),
+            "lch" => Some(ColorSpace::Lch),
+            "oklab" => Some(ColorSpace::Oklab),
+            "oklch" => Some(ColorSpace::Oklch),
+            _ => None,
+        }
+    }
+
+    /// True for the spaces of the legacy css color functions.
+    ///
+    /// Colors in these spaces are represented as [`Rgba`].
+    pub fn is_legacy(self) -> bool {
+        matches!(self, ColorSpace::Rgb | ColorSpace::Hsl | ColorSpace::Hwb)
+    }
+
+    /// True for spaces where the third channel is a hue.
+    pub fn is_polar(self) -> bool {
+        matches!(self, ColorSpace::Lch | ColorSpace::Oklch)
+    }
+
+    /// The names of the three channels of this space.
+    pub fn channels(self) -> [&'static str; 3] {
+        match self {
+            ColorSpace::Rgb
+            | ColorSpace::Srgb
+            | ColorSpace::SrgbLinear
+            | ColorSpace::DisplayP3 => ["red", "green", "blue"],
+            ColorSpace::Hsl => ["hue", "saturation", "lightness"],
+            ColorSpace::Hwb => ["hue", "whiteness", "blackness"],
+            ColorSpace::XyzD50 | ColorSpace::XyzD65 => ["x", "y", "z"],
+            ColorSpace::Lab | ColorSpace::Oklab => ["lightness", "a", "b"],
+            ColorSpace::Lch | ColorSpace::Oklch => {
+                ["lightness", "chroma", "hue"]
+            }
+        }
+    }
+
+    /// Get the channels of a color in this space as xyz (d65).
+    fn to_xyz(self, c: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::Rgb => ColorSpace::Srgb.to_xyz([
+                c[0] / 255.,
+                c[1] / 255.,
+                c[2] / 255.,
+            ]),
+            ColorSpace::Hsl => ColorSpace::Srgb.to_xyz(hsl_to_srgb(c)),
+            ColorSpace::Hwb => ColorSpace::Srgb.to_xyz(hwb_to_srgb(c)),
+            ColorSpace::Srgb => mul(&SRGB_TO_XYZ, map3(c, srgb_to_linear)),
+            ColorSpace::SrgbLinear => mul(&SRGB_TO_XYZ, c),
+            ColorSpace::DisplayP3 => mul(&P3_TO_XYZ, map3(c, srgb_to_linear)),
+            ColorSpace::XyzD50 => mul(&D50_TO_D65, c),
+            ColorSpace::XyzD65 => c,
+            ColorSpace::Lab => mul(&D50_TO_D65, lab_to_xyz_d50(c)),
+            ColorSpace::Lch => ColorSpace::Lab.to_xyz(from_polar(c)),
+            ColorSpace::Oklab => {
+                let lms = map3(mul(&OKLAB_TO_LMS, c), |v| v * v * v);
+                mul(&LMS_TO_XYZ, lms)
+            }
+            ColorSpace::Oklch => ColorSpace::Oklab.to_xyz(from_polar(c)),
+        }
+    }
+
+    /// Get xyz (d65) channels as channels in this space.
+    fn channels_from_xyz(self, xyz: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::Rgb => {
+                map3(ColorSpace::Srgb.channels_from_xyz(xyz), |v| v * 255.)
+            }
+            ColorSpace::Hsl => {
+                srgb_to_hsl(ColorSpace::Srgb.channels_from_xyz(xyz))
+            }
+            ColorSpace::Hwb => {
+                srgb_to_hwb(ColorSpace::Srgb.channels_from_xyz(xyz))
+            }
+            ColorSpace::Srgb => map3(mul(&XYZ_TO_SRGB, xyz), linear_to_srgb),
+            ColorSpace::SrgbLinear => mul(&XYZ_TO_SRGB, xyz),
+            ColorSpace::DisplayP3 => {
+                map3(mul(&XYZ_TO_P3, xyz), linear_to_srgb)
+            }
+            ColorSpace::XyzD50 => mul(&D65_TO_D50, xyz),
+            ColorSpace::XyzD65 => xyz,
+            ColorSpace::Lab => xyz_d50_to_lab(mul(&D65_TO_D50, xyz)),
+            ColorSpace::Lch => {
+                to_polar(ColorSpace::Lab.channels_from_xyz(xyz))
+            }
+            ColorSpace::Oklab => {
+                let lms = map3(mul(&XYZ_TO_LMS, xyz), f64::cbrt);
+                mul(&LMS_TO_OKLAB, lms)
+            }
+            ColorSpace::Oklch => {
+                to_polar(ColorSpace::Oklab.channels_from_xyz(xyz))
+            }
+        }
+    }
+}
+
+impl Display for ColorSpace {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        out.write_str(match self {
+            ColorSpace::Rgb => "rgb",
+            ColorSpace::Hsl => "hsl",
+            ColorSpace::Hwb => "hwb",
+            ColorSpace::Srgb => "srgb",
+            ColorSpace::SrgbLinear => "srgb-linear",
+            ColorSpace::DisplayP3 => "display-p3",
+            ColorSpace::XyzD50 => "xyz-d50",
+            ColorSpace::XyzD65 => "xyz",
+            ColorSpace::Lab => "lab",
+            ColorSpace::Lch => "lch",
+            ColorSpace::Oklab => "oklab",
+            ColorSpace::Oklch => "oklch",
+        })
+    }
+}
+
+/// A color in a color space.
+///
+/// The channels are in the units css uses for the space, except that
+/// percentages are stored as fractions, so e.g. lab lightness is
+/// 0 .. 100 but hsl saturation is 0 .. 1.
+/// Hues are in degrees.
+#[derive(Clone, Debug)]
+pub struct SpaceColor {
+    space: ColorSpace,
+    channels: [f64; 3],
+    alpha: f64,
+}
+
+impl SpaceColor {
+    /// Create a color from channels in `space`.
+    ///
+    /// Lightness and alpha are clamped to their valid range, and hues
+    /// are normalized to 0 .. 360.
+    pub fn new(space: ColorSpace, channels: [f64; 3], alpha: f64) -> Self {
+        let mut channels = channels;
+        match space {
+            ColorSpace::Lab => channels[0] = channels[0].clamp(0., 100.),
+            ColorSpace::Oklab => channels[0] = channels[0].clamp(0., 1.),
+            ColorSpace::Lch => {
+                channels[0] = channels[0].clamp(0., 100.);
+                channels[1] = channels[1].max(0.);
+            }
+            ColorSpace::Oklch => {
+                channels[0] = channels[0].clamp(0., 1.);
+                channels[1] = channels[1].max(0.);
+            }
+            _ => (),
+        }
+        if space.is_polar() {
+            channels[2] = normalize_hue(channels[2]);
+        } else if matches!(space, ColorSpace::Hsl | ColorSpace::Hwb) {
+            channels[0] = normalize_hue(channels[0]);
+        }
+        SpaceColor {
+            space,
+            channels,
+            alpha: alpha.clamp(0., 1.),
+        }
+    }
+    pub fn space(&self) -> ColorSpace {
+        self.space
+    }
+    pub fn channels(&self) -> [f64; 3] {
+        self.channels
+    }
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Convert this color to another color space.
+    pub fn to_space(&self, space: ColorSpace) -> SpaceColor {
+        if space == self.space {
+            return self.clone();
+        }
+        let channels =
+            space.channels_from_xyz(self.space.to_xyz(self.channels));
+        SpaceColor::new(space, channels, self.alpha)
+    }
+
+    /// Returns true if `other` is in the same space as this color,
+    /// and all channels are equal but for rounding errors.
+    pub fn fuzzy_eq(&self, other: &SpaceColor) -> bool {
+        const EPSILON: f64 = 1e-11;
+        let eq = |a: f64, b: f64| (a - b).abs() <= EPSILON * a.abs().max(1.);
+        self.space == other.space
+            && (0..3).all(|i| {
+                let (a, b) = (self.channels[i], other.channels[i]);
+                if self.is_hue(i) {
+                    // A hue close to 360 is close to 0.
+                    let d = (a - b).rem_euclid(360.);
+                    eq(d, 0.) || eq(d, 360.)
+                } else {
+                    eq(a, b)
+                }
+            })
+            && eq(self.alpha, other.alpha)
+    }
+
+    /// Returns true if this color is inside the gamut of its space.
+    pub fn is_in_gamut(&self) -> bool {
+        const EPSILON: f64 = 1e-11;
+        let (lo, hi) = self.bounds();
+        self.channels
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| !self.is_hue(i))
+            .all(|(i, v)| *v >= lo[i] - EPSILON && *v <= hi[i] + EPSILON)
+    }
+
+    /// Get this color with each channel clamped to the gamut of its
+    /// space.
+    pub fn clip(&self) -> SpaceColor {
+        let (lo, hi) = self.bounds();
+        let mut channels = self.channels;
+        for (i, v) in channels.iter_mut().enumerate() {
+            if !self.is_hue(i) {
+                *v = v.clamp(lo[i], hi[i]);
+            }
+        }
+        SpaceColor::new(self.space, channels, self.alpha)
+    }
+
+    /// Map this color into the gamut of its space with the
+    /// `local-minde` method of css color level 4.
+    ///
+    /// The chroma (in oklch) is reduced until the clipped color is
+    /// close enough to be indistinguishable.
+    pub fn to_gamut_local_minde(&self) -> SpaceColor {
+        const JND: f64 = 0.02;
+        const EPSILON: f64 = 0.0001;
+        let origin = self.to_space(ColorSpace::Oklch);
+        let lightness = origin.channels[0];
+        if lightness >= 1. {
+            let white = SpaceColor::new(ColorSpace::Oklab, [1., 0., 0.], 1.);
+            return white.to_space(self.space).with_alpha(self.alpha);
+        } else if lightness <= 0. {
+            let black = SpaceColor::new(ColorSpace::Oklab, [0., 0., 0.], 1.);
+            return black.to_space(self.space).with_alpha(self.alpha);
+        }
+        if self.is_in_gamut() {
+            return self.clone();
+        }
+        let mut current = origin.clone();
+        let clipped = current.to_space(self.space).clip();
+        if delta_eok(&clipped, &current) < JND {
+            return clipped;
+        }
+        let mut min = 0.;
+        let mut max = origin.channels[1];
+        let mut min_in_gamut = true;
+        let mut clipped = clipped;
+        while max - min > EPSILON {
+            let chroma = (min + max) / 2.;
+            current.channels[1] = chroma;
+            let candidate = current.to_space(self.space);
+            if min_in_gamut && candidate.is_in_gamut() {
+                min = chroma;
+                continue;
+            }
+            clipped = candidate.clip();
+            let e = delta_eok(&clipped, &current);
+            if e < JND {
+                if JND - e < EPSILON {
+                    return clipped;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        clipped
+    }
+
+    /// Interpolate between this color and `other` in `space`.
+    ///
+    /// The `weight` is the proportion of this color in the result.
+    /// Hues are interpolated as given by `hue`.
+    pub fn interpolate(
+        &self,
+        other: &SpaceColor,
+        weight: f64,
+        space: ColorSpace,
+        hue: HueInterpolation,
+    ) -> SpaceColor {
+        let a = self.to_space(space);
+        let b = other.to_space(space);
+        let alpha = a.alpha * weight + b.alpha * (1. - weight);
+        let mut channels = [0.; 3];
+        for (i, c) in channels.iter_mut().enumerate() {
+            *c = if a.is_hue(i) {
+                let (ha, hb) = hue.adjust(a.channels[i], b.channels[i]);
+                ha * weight + hb * (1. - weight)
+            } else {
+                // Interpolate with premultiplied alpha.
+                let v = a.channels[i] * a.alpha * weight
+                    + b.channels[i] * b.alpha * (1. - weight);
+                if alpha == 0. {
+                    v
+                } else {
+                    v / alpha
+                }
+            };
+        }
+        SpaceColor::new(space, channels, alpha)
+    }
+
+    pub fn with_alpha(&self, alpha: f64) -> SpaceColor {
+        SpaceColor::new(self.space, self.channels, alpha)
+    }
+
+    /// Get this color as a legacy rgb color.
+    ///
+    /// Channels out of the srgb gamut are clipped.
+    pub fn to_rgba(&self) -> Rgba {
+        let [r, g, b] = self.to_space(ColorSpace::Rgb).channels;
+        Rgba::new(ratio(r), ratio(g), ratio(b), ratio(self.alpha))
+    }
+
+    pub fn format(&self, format: Format) -> Formatted<'_, SpaceColor> {
+        Formatted {
+            value: self,
+            format,
+        }
+    }
+
+    fn is_hue(&self, i: usize) -> bool {
+        self.space.channels()[i] == "hue"
+    }
+
+    /// The lower and upper bounds of the channels.
+    fn bounds(&self) -> ([f64; 3], [f64; 3]) {
+        match self.space {
+            ColorSpace::Rgb => ([0.; 3], [255.; 3]),
+            ColorSpace::Hsl | ColorSpace::Hwb => ([0.; 3], [360., 1., 1.]),
+            ColorSpace::Srgb
+            | ColorSpace::SrgbLinear
+            | ColorSpace::DisplayP3 => ([0.; 3], [1.; 3]),
+            _ => ([f64::NEG_INFINITY; 3], [f64::INFINITY; 3]),
+        }
+    }
+
+    /// The channels and alpha as comparable numbers.
+    fn key(&self) -> (ColorSpace, [NumValue; 4]) {
+        let [a, b, c] = self.channels;
+        (
+            self.space,
+            [a.into(), b.into(), c.into(), self.alpha.into()],
+        )
+    }
+}
+
+impl From<&Rgba> for SpaceColor {
+    fn from(rgba: &Rgba) -> SpaceColor {
+        let f = |v: &Rational| Number::from(*v).to_f64();
+        SpaceColor::new(
+            ColorSpace::Rgb,
+            [f(&rgba.red), f(&rgba.green), f(&rgba.blue)],
+            f(&rgba.alpha),
+        )
+    }
+}
+
+impl PartialEq for SpaceColor {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+impl Eq for SpaceColor {}
+
+impl PartialOrd for SpaceColor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for SpaceColor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// How hues are interpolated, as in `mix($a, $b, $method: oklch longer)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HueInterpolation {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl HueInterpolation {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_ref() {
+            "shorter" => Some(HueInterpolation::Shorter),
+            "longer" => Some(HueInterpolation::Longer),
+            "increasing" => Some(HueInterpolation::Increasing),
+            "decreasing" => Some(HueInterpolation::Decreasing),
+            _ => None,
+        }
+    }
+
+    /// Adjust two hues (0 .. 360) so that plain interpolation between
+    /// them goes the right way around the hue circle.
+    fn adjust(self, a: f64, b: f64) -> (f64, f64) {
+        let d = b - a;
+        match self {
+            HueInterpolation::Shorter if d > 180. => (a + 360., b),
+            HueInterpolation::Shorter if d < -180. => (a, b + 360.),
+            HueInterpolation::Longer if d > 0. && d < 180. => (a + 360., b),
+            HueInterpolation::Longer if d > -180. && d <= 0. => (a, b + 360.),
+            HueInterpolation::Increasing if d < 0. => (a, b + 360.),
+            HueInterpolation::Decreasing if d > 0. => (a + 360., b),
+            _ => (a, b),
+        }
+    }
+}
+
+impl<'a> Display for Formatted<'a, SpaceColor> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        let color = self.value;
+        let num = |v: f64| Number::from(v).format(self.format).to_string();
+        let [a, b, c] = color.channels;
+        match color.space {
+            ColorSpace::Lab => {
+                write!(out, "lab({}% {} {}", num(a), num(b), num(c))?
+            }
+            ColorSpace::Lch => {
+                write!(out, "lch({}% {} {}deg", num(a), num(b), num(c))?
+            }
+            ColorSpace::Oklab => {
+                write!(out, "oklab({}% {} {}", num(a * 100.), num(b), num(c))?
+            }
+            ColorSpace::Oklch => write!(
+                out,
+                "oklch({}% {} {}deg",
+                num(a * 100.),
+                num(b),
+                num(c)
+            )?,
+            space => write!(
+                out,
+                "color({} {} {} {}",
+                space,
+                num(a),
+                num(b),
+                num(c)
+            )?,
+        }
+        if color.alpha < 1. {
+            write!(out, " / {}", num(color.alpha))?;
+        }
+        out.write_str(")")
+    }
+}
+
+/// The "delta e ok" distance between two colors.
+fn delta_eok(a: &SpaceColor, b: &SpaceColor) -> f64 {
+    let a = a.to_space(ColorSpace::Oklab).channels;
+    let b = b.to_space(ColorSpace::Oklab).channels;
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2))
+        .sqrt()
+}
+
+/// Get `v` as a rational for a legacy color.
+///
+/// The denominator is kept small, since the legacy color functions
+/// do exact arithmetic on the channels.
+fn ratio(v: f64) -> Rational {
+    if v.is_finite() {
+        Rational::new((v * 10_000.).round() as isize, 10_000)
+    } else {
+        Rational::zero()
+    }
+}
+
+fn normalize_hue(hue: f64) -> f64 {
+    if hue.is_finite() {
+        hue.rem_euclid(360.)
+    } else {
+        0.
+    }
+}
+
+fn map3(c: [f64; 3], f: impl Fn(f64) -> f64) -> [f64; 3] {
+    [f(c[0]), f(c[1]), f(c[2])]
+}
+
+fn mul(m: &[[f64; 3]; 3], c: [f64; 3]) -> [f64; 3] {
+    let row = |r: &[f64; 3]| r[0] * c[0] + r[1] * c[1] + r[2] * c[2];
+    [row(&m[0]), row(&m[1]), row(&m[2])]
+}
+
+fn srgb_to_linear(v: f64) -> f64 {
+    let abs = v.abs();
+    if abs <= 0.04045 {
+        v / 12.92
+    } else {
+        v.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f64) -> f64 {
+    let abs = v.abs();
+    if abs > 0.0031308 {
+        v.signum() * (1.055 * abs.powf(1. / 2.4) - 0.055)
+    } else {
+        v * 12.92
+    }
+}
+
+fn hsl_to_srgb([h, s, l]: [f64; 3]) -> [f64; 3] {
+    let f = |n: f64| {
+        let k = (n + h / 30.) % 12.;
+        let a = s * l.min(1. - l);
+        l - a * (k - 3.).min(9. - k).clamp(-1., 1.)
+    };
+    [f(0.), f(8.), f(4.)]
+}
+
+fn srgb_to_hsl([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let l = (max + min) / 2.;
+    let s = if d == 0. || l == 0. || l == 1. {
+        0.
+    } else {
+        (max - l) / l.min(1. - l)
+    };
+    [hue(r, g, b, max, d), s, l]
+}
+
+fn hwb_to_srgb([h, w, b]: [f64; 3]) -> [f64; 3] {
+    if w + b >= 1. {
+        let gray = w / (w + b);
+        return [gray; 3];
+    }
+    map3(hsl_to_srgb([h, 1., 0.5]), |v| v * (1. - w - b) + w)
+}
+
+fn srgb_to_hwb([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    [hue(r, g, b, max, max - min), min, 1. - max]
+}
+
+/// The hue, in degrees, of an srgb color.
+fn hue(r: f64, g: f64, b: f64, max: f64, d: f64) -> f64 {
+    if d == 0. {
+        0.
+    } else if max == r {
+        60. * ((g - b) / d) + if g < b { 360. } else { 0. }
+    } else if max == g {
+        60. * ((b - r) / d + 2.)
+    } else {
+        60. * ((r - g) / d + 4.)
+    }
+}
+
+fn to_polar([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let chroma = (a * a + b * b).sqrt();
+    let hue = if chroma < 1e-10 {
+        0.
+    } else {
+        b.atan2(a).to_degrees()
+    };
+    [l, chroma, hue]
+}
+
+fn from_polar([l, c, h]: [f64; 3]) -> [f64; 3] {
+    let h = h.to_radians();
+    [l, c * h.cos(), c * h.sin()]
+}
+
+const LAB_EPSILON: f64 = 216. / 24389.;
+const LAB_KAPPA: f64 = 24389. / 27.;
+const D50: [f64; 3] = [0.3457 / 0.3585, 1., (1. - 0.3457 - 0.3585) / 0.3585];
+
+fn xyz_d50_to_lab(xyz: [f64; 3]) -> [f64; 3] {
+    let f = |v: f64| {
+        if v > LAB_EPSILON {
+            v.cbrt()
+        } else {
+            (LAB_KAPPA * v + 16.) / 116.
+        }
+    };
+    let f0 = f(xyz[0] / D50[0]);
+    let f1 = f(xyz[1] / D50[1]);
+    let f2 = f(xyz[2] / D50[2]);
+    [116. * f1 - 16., 500. * (f0 - f1), 200. * (f1 - f2)]
+}
+
+fn lab_to_xyz_d50([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let f1 = (l + 16.) / 116.;
+    let f0 = a / 500. + f1;
+    let f2 = f1 - b / 200.;
+    let inv = |f: f64| {
+        let cube = f * f * f;
+        if cube > LAB_EPSILON {
+            cube
+        } else {
+            (116. * f - 16.) / LAB_KAPPA
+        }
+    };
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        f1 * f1 * f1
+    } else {
+        l / LAB_KAPPA
+    };
+    [inv(f0) * D50[0], y * D50[1], inv(f2) * D50[2]]
+}
+
+const SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+const XYZ_TO_SRGB: [[f64; 3]; 3] = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [
+        0.05563007969699366,
+        -0.20397695888897652,
+        1.0569715142428786,
+    ],
+];
+const P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+const XYZ_TO_P3: [[f64; 3]; 3] = [
+    [2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+    [
+        -0.8294889695615747,
+        1.7626640603183463,
+        0.023624685841943577,
+    ],
+    [
+        0.03584583024378447,
+        -0.07617238926804182,
+        0.9568845240076872,
+    ],
+];
+const D65_TO_D50: [[f64; 3]; 3] = [
+    [
+        1.0479298208405488,
+        0.022946793341019088,
+        -0.05019222954313557,
+    ],
+    [
+        0.029627815688159344,
+        0.990434484573249,
+        -0.01707382502938514,
+    ],
+    [
+        -0.009243058152591178,
+        0.015055144896577895,
+        0.7518742899580008,
+    ],
+];
+const D50_TO_D65: [[f64; 3]; 3] = [
+    [
+        0.9554733942048979,
+        -0.023098374726038654,
+        0.06325919498911496,
+    ],
+    [
+        -0.02836971286639444,
+        1.0099953374555604,
+        0.02104147560735432,
+    ],
+    [
+        0.012314034948960157,
+        -0.02050758481440557,
+        1.3303659126444374,
+    ],
+];
+const XYZ_TO_LMS: [[f64; 3]; 3] = [
+    [0.8190224379967030, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+const LMS_TO_XYZ: [[f64; 3]; 3] = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.1122868032803170, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+const LMS_TO_OKLAB: [[f64; 3]; 3] = [
+    [0.2104542683093140, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.4285922420485799, 0.4505937096174110],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+const OKLAB_TO_LMS: [[f64; 3]; 3] = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn near(a: [f64; 3], b: [f64; 3]) -> bool {
+        a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-4)
+    }
+
+    #[test]
+    fn red_to_lab() {
+        let red = SpaceColor::new(ColorSpace::Rgb, [255., 0., 0.], 1.);
+        let lab = red.to_space(ColorSpace::Lab).channels();
+        assert!(near(lab, [54.29054, 80.80492, 69.89102]), "{:?}", lab);
+    }
+
+    #[test]
+    fn red_to_oklch() {
+        let red = SpaceColor::new(ColorSpace::Rgb, [255., 0., 0.], 1.);
+        let oklch = red.to_space(ColorSpace::Oklch).channels();
+        assert!(near(oklch, [0.62796, 0.25768, 29.23389]), "{:?}", oklch);
+    }
+
+    #[test]
+    fn round_trip() {
+        let c = SpaceColor::new(ColorSpace::Lch, [50., 30., 120.], 1.);
+        for space in
+            &[ColorSpace::Hwb, ColorSpace::DisplayP3, ColorSpace::Oklab]
+        {
+            let back = c.to_space(*space).to_space(ColorSpace::Lch);
+            assert!(near(back.channels(), c.channels()), "{:?}", back);
+        }
+    }
+
+    #[test]
+    fn fuzzy_round_trip() {
+        let c = SpaceColor::new(ColorSpace::Lab, [50., 40., -20.], 1.);
+        let back = c.to_space(ColorSpace::Oklch).to_space(ColorSpace::Lab);
+        assert!(back.fuzzy_eq(&c), "{:?}", back);
+        assert!(!back.fuzzy_eq(&c.to_space(ColorSpace::Lch)));
+    }
+
+    #[test]
+    fn gamut() {
+        let c = SpaceColor::new(ColorSpace::DisplayP3, [1., 0., 0.], 1.);
+        let srgb = c.to_space(ColorSpace::Srgb);
+        assert!(c.is_in_gamut());
+        assert!(!srgb.is_in_gamut());
+        assert!(srgb.clip().is_in_gamut());
+        assert!(srgb.to_gamut_local_minde().is_in_gamut());
+    }
+
+    #[test]
+    fn hue_interpolation() {
+        let (a, b) = HueInterpolation::Shorter.adjust(350., 10.);
+        assert_eq!((a + b) / 2., 360.);
+        let (a, b) = HueInterpolation::Longer.adjust(350., 10.);
+        assert_eq!((a + b) / 2., 180.);
+    }
+}
@@ -1,13 +1,17 @@
 mod colors;
+mod colorspace;
 mod list_separator;
 mod number;
 mod operator;
 mod quotes;
 mod unit;
+mod unitset;
 
-pub use self::colors::Rgba;
+pub use self::colors::{Color, Rgba};
+pub use self::colorspace::{ColorSpace, HueInterpolation, SpaceColor};
 pub use self::list_separator::ListSeparator;
-pub use self::number::Number;
+pub use self::number::{NumValue, Number};
 pub use self::operator::Operator;
 pub use self::quotes::Quotes;
 pub use self::unit::Unit;
+pub use self::unitset::UnitSet;
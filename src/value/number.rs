@@ -1,6 +1,12 @@
+use crate::error::Error;
 use crate::output::{Format, Formatted};
-use num_rational::Rational;
-use num_traits::{Signed, Zero};
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::{BigRational, Ratio, Rational};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed, ToPrimitive, Zero,
+};
+use std::cmp::Ordering;
 use std::fmt::{self, Write};
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
@@ -11,11 +17,37 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 /// between -1 and 1) is included.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Number {
-    pub value: Rational,
+    pub value: NumValue,
     pub plus_sign: bool,
     pub lead_zero: bool,
 }
 
+/// A numeric value, without unit.
+///
+/// Numbers are kept as exact rationals as long as possible.
+/// A rational that does not fit in `isize` becomes a big rational,
+/// and a result that can't be rational (e.g. a square root, or a
+/// division by zero) or that would be a very large big rational
+/// becomes a float.
+/// No arithmetic on a `NumValue` panics.
+///
+/// The `Eq` and `Ord` implementations are exact, with NaN equal to
+/// itself and greater than any other value.
+/// Sass comparisons are fuzzy, see [`fuzzy_cmp`](Self::fuzzy_cmp).
+#[derive(Clone, Debug)]
+pub enum NumValue {
+    Rational(Rational),
+    BigRational(BigRational),
+    Float(f64),
+}
+
+/// Floats closer than this are considered equal.
+const EPSILON: f64 = 1e-11;
+
+/// Big rationals with a numerator or denominator larger than this
+/// many bits become floats.
+const MAX_BITS: usize = 1024;
+
 impl Number {
     /// Computes the absolute value of the number, retaining the flags.
     pub fn abs(self) -> Self {
@@ -30,9 +62,60 @@ impl Number {
         self.value.is_integer()
     }
     /// Converts to an integer, rounding towards zero.
+    ///
+    /// Values outside the range of `isize` are saturated, and NaN is
+    /// converted to zero.
+    /// See [`to_isize`](Self::to_isize) for a checked conversion.
     pub fn to_integer(&self) -> isize {
         self.value.to_integer()
     }
+    /// Converts to an integer, rounding towards zero.
+    ///
+    /// Returns `None` if the value is outside the range of `isize`,
+    /// or not a number.
+    pub fn to_isize(&self) -> Option<isize> {
+        self.value.to_isize()
+    }
+    /// Get this number as an `isize` rational.
+    ///
+    /// Fails for numbers that can not be (approximately) represented
+    /// as such, e.g. infinite numbers.
+    pub fn as_ratio(&self) -> Result<Rational, Error> {
+        self.value.as_ratio()
+    }
+    /// Get this number as a float.
+    pub fn to_f64(&self) -> f64 {
+        self.value.to_f64()
+    }
+    /// The smallest integer greater than or equal to this number.
+    pub fn ceil(&self) -> Self {
+        Number::from(self.value.map(Ratio::ceil, Ratio::ceil, f64::ceil))
+    }
+    /// The largest integer less than or equal to this number.
+    pub fn floor(&self) -> Self {
+        Number::from(self.value.map(Ratio::floor, Ratio::floor, f64::floor))
+    }
+    /// The nearest integer, rounding half-way cases away from zero.
+    pub fn round(&self) -> Self {
+        Number::from(self.value.map(Ratio::round, Ratio::round, f64::round))
+    }
+    /// Returns true if this number is NaN.
+    pub fn is_nan(&self) -> bool {
+        match self.value {
+            NumValue::Float(f) => f.is_nan(),
+            _ => false,
+        }
+    }
+    /// Returns true if this number is finite, i.e. not infinite or NaN.
+    pub fn is_finite(&self) -> bool {
+        match self.value {
+            NumValue::Float(f) => f.is_finite(),
+            _ => true,
+        }
+    }
+    pub fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
     pub fn format(&self, format: Format) -> Formatted<Self> {
         Formatted {
             value: self,
@@ -41,9 +124,233 @@ impl Number {
     }
 }
 
+impl NumValue {
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            -self
+        } else {
+            self.clone()
+        }
+    }
+    fn is_negative(&self) -> bool {
+        match self {
+            NumValue::Rational(r) => r.is_negative(),
+            NumValue::BigRational(r) => r.is_negative(),
+            NumValue::Float(f) => *f < 0.,
+        }
+    }
+    fn is_integer(&self) -> bool {
+        match self {
+            NumValue::Rational(r) => r.is_integer(),
+            NumValue::BigRational(r) => r.is_integer(),
+            NumValue::Float(f) => {
+                f.is_finite() && (f - f.round()).abs() < EPSILON
+            }
+        }
+    }
+    fn to_integer(&self) -> isize {
+        match self {
+            NumValue::Rational(r) => r.to_integer(),
+            NumValue::BigRational(r) => {
+                r.to_integer().to_isize().unwrap_or(if r.is_negative() {
+                    isize::MIN
+                } else {
+                    isize::MAX
+                })
+            }
+            NumValue::Float(f) if self.is_integer() => f.round() as isize,
+            NumValue::Float(f) => *f as isize,
+        }
+    }
+    fn to_isize(&self) -> Option<isize> {
+        match self {
+            NumValue::Rational(r) => Some(r.to_integer()),
+            NumValue::BigRational(r) => r.to_integer().to_isize(),
+            NumValue::Float(f) => {
+                let f = if self.is_integer() {
+                    f.round()
+                } else {
+                    f.trunc()
+                };
+                // Note: isize::MAX as f64 rounds up to 2^63.
+                if f >= isize::MIN as f64 && f < isize::MAX as f64 {
+                    Some(f as isize)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+    fn as_ratio(&self) -> Result<Rational, Error> {
+        match self {
+            NumValue::Rational(r) => Some(*r),
+            NumValue::BigRational(_) => {
+                Rational::approximate_float(self.to_f64())
+            }
+            NumValue::Float(f) => Rational::approximate_float(*f),
+        }
+        .ok_or_else(|| {
+            Error::S(format!(
+                "{} is not a number in a usable range.",
+                Number::from(self.clone()).format(Format::default())
+            ))
+        })
+    }
+    fn to_f64(&self) -> f64 {
+        match self {
+            NumValue::Rational(r) => *r.numer() as f64 / *r.denom() as f64,
+            NumValue::BigRational(r) => {
+                // Shift away low bits to keep both parts in f64 range.
+                let bits = r.numer().bits().max(r.denom().bits());
+                let shift = bits.saturating_sub(1000);
+                let n = r.numer() >> shift;
+                let d = r.denom() >> shift;
+                match (n.to_f64(), d.to_f64()) {
+                    (Some(n), Some(d)) if d != 0. => n / d,
+                    (Some(n), _) if n < 0. => f64::NEG_INFINITY,
+                    (Some(n), _) if n > 0. => f64::INFINITY,
+                    _ => 0.,
+                }
+            }
+            NumValue::Float(f) => *f,
+        }
+    }
+    /// Get this value as a big rational, unless it is a float.
+    fn to_big(&self) -> Option<BigRational> {
+        match self {
+            NumValue::Rational(r) => Some(Ratio::new_raw(
+                BigInt::from(*r.numer()),
+                BigInt::from(*r.denom()),
+            )),
+            NumValue::BigRational(r) => Some(r.clone()),
+            NumValue::Float(_) => None,
+        }
+    }
+    /// Create a value from a big rational, which is made small if it
+    /// fits.
+    fn from_big(value: BigRational) -> Self {
+        match (value.numer().to_isize(), value.denom().to_isize()) {
+            (Some(n), Some(d)) => NumValue::Rational(Ratio::new_raw(n, d)),
+            _ => NumValue::BigRational(value),
+        }
+    }
+    /// Create a value from the decimal digits before and after the
+    /// decimal point, e.g. `b"12"` and `b"34"` for 12.34.
+    ///
+    /// Returns `None` if there are no digits, or any non-digits.
+    pub(crate) fn from_decimal(
+        whole: &[u8],
+        decimals: &[u8],
+    ) -> Option<Self> {
+        if (whole.is_empty() && decimals.is_empty())
+            || !whole.iter().chain(decimals).all(u8::is_ascii_digit)
+        {
+            return None;
+        }
+        let small = || {
+            let mut n: isize = 0;
+            let mut d: isize = 1;
+            for c in whole {
+                n = n.checked_mul(10)?.checked_add(isize::from(c - b'0'))?;
+            }
+            for c in decimals {
+                n = n.checked_mul(10)?.checked_add(isize::from(c - b'0'))?;
+                d = d.checked_mul(10)?;
+            }
+            Some(Rational::new(n, d))
+        };
+        if let Some(value) = small() {
+            return Some(NumValue::Rational(value));
+        }
+        let digits = [whole, decimals].concat();
+        let n = BigInt::parse_bytes(&digits, 10)?;
+        Some(NumValue::from_big(BigRational::new(
+            n,
+            num_traits::pow(BigInt::from(10), decimals.len()),
+        )))
+    }
+    /// Get ten to the power of `exp`.
+    ///
+    /// This is exact, except for very large or small exponents.
+    pub(crate) fn pow10(exp: i64) -> Self {
+        if exp.abs() > 400 {
+            NumValue::Float(10f64.powf(exp as f64))
+        } else {
+            let p = num_traits::pow(
+                BigInt::from(10),
+                exp.unsigned_abs() as usize,
+            );
+            NumValue::from_big(if exp < 0 {
+                BigRational::new(BigInt::from(1), p)
+            } else {
+                BigRational::from_integer(p)
+            })
+        }
+    }
+    /// Compare two values as sass does.
+    ///
+    /// If either value is a float, values that differ by less than
+    /// `1e-11` are equal.
+    /// NaN is not ordered relative to any value, not even NaN.
+    pub fn fuzzy_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (NumValue::Float(_), _) | (_, NumValue::Float(_)) => {
+                let (a, b) = (self.to_f64(), other.to_f64());
+                if (a - b).abs() < EPSILON {
+                    Some(Ordering::Equal)
+                } else {
+                    a.partial_cmp(&b)
+                }
+            }
+            _ => Some(self.cmp(other)),
+        }
+    }
+    fn map(
+        &self,
+        small: fn(&Rational) -> Rational,
+        big: fn(&BigRational) -> BigRational,
+        float: fn(f64) -> f64,
+    ) -> Self {
+        match self {
+            NumValue::Rational(r) => NumValue::Rational(small(r)),
+            NumValue::BigRational(r) => NumValue::from_big(big(r)),
+            NumValue::Float(f) => NumValue::Float(float(*f)),
+        }
+    }
+    /// Apply a binary operation.
+    ///
+    /// The operation is done with `isize` rationals if possible, with
+    /// big rationals if that overflows, and with floats if either
+    /// value is a float, the operation can't be done with rationals
+    /// or the big rational result would be larger than `MAX_BITS`.
+    fn binop(
+        &self,
+        rhs: &Self,
+        small: fn(&Rational, &Rational) -> Option<Rational>,
+        big: fn(BigRational, BigRational) -> Option<BigRational>,
+        float: fn(f64, f64) -> f64,
+    ) -> Self {
+        if let (NumValue::Rational(a), NumValue::Rational(b)) = (self, rhs) {
+            if let Some(result) = small(a, b) {
+                return NumValue::Rational(result);
+            }
+        }
+        if let (Some(a), Some(b)) = (self.to_big(), rhs.to_big()) {
+            if let Some(result) = big(a, b) {
+                if result.numer().bits() <= MAX_BITS
+                    && result.denom().bits() <= MAX_BITS
+                {
+                    return NumValue::from_big(result);
+                }
+            }
+        }
+        NumValue::Float(float(self.to_f64(), rhs.to_f64()))
+    }
+}
+
 impl<T> From<T> for Number
 where
-    T: Into<Rational>,
+    T: Into<NumValue>,
 {
     fn from(value: T) -> Number {
         Number {
@@ -54,41 +361,184 @@ where
     }
 }
 
+impl From<Rational> for NumValue {
+    fn from(value: Rational) -> Self {
+        NumValue::Rational(value)
+    }
+}
+impl From<BigRational> for NumValue {
+    fn from(value: BigRational) -> Self {
+        NumValue::from_big(value)
+    }
+}
+impl From<isize> for NumValue {
+    fn from(value: isize) -> Self {
+        NumValue::Rational(Rational::from_integer(value))
+    }
+}
+impl From<i32> for NumValue {
+    fn from(value: i32) -> Self {
+        NumValue::from(value as isize)
+    }
+}
+impl From<f64> for NumValue {
+    fn from(value: f64) -> Self {
+        NumValue::Float(value)
+    }
+}
+
+impl Add for &NumValue {
+    type Output = NumValue;
+    fn add(self, rhs: Self) -> NumValue {
+        self.binop(
+            rhs,
+            |a, b| a.checked_add(b),
+            |a, b| Some(a + b),
+            |a, b| a + b,
+        )
+    }
+}
+impl Sub for &NumValue {
+    type Output = NumValue;
+    fn sub(self, rhs: Self) -> NumValue {
+        self.binop(
+            rhs,
+            |a, b| a.checked_sub(b),
+            |a, b| Some(a - b),
+            |a, b| a - b,
+        )
+    }
+}
+impl Mul for &NumValue {
+    type Output = NumValue;
+    fn mul(self, rhs: Self) -> NumValue {
+        self.binop(
+            rhs,
+            |a, b| a.checked_mul(b),
+            |a, b| Some(a * b),
+            |a, b| a * b,
+        )
+    }
+}
+impl Div for &NumValue {
+    type Output = NumValue;
+    fn div(self, rhs: Self) -> NumValue {
+        self.binop(
+            rhs,
+            |a, b| if b.is_zero() { None } else { a.checked_div(b) },
+            |a, b| if b.is_zero() { None } else { Some(a / b) },
+            |a, b| a / b,
+        )
+    }
+}
+impl Rem for &NumValue {
+    type Output = NumValue;
+    fn rem(self, rhs: Self) -> NumValue {
+        self.binop(
+            rhs,
+            |a, b| {
+                if b.is_zero() {
+                    return None;
+                }
+                let q = a.checked_div(b)?.trunc();
+                a.checked_sub(&q.checked_mul(b)?)
+            },
+            |a, b| if b.is_zero() { None } else { Some(a % b) },
+            |a, b| a % b,
+        )
+    }
+}
+impl Neg for &NumValue {
+    type Output = NumValue;
+    fn neg(self) -> NumValue {
+        match self {
+            NumValue::Rational(r) => match r.numer().checked_neg() {
+                Some(n) => NumValue::Rational(Ratio::new_raw(n, *r.denom())),
+                None => NumValue::from_big(-self.to_big().unwrap()),
+            },
+            NumValue::BigRational(r) => NumValue::from_big(-r),
+            NumValue::Float(f) => NumValue::Float(-f),
+        }
+    }
+}
+
+impl PartialEq for NumValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for NumValue {}
+
+impl PartialOrd for NumValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for NumValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (NumValue::Rational(a), NumValue::Rational(b)) => a.cmp(b),
+            (NumValue::Float(a), NumValue::Float(b)) => match (a, b) {
+                (a, b) if a.is_nan() || b.is_nan() => {
+                    a.is_nan().cmp(&b.is_nan())
+                }
+                (a, b) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+            },
+            (NumValue::Float(f), r) => float_cmp_exact(*f, r),
+            (r, NumValue::Float(f)) => float_cmp_exact(*f, r).reverse(),
+            _ => self.to_big().cmp(&other.to_big()),
+        }
+    }
+}
+
+/// Compare a float to a value that is not a float, exactly.
+fn float_cmp_exact(f: f64, other: &NumValue) -> Ordering {
+    if f.is_nan() {
+        Ordering::Greater
+    } else {
+        match BigRational::from_float(f) {
+            Some(f) => Some(f).cmp(&other.to_big()),
+            None if f > 0. => Ordering::Greater,
+            None => Ordering::Less,
+        }
+    }
+}
+
 impl Add for Number {
     type Output = Number;
     fn add(self, rhs: Self) -> Self::Output {
-        Number::from(self.value + rhs.value)
+        Number::from(&self.value + &rhs.value)
     }
 }
 impl<'a> Div for &'a Number {
     type Output = Number;
     fn div(self, rhs: Self) -> Self::Output {
-        Number::from(self.value / rhs.value)
+        Number::from(&self.value / &rhs.value)
     }
 }
 impl<'a> Mul for &'a Number {
     type Output = Number;
     fn mul(self, rhs: Self) -> Self::Output {
-        Number::from(self.value * rhs.value)
+        Number::from(&self.value * &rhs.value)
     }
 }
 impl<'a> Rem for &'a Number {
     type Output = Number;
     fn rem(self, rhs: Self) -> Self::Output {
-        Number::from(self.value % rhs.value)
+        Number::from(&self.value % &rhs.value)
     }
 }
 impl<'a> Neg for &'a Number {
     type Output = Number;
     fn neg(self) -> Number {
-        Number::from(-self.value)
+        Number::from(-&self.value)
     }
 }
 
 impl<'a> Sub for &'a Number {
     type Output = Number;
     fn sub(self, rhs: Self) -> Self::Output {
-        Number::from(self.value - rhs.value)
+        Number::from(&self.value - &rhs.value)
     }
 }
 impl Zero for Number {
@@ -96,77 +546,233 @@ impl Zero for Number {
         Number::from(0)
     }
     fn is_zero(&self) -> bool {
-        self.value.is_zero()
+        match self.value {
+            NumValue::Rational(ref r) => r.is_zero(),
+            NumValue::BigRational(ref r) => r.is_zero(),
+            NumValue::Float(f) => f == 0.,
+        }
     }
 }
 
 impl<'a> fmt::Display for Formatted<'a, Number> {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
-        let mut frac = self.value.value.fract();
+        let precision = self.format.precision;
+        let (negative, whole, dec) = match self.value.value {
+            NumValue::Rational(ref r) => rational_parts(r, precision)?,
+            NumValue::BigRational(ref r) => rational_parts(r, precision)?,
+            NumValue::Float(f) if f.is_nan() => return out.write_str("NaN"),
+            NumValue::Float(f) if f.is_infinite() => {
+                return out.write_str(if f < 0. {
+                    "-Infinity"
+                } else {
+                    "Infinity"
+                });
+            }
+            NumValue::Float(f) => float_parts(f, precision),
+        };
 
-        let mut whole = self.value.value.to_integer().abs();
-        let mut dec = String::with_capacity(if frac.is_zero() {
-            0
-        } else {
-            self.format.precision
-        });
+        if negative && (whole != "0" || !dec.is_empty()) {
+            out.write_char('-')?;
+        }
 
-        if !frac.is_zero() {
-            for _ in 0..(self.format.precision - 1) {
-                frac *= 10;
-                write!(dec, "{}", frac.to_integer().abs())?;
-                frac = frac.fract();
-                if frac.is_zero() {
-                    break;
-                }
+        let skip_zero = self.format.is_compressed() || !self.value.lead_zero;
+        if !(whole == "0" && skip_zero && !dec.is_empty()) {
+            out.write_str(&whole)?;
+        }
+
+        if !dec.is_empty() {
+            write!(out, ".{}", dec)?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the sign, the whole part and the decimals (to `precision`
+/// digits) of a rational number.
+fn rational_parts<T>(
+    value: &Ratio<T>,
+    precision: usize,
+) -> Result<(bool, String, String), fmt::Error>
+where
+    T: Clone + Integer + Signed + fmt::Display + From<u8>,
+{
+    let ten = || T::from(10);
+    let mut frac = value.fract();
+
+    let mut whole = value.to_integer().abs();
+    let mut dec =
+        String::with_capacity(if frac.is_zero() { 0 } else { precision });
+
+    if !frac.is_zero() {
+        for _ in 0..(precision - 1) {
+            frac = frac * ten();
+            write!(dec, "{}", frac.to_integer().abs())?;
+            frac = frac.fract();
+            if frac.is_zero() {
+                break;
             }
-            if !frac.is_zero() {
-                let end = (frac * 10).round().abs().to_integer();
-                if end == 10 {
-                    loop {
-                        match dec.pop() {
-                            Some('9') => continue,
-                            None => {
-                                whole += 1;
-                                break;
-                            }
-                            Some(c) => {
-                                dec.push(char::from(c as u8 + 1));
-                                break;
-                            }
+        }
+        if !frac.is_zero() {
+            let end = (frac * ten()).round().abs().to_integer();
+            if end == ten() {
+                loop {
+                    match dec.pop() {
+                        Some('9') => continue,
+                        None => {
+                            whole = whole + T::one();
+                            break;
+                        }
+                        Some(c) => {
+                            dec.push(char::from(c as u8 + 1));
+                            break;
                         }
                     }
-                } else if end == 0 {
-                    loop {
-                        match dec.pop() {
-                            Some('0') => continue,
-                            None => break,
-                            Some(c) => {
-                                dec.push(c);
-                                break;
-                            }
+                }
+            } else if end.is_zero() {
+                loop {
+                    match dec.pop() {
+                        Some('0') => continue,
+                        None => break,
+                        Some(c) => {
+                            dec.push(c);
+                            break;
                         }
                     }
-                } else {
-                    write!(dec, "{}", end)?;
                 }
+            } else {
+                write!(dec, "{}", end)?;
             }
         }
+    }
+    Ok((value.is_negative(), whole.to_string(), dec))
+}
 
-        if self.value.value.is_negative()
-            && (!whole.is_zero() || !dec.is_empty())
-        {
-            out.write_char('-')?;
-        }
+/// Get the sign, the whole part and the decimals (to `precision`
+/// digits) of a finite float.
+fn float_parts(value: f64, precision: usize) -> (bool, String, String) {
+    let s = format!("{:.*}", precision, value.abs());
+    let (whole, dec) = match s.find('.') {
+        Some(p) => (&s[..p], s[p + 1..].trim_end_matches('0')),
+        None => (&s[..], ""),
+    };
+    (value < 0., whole.to_string(), dec.to_string())
+}
 
-        let skip_zero = self.format.is_compressed() || !self.value.lead_zero;
-        if !(whole == 0 && skip_zero && !dec.is_empty()) {
-            write!(out, "{}", whole)?;
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fmt(value: impl Into<NumValue>) -> String {
+        Number::from(value).format(Format::default()).to_string()
+    }
+
+    #[test]
+    fn no_overflow() {
+        let third = NumValue::from(Rational::new(1, 3));
+        let mut v = NumValue::from(1);
+        for _ in 0..100 {
+            v = &v * &third;
         }
+        assert!(matches!(v, NumValue::BigRational(_)));
+        assert_eq!(fmt(v), "0");
+        let big = &NumValue::from(isize::MAX) + &NumValue::from(1);
+        assert_eq!(fmt(big), "9223372036854775808");
+    }
 
-        if !dec.is_empty() {
-            write!(out, ".{}", dec)?;
+    #[test]
+    fn back_to_small() {
+        let big = &NumValue::from(isize::MAX) * &NumValue::from(4);
+        let small = &big / &NumValue::from(isize::MAX);
+        assert!(matches!(small, NumValue::Rational(_)));
+        assert_eq!(fmt(small), "4");
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let zero = NumValue::from(0);
+        assert_eq!(fmt(&NumValue::from(1) / &zero), "Infinity");
+        assert_eq!(fmt(&NumValue::from(-1) / &zero), "-Infinity");
+        assert_eq!(fmt(&zero / &zero), "NaN");
+        assert_eq!(fmt(&NumValue::from(1) % &zero), "NaN");
+    }
+
+    #[test]
+    fn checked_integer() {
+        assert_eq!(Number::from(-17).to_isize(), Some(-17));
+        assert_eq!(Number::from(2.5).to_isize(), Some(2));
+        assert_eq!(Number::from(1e30).to_isize(), None);
+        assert_eq!(Number::from(f64::NAN).to_isize(), None);
+        let big = &NumValue::from(isize::MAX) + &NumValue::from(1);
+        assert_eq!(Number::from(big).to_isize(), None);
+    }
+
+    #[test]
+    fn float_format() {
+        assert_eq!(fmt(0.1 + 0.2), "0.3");
+        assert_eq!(fmt(-1e-12), "0");
+        assert_eq!(fmt(2.5), "2.5");
+        assert_eq!(fmt(-17.0), "-17");
+    }
+
+    #[test]
+    fn fuzzy_equality() {
+        let third = NumValue::from(Rational::new(3, 10));
+        assert_eq!(
+            NumValue::from(0.1 + 0.2).fuzzy_cmp(&third),
+            Some(Ordering::Equal)
+        );
+        assert_ne!(NumValue::from(0.1 + 0.2), third);
+        assert!(NumValue::from(0.3) < NumValue::from(0.30001));
+        let nan = NumValue::from(f64::NAN);
+        assert_eq!(nan.fuzzy_cmp(&nan), None);
+    }
+
+    #[test]
+    fn exact_order() {
+        let a = NumValue::from(1.0);
+        let b = NumValue::from(1.0 + 6e-12);
+        let c = NumValue::from(1.0 + 12e-12);
+        assert!(a < b && b < c && a < c);
+        let nan = NumValue::from(f64::NAN);
+        assert_eq!(nan, nan);
+        assert!(nan > NumValue::from(f64::INFINITY));
+        assert!(NumValue::from(0.5) == NumValue::from(Rational::new(1, 2)));
+        let big = &NumValue::from(isize::MAX) * &NumValue::from(4);
+        assert!(NumValue::from(f64::INFINITY) > big);
+        assert!(NumValue::from(1e30) > big);
+    }
+
+    #[test]
+    fn big_falls_back_to_float() {
+        let mut v = NumValue::from(Rational::new(11, 10));
+        for _ in 0..60 {
+            v = &v * &v;
         }
-        Ok(())
+        assert!(matches!(v, NumValue::Float(_)));
+        assert_eq!(fmt(v), "Infinity");
+    }
+
+    #[test]
+    fn pow10() {
+        assert_eq!(fmt(NumValue::pow10(3)), "1000");
+        assert_eq!(fmt(NumValue::pow10(-2)), "0.01");
+        assert!(matches!(NumValue::pow10(30), NumValue::BigRational(_)));
+        assert_eq!(fmt(NumValue::pow10(5000)), "Infinity");
+    }
+
+    #[test]
+    fn parse_long_decimal() {
+        let v = NumValue::from_decimal(b"1", b"00000000000000000001");
+        assert!(matches!(v, Some(NumValue::BigRational(_))));
+        assert_eq!(fmt(v.unwrap()), "1");
+    }
+
+    #[test]
+    fn parse_bad_decimal() {
+        assert!(NumValue::from_decimal(b"", b"").is_none());
+        assert!(NumValue::from_decimal(b"1x", b"").is_none());
+        assert!(
+            NumValue::from_decimal(b"12345678901234567890", b"x").is_none()
+        );
     }
 }
@@ -1,6 +1,7 @@
 use crate::css::Value;
-use crate::value::{ListSeparator, Quotes, Unit};
-use num_traits::Zero;
+use crate::error::Error;
+use crate::value::{Color, ListSeparator, Number, Quotes, UnitSet};
+use std::cmp::Ordering;
 use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -24,8 +25,21 @@ pub enum Operator {
 }
 
 impl Operator {
-    pub fn eval(&self, a: Value, b: Value) -> Option<Value> {
-        match *self {
+    /// Evaluate this operator on the values `a` and `b`.
+    ///
+    /// Returns `Ok(None)` if the operation is not defined for the
+    /// values, so that it should be kept as is in the output.
+    /// Arithmetic on numbers with incompatible units is an error.
+    pub fn eval(&self, a: Value, b: Value) -> Result<Option<Value>, Error> {
+        if self.is_arithmetic(&a, &b) && non_legacy_arithmetic(&a, &b) {
+            return Err(Error::S(format!(
+                "Undefined operation \"{} {} {}\".",
+                a.format(Default::default()),
+                self,
+                b.format(Default::default()),
+            )));
+        }
+        Ok(match *self {
             Operator::And => Some(Value::bool(a.is_true() && b.is_true())),
             Operator::Or => {
                 if a.is_true() {
@@ -36,26 +50,33 @@ impl Operator {
             }
             Operator::Equal => Some(Value::bool(equal_values(&a, &b))),
             Operator::NotEqual => Some(Value::bool(!equal_values(&a, &b))),
-            Operator::Greater => Some(Value::bool(a > b)),
-            Operator::GreaterE => Some(Value::bool(a >= b)),
-            Operator::Lesser => Some(Value::bool(a < b)),
-            Operator::LesserE => Some(Value::bool(a <= b)),
+            Operator::Greater => Some(Value::bool(
+                compare(&a, &b)?.map_or(false, Ordering::is_gt),
+            )),
+            Operator::GreaterE => Some(Value::bool(
+                compare(&a, &b)?.map_or(false, Ordering::is_ge),
+            )),
+            Operator::Lesser => Some(Value::bool(
+                compare(&a, &b)?.map_or(false, Ordering::is_lt),
+            )),
+            Operator::LesserE => Some(Value::bool(
+                compare(&a, &b)?.map_or(false, Ordering::is_le),
+            )),
             Operator::Plus => match (a, b) {
-                (Value::Color(a, _), Value::Numeric(bn, Unit::None, _)) => {
-                    let bn = bn.value;
-                    Some(Value::Color(a + bn, None))
-                }
-                (Value::Color(a, _), Value::Color(b, _)) => {
-                    Some(Value::Color(a + b, None))
-                }
+                (
+                    Value::Color(Color::Rgba(a), _),
+                    Value::Numeric(bn, bu, _),
+                ) if bu.is_none() => bn
+                    .as_ratio()
+                    .ok()
+                    .map(|bn| Value::Color((a + bn).into(), None)),
+                (
+                    Value::Color(Color::Rgba(a), _),
+                    Value::Color(Color::Rgba(b), _),
+                ) => Some(Value::Color((a + b).into(), None)),
                 (Value::Numeric(a, au, ..), Value::Numeric(b, bu, ..)) => {
-                    if au == bu || bu == Unit::None {
-                        Some(Value::Numeric(a + b, au, true))
-                    } else if au == Unit::None {
-                        Some(Value::Numeric(a + b, bu, true))
-                    } else {
-                        None
-                    }
+                    let (b, unit) = add_units(b, bu, au)?;
+                    Some(Value::Numeric(a + b, unit, true))
                 }
                 (Value::Literal(a, Quotes::None), Value::Literal(b, _)) => {
                     Some(Value::Literal(format!("{}{}", a, b), Quotes::None))
@@ -75,26 +96,23 @@ impl Operator {
             },
             Operator::Minus => match (&a, &b) {
                 (
-                    &Value::Color(ref a, _),
-                    &Value::Numeric(ref bn, Unit::None, _),
-                ) => {
-                    let bn = bn.value;
-                    Some(Value::Color(a - bn, None))
-                }
-                (&Value::Color(ref a, _), &Value::Color(ref b, _)) => {
-                    Some(Value::Color(a - b, None))
-                }
+                    &Value::Color(Color::Rgba(ref a), _),
+                    &Value::Numeric(ref bn, ref bu, _),
+                ) if bu.is_none() => bn
+                    .as_ratio()
+                    .ok()
+                    .map(|bn| Value::Color((a - bn).into(), None)),
+                (
+                    &Value::Color(Color::Rgba(ref a), _),
+                    &Value::Color(Color::Rgba(ref b), _),
+                ) => Some(Value::Color((a - b).into(), None)),
                 (
                     &Value::Numeric(ref av, ref au, ..),
                     &Value::Numeric(ref bv, ref bu, ..),
                 ) => {
-                    if au == bu || bu == &Unit::None {
-                        Some(Value::Numeric(av - bv, au.clone(), true))
-                    } else if au == &Unit::None {
-                        Some(Value::Numeric(av - bv, bu.clone(), true))
-                    } else {
-                        None
-                    }
+                    let (bv, unit) =
+                        add_units(bv.clone(), bu.clone(), au.clone())?;
+                    Some(Value::Numeric(av - &bv, unit, true))
                 }
                 // Note: This very special case should probably be much
                 // more general.
@@ -119,13 +137,8 @@ impl Operator {
                     &Value::Numeric(ref b, ref bu, ..),
                 ) = (&a, &b)
                 {
-                    if bu == &Unit::None {
-                        Some(Value::Numeric(a * b, au.clone(), true))
-                    } else if au == &Unit::None {
-                        Some(Value::Numeric(a * b, bu.clone(), true))
-                    } else {
-                        None
-                    }
+                    let (unit, factor) = au.multiply(bu);
+                    Some(Value::Numeric(&(a * b) * &factor, unit, true))
                 } else {
                     None
                 }
@@ -134,33 +147,22 @@ impl Operator {
                 if a.is_calculated() || b.is_calculated() {
                     match (&a, &b) {
                         (
-                            &Value::Color(ref a, _),
-                            &Value::Numeric(ref bn, Unit::None, ..),
-                        ) => {
-                            let bn = bn.value;
-                            Some(Value::Color(a / bn, None))
-                        }
+                            &Value::Color(Color::Rgba(ref a), _),
+                            &Value::Numeric(ref bn, ref bu, ..),
+                        ) if bu.is_none() => bn
+                            .as_ratio()
+                            .ok()
+                            .map(|bn| Value::Color((a / bn).into(), None)),
                         (
                             &Value::Numeric(ref av, ref au, ..),
                             &Value::Numeric(ref bv, ref bu, ..),
                         ) => {
-                            if bv.is_zero() {
-                                None
-                            } else if bu == &Unit::None {
-                                Some(Value::Numeric(
-                                    av / bv,
-                                    au.clone(),
-                                    true,
-                                ))
-                            } else if au == bu {
-                                Some(Value::Numeric(
-                                    av / bv,
-                                    Unit::None,
-                                    true,
-                                ))
-                            } else {
-                                None
-                            }
+                            let (unit, factor) = au.divide(bu);
+                            Some(Value::Numeric(
+                                &(av / bv) * &factor,
+                                unit,
+                                true,
+                            ))
                         }
                         //_ => None,
                         (a, b) => Some(Value::BinOp(
@@ -175,26 +177,69 @@ impl Operator {
                     None
                 }
             }
-            Operator::Modulo => match (&a, &b) {
-                (
-                    &Value::Numeric(ref av, ref au, ..),
-                    &Value::Numeric(ref bv, ref bu, ..),
-                ) => {
-                    if au == bu && !bv.is_zero() {
-                        Some(Value::Numeric(av % bv, Unit::None, true))
-                    } else if bu == &Unit::None && !bv.is_zero() {
-                        Some(Value::Numeric(av % bv, au.clone(), true))
-                    } else {
-                        None
-                    }
+            Operator::Modulo => match (a, b) {
+                (Value::Numeric(av, au, ..), Value::Numeric(bv, bu, ..)) => {
+                    let (bv, unit) = add_units(bv, bu, au)?;
+                    Some(Value::Numeric(&av % &bv, unit, true))
                 }
                 _ => None,
             },
             Operator::Not => panic!("not is a unary operator only"),
+        })
+    }
+
+    /// True if this is an arithmetic operator, that will be
+    /// evaluated for `a` and `b`.
+    ///
+    /// A division is only evaluated if either operand is calculated.
+    fn is_arithmetic(&self, a: &Value, b: &Value) -> bool {
+        match *self {
+            Operator::Plus
+            | Operator::Minus
+            | Operator::Multiply
+            | Operator::Modulo => true,
+            Operator::Div => a.is_calculated() || b.is_calculated(),
+            _ => false,
         }
     }
 }
 
+/// Arithmetic is only defined for legacy colors, so a non-legacy color
+/// can't be combined with a number or another color.
+fn non_legacy_arithmetic(a: &Value, b: &Value) -> bool {
+    let is_non_legacy = |v: &Value| match v {
+        Value::Color(c, _) => !c.is_legacy(),
+        _ => false,
+    };
+    let is_operand =
+        |v: &Value| matches!(v, Value::Color(..) | Value::Numeric(..));
+    (is_non_legacy(a) && is_operand(b)) || (is_non_legacy(b) && is_operand(a))
+}
+
+/// Get the value `b` in `b_unit` converted to `a_unit`, for an
+/// additive operation, and the unit of the result.
+///
+/// If either value is unitless, no conversion is needed and the unit
+/// of the other value is used.
+fn add_units(
+    b: Number,
+    b_unit: UnitSet,
+    a_unit: UnitSet,
+) -> Result<(Number, UnitSet), Error> {
+    if b_unit.is_none() {
+        Ok((b, a_unit))
+    } else if a_unit.is_none() {
+        Ok((b, b_unit))
+    } else if let Some(factor) = b_unit.scale_to(&a_unit) {
+        Ok((&b * &factor, a_unit))
+    } else {
+        Err(Error::S(format!(
+            "Incompatible units {} and {}.",
+            a_unit, b_unit
+        )))
+    }
+}
+
 impl fmt::Display for Operator {
     fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -227,9 +272,40 @@ impl fmt::Display for Operator {
 ///
 /// Make this a separate function, so the rust == operator is still
 /// the strict derived version, for unit tests etc.
+///
+/// Numbers with compatible units are compared after conversion, so
+/// e.g. `1in == 96px`.  NaN is not equal to anything, not even NaN.
+/// Colors are equal if they have the same channels, regardless of
+/// how they are written, see [`Color::equals`].
 fn equal_values(a: &Value, b: &Value) -> bool {
     match (a, b) {
+        (Value::Color(a, _), Value::Color(b, _)) => a.equals(b),
         (&Value::Literal(ref a, _), &Value::Literal(ref b, _)) => a == b,
+        (Value::Numeric(av, au, ..), Value::Numeric(bv, bu, ..)) => {
+            match bu.scale_to(au) {
+                Some(factor) => {
+                    av.value.fuzzy_cmp(&(bv * &factor).value)
+                        == Some(Ordering::Equal)
+                }
+                None => false,
+            }
+        }
         (a, b) => a == b,
     }
 }
+
+/// Compare two values for the relational operators.
+///
+/// Numbers are compared after unit conversion, and comparing
+/// numbers with incompatible units is an error.
+/// NaN is not ordered relative to any number, so `None` is returned
+/// if either operand is NaN.
+fn compare(a: &Value, b: &Value) -> Result<Option<Ordering>, Error> {
+    match (a, b) {
+        (Value::Numeric(av, au, ..), Value::Numeric(bv, bu, ..)) => {
+            let (bv, _) = add_units(bv.clone(), bu.clone(), au.clone())?;
+            Ok(av.value.fuzzy_cmp(&bv.value))
+        }
+        (a, b) => Ok(Some(a.cmp(b))),
+    }
+}
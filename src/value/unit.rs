@@ -1,7 +1,7 @@
 //! The Unit enum defines css units
 
+use super::Number;
 use num_rational::Rational;
-use num_traits::One;
 use std::fmt;
 
 /// Units in css.
@@ -41,9 +41,13 @@ pub enum Unit {
     Dpi,
     Dpcm,
     Dppx,
+    /// `x` is an alias for `dppx`.
+    X,
     // Special units
     Percent,
     Fr,
+    /// Any other unit, as written.
+    Unknown(String),
     None,
 }
 
@@ -72,47 +76,77 @@ impl Unit {
 
             Unit::Hz | Unit::Khz => "frequency",
 
-            Unit::Dpi | Unit::Dpcm | Unit::Dppx => "resolution",
+            Unit::Dpi | Unit::Dpcm | Unit::Dppx | Unit::X => "resolution",
 
             Unit::Percent | Unit::Fr | Unit::None => "none",
+
+            Unit::Unknown(_) => "unknown",
+        }
+    }
+
+    /// Get the number of `self` units per `other` unit, if the units
+    /// are convertible to each other.
+    ///
+    /// Relative units, like `em` or `%`, are only convertible to
+    /// themselves.
+    pub fn conversion_factor(&self, other: &Unit) -> Option<Number> {
+        if self == other {
+            Some(Number::from(1))
+        } else if self.is_absolute()
+            && other.is_absolute()
+            && self.dimension() == other.dimension()
+        {
+            Some(&other.scale_factor() / &self.scale_factor())
+        } else {
+            None
         }
     }
 
-    /// Some of these are exact and correct, others are more arbitrary.
-    /// When comparing 10cm to 4in, these factors will give correct results.
-    /// When comparing rems to vw, who can say?
-    pub fn scale_factor(&self) -> Rational {
+    fn is_absolute(&self) -> bool {
+        !matches!(
+            self,
+            Unit::Em
+                | Unit::Ex
+                | Unit::Ch
+                | Unit::Rem
+                | Unit::Vw
+                | Unit::Vh
+                | Unit::Vmin
+                | Unit::Vmax
+                | Unit::Percent
+                | Unit::Fr
+                | Unit::Unknown(_)
+                | Unit::None
+        )
+    }
+
+    /// The size of this unit in the base unit of its dimension.
+    ///
+    /// The base units are `mm`, `turn`, `s`, `Hz` and `dppx`.
+    /// For relative and unknown units, the factor is one, but it is
+    /// not meaningful to convert those to other units.
+    pub fn scale_factor(&self) -> Number {
+        use std::f64::consts::PI;
         match *self {
-            Unit::Em | Unit::Rem => Rational::new(10, 2),
-            Unit::Ex => Rational::new(10, 3),
-            Unit::Ch => Rational::new(10, 4),
-            Unit::Vw | Unit::Vh | Unit::Vmin | Unit::Vmax => Rational::one(),
-            Unit::Cm => Rational::new(10, 1),
-            Unit::Mm => Rational::one(),
-            Unit::Q => Rational::new(1, 4),
-            Unit::In => Rational::new(254, 10),
-            Unit::Pt => Rational::new(254, 720),
-            Unit::Pc => Rational::new(254, 60),
-            Unit::Px => Rational::new(254, 960),
-
-            Unit::Deg => Rational::new(360, 1),
-            Unit::Grad => Rational::new(400, 1),
-            Unit::Rad => Rational::new(62_832, 10_000), // approximate
-            Unit::Turn => Rational::one(),
-
-            Unit::S => Rational::one(),
-            Unit::Ms => Rational::new(1, 1000),
-
-            Unit::Hz => Rational::one(),
-            Unit::Khz => Rational::new(1000, 1),
-
-            Unit::Dpi => Rational::new(96, 1),
-            Unit::Dpcm => Rational::new(9600, 254),
-            Unit::Dppx => Rational::one(),
-
-            Unit::Percent => Rational::new(1, 100),
-            Unit::Fr => Rational::one(),
-            Unit::None => Rational::one(),
+            Unit::Cm => Rational::new(10, 1).into(),
+            Unit::Q => Rational::new(1, 4).into(),
+            Unit::In => Rational::new(254, 10).into(),
+            Unit::Pt => Rational::new(254, 720).into(),
+            Unit::Pc => Rational::new(254, 60).into(),
+            Unit::Px => Rational::new(254, 960).into(),
+
+            Unit::Deg => Rational::new(1, 360).into(),
+            Unit::Grad => Rational::new(1, 400).into(),
+            Unit::Rad => (1. / (2. * PI)).into(),
+
+            Unit::Ms => Rational::new(1, 1000).into(),
+
+            Unit::Khz => Rational::new(1000, 1).into(),
+
+            Unit::Dpi => Rational::new(1, 96).into(),
+            Unit::Dpcm => Rational::new(254, 9600).into(),
+
+            _ => Number::from(1),
         }
     }
 }
@@ -131,7 +165,7 @@ impl fmt::Display for Unit {
             Unit::Vmax => write!(out, "vmax"),
             Unit::Cm => write!(out, "cm"),
             Unit::Mm => write!(out, "mm"),
-            Unit::Q => write!(out, "q"),
+            Unit::Q => write!(out, "Q"),
             Unit::In => write!(out, "in"),
             Unit::Pt => write!(out, "pt"),
             Unit::Pc => write!(out, "pc"),
@@ -151,9 +185,11 @@ impl fmt::Display for Unit {
             Unit::Dpi => write!(out, "dpi"),
             Unit::Dpcm => write!(out, "dpcm"),
             Unit::Dppx => write!(out, "dppx"),
+            Unit::X => write!(out, "x"),
             // Special units
             Unit::Percent => write!(out, "%"),
             Unit::Fr => write!(out, "fr"),
+            Unit::Unknown(ref name) => out.write_str(name),
             Unit::None => Ok(()),
         }
     }
@@ -0,0 +1,279 @@
+//! The units of a number, which may be compound, like `px*px` or `px/s`.
+
+use super::{Number, Unit};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The units of a numeric value.
+///
+/// A simple unit, like `px`, is a single numerator unit.
+/// Multiplying and dividing numbers can give compound units, with
+/// several numerator and/or denominator units, like `px*px` or
+/// `1/s`.
+/// Only values with at most one numerator unit and no denominator
+/// units can be written as css.
+///
+/// The units are kept in the order they are given, but the order
+/// does not matter for equality, so `px*em` is equal to `em*px`.
+#[derive(Clone, Debug, Default)]
+pub struct UnitSet {
+    numerators: Vec<Unit>,
+    denominators: Vec<Unit>,
+}
+
+impl UnitSet {
+    /// The unit set of a unitless number.
+    pub fn scalar() -> Self {
+        UnitSet::default()
+    }
+    /// Create a unit set from numerator and denominator units.
+    ///
+    /// Units that are equal in the numerator and denominator are
+    /// cancelled.
+    pub fn new(numerators: Vec<Unit>, denominators: Vec<Unit>) -> Self {
+        let mut numerators = numerators;
+        let mut denominators = denominators;
+        numerators.retain(|u| u != &Unit::None);
+        denominators.retain(|u| u != &Unit::None);
+        let mut i = 0;
+        while i < numerators.len() {
+            if let Some(j) =
+                denominators.iter().position(|d| d == &numerators[i])
+            {
+                numerators.remove(i);
+                denominators.remove(j);
+            } else {
+                i += 1;
+            }
+        }
+        UnitSet {
+            numerators,
+            denominators,
+        }
+    }
+    /// Returns true if this is the unit set of a unitless number.
+    pub fn is_none(&self) -> bool {
+        self.numerators.is_empty() && self.denominators.is_empty()
+    }
+    /// Returns true if this is the single unit `%`.
+    pub fn is_percent(&self) -> bool {
+        self == &Unit::Percent
+    }
+    /// Returns true if a number with these units can be written as css.
+    pub fn is_valid_css(&self) -> bool {
+        self.numerators.len() <= 1 && self.denominators.is_empty()
+    }
+    pub fn numerators(&self) -> &[Unit] {
+        &self.numerators
+    }
+    pub fn denominators(&self) -> &[Unit] {
+        &self.denominators
+    }
+
+    /// Get the units of a product of numbers with these units and
+    /// `other` units.
+    ///
+    /// Numerator units of one factor and compatible denominator units
+    /// of the other are cancelled.
+    /// Cancelling units that are not equal (e.g. `s` and `ms`)
+    /// scales the product, so the returned factor should be
+    /// multiplied with the product of the values.
+    pub fn multiply(&self, other: &UnitSet) -> (UnitSet, Number) {
+        let mut factor = Number::from(1);
+        let mut numerators = Vec::new();
+        let mut other_den = other.denominators.clone();
+        for n in &self.numerators {
+            if !cancel(n, &mut other_den, &mut factor) {
+                numerators.push(n.clone());
+            }
+        }
+        let mut self_den = self.denominators.clone();
+        for n in &other.numerators {
+            if !cancel(n, &mut self_den, &mut factor) {
+                numerators.push(n.clone());
+            }
+        }
+        self_den.extend(other_den);
+        (UnitSet::new(numerators, self_den), factor)
+    }
+    /// Get the units of a quotient of numbers with these units and
+    /// `other` units.
+    ///
+    /// The returned factor should be multiplied with the quotient of
+    /// the values.
+    pub fn divide(&self, other: &UnitSet) -> (UnitSet, Number) {
+        self.multiply(&other.invert())
+    }
+    /// Get the inverse of this unit set, i.e. `1/px` for `px`.
+    pub fn invert(&self) -> UnitSet {
+        UnitSet {
+            numerators: self.denominators.clone(),
+            denominators: self.numerators.clone(),
+        }
+    }
+
+    /// Get the factor to convert a value in these units to `other`
+    /// units, if the units are compatible.
+    pub fn scale_to(&self, other: &UnitSet) -> Option<Number> {
+        if self == other {
+            return Some(Number::from(1));
+        }
+        if self.numerators.len() != other.numerators.len()
+            || self.denominators.len() != other.denominators.len()
+        {
+            return None;
+        }
+        let mut factor = Number::from(1);
+        let mut other_num = other.numerators.clone();
+        for n in &self.numerators {
+            let i = other_num
+                .iter()
+                .position(|o| o.conversion_factor(n).is_some())?;
+            factor = &factor * &other_num.remove(i).conversion_factor(n)?;
+        }
+        let mut other_den = other.denominators.clone();
+        for d in &self.denominators {
+            let i = other_den
+                .iter()
+                .position(|o| o.conversion_factor(d).is_some())?;
+            factor = &factor / &other_den.remove(i).conversion_factor(d)?;
+        }
+        Some(factor)
+    }
+}
+
+impl UnitSet {
+    /// The units in a canonical order, for comparisons.
+    fn sorted(&self) -> (Vec<&Unit>, Vec<&Unit>) {
+        let mut numerators = self.numerators.iter().collect::<Vec<_>>();
+        let mut denominators = self.denominators.iter().collect::<Vec<_>>();
+        numerators.sort();
+        denominators.sort();
+        (numerators, denominators)
+    }
+}
+
+impl PartialEq for UnitSet {
+    fn eq(&self, other: &UnitSet) -> bool {
+        if self.numerators == other.numerators
+            && self.denominators == other.denominators
+        {
+            return true;
+        }
+        self.numerators.len() == other.numerators.len()
+            && self.denominators.len() == other.denominators.len()
+            && self.sorted() == other.sorted()
+    }
+}
+impl Eq for UnitSet {}
+
+impl PartialOrd for UnitSet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for UnitSet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sorted().cmp(&other.sorted())
+    }
+}
+
+/// Cancel `unit` against the first compatible unit of `units`.
+///
+/// Returns true and updates `factor` if a unit was cancelled.
+fn cancel(unit: &Unit, units: &mut Vec<Unit>, factor: &mut Number) -> bool {
+    let found = units
+        .iter()
+        .enumerate()
+        .find_map(|(i, u)| unit.conversion_factor(u).map(|f| (i, f)));
+    if let Some((i, f)) = found {
+        units.remove(i);
+        *factor = &*factor / &f;
+        true
+    } else {
+        false
+    }
+}
+
+impl From<Unit> for UnitSet {
+    fn from(unit: Unit) -> UnitSet {
+        UnitSet::new(vec![unit], vec![])
+    }
+}
+
+impl PartialEq<Unit> for UnitSet {
+    fn eq(&self, other: &Unit) -> bool {
+        match other {
+            Unit::None => self.is_none(),
+            unit => {
+                self.numerators == [unit.clone()]
+                    && self.denominators.is_empty()
+            }
+        }
+    }
+}
+
+impl fmt::Display for UnitSet {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        fn join(units: &[Unit]) -> String {
+            units
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("*")
+        }
+        out.write_str(&join(&self.numerators))?;
+        if !self.denominators.is_empty() {
+            write!(out, "/{}", join(&self.denominators))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multiply_cancels() {
+        let speed = UnitSet::new(vec![Unit::Px], vec![Unit::Ms]);
+        let (units, factor) = speed.multiply(&Unit::S.into());
+        assert_eq!(units, Unit::Px);
+        assert_eq!(factor, Number::from(1000));
+    }
+
+    #[test]
+    fn divide_to_compound() {
+        let (units, factor) =
+            UnitSet::from(Unit::Px).divide(&Unit::Em.into());
+        assert_eq!(units.to_string(), "px/em");
+        assert_eq!(factor, Number::from(1));
+        let (units, _) = UnitSet::scalar().divide(&Unit::Px.into());
+        assert_eq!(units.to_string(), "/px");
+    }
+
+    #[test]
+    fn relative_units_do_not_cancel() {
+        let (units, _) = UnitSet::from(Unit::Em).divide(&Unit::Px.into());
+        assert!(!units.is_valid_css());
+        assert_eq!(units.to_string(), "em/px");
+    }
+
+    #[test]
+    fn keeps_order() {
+        let (units, _) = UnitSet::from(Unit::Px).multiply(&Unit::Em.into());
+        assert_eq!(units.to_string(), "px*em");
+        assert_eq!(units, UnitSet::new(vec![Unit::Em, Unit::Px], vec![]));
+    }
+
+    #[test]
+    fn scale_to() {
+        let px = UnitSet::from(Unit::Px);
+        assert_eq!(
+            UnitSet::from(Unit::In).scale_to(&px),
+            Some(Number::from(96))
+        );
+        assert_eq!(UnitSet::from(Unit::Em).scale_to(&px), None);
+        assert_eq!(UnitSet::scalar().scale_to(&px), None);
+    }
+}
@@ -3,11 +3,13 @@
 use crate::css::{self, Value};
 use crate::error::Error;
 use crate::functions::{get_builtin_function, SassFunction};
+use crate::logger::{self, Logger, StdErrLogger};
 use crate::output::Format;
 use crate::sass::{self, Item};
 use crate::selectors::Selectors;
 use std::collections::BTreeMap;
-use std::sync::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 /// Variables, functions and mixins are defined in a `Scope`.
 ///
@@ -25,6 +27,93 @@ pub trait Scope {
     /// parent of this scope.
     fn define_global(&self, name: &str, val: &Value);
 
+    /// Change the value of an existing variable, if it is defined in
+    /// this scope or an enclosing local scope.
+    ///
+    /// A global variable is only changed if `semi_global` is true,
+    /// i.e. if there are only flow control scopes between the scope
+    /// of the declaration and the global scope.
+    /// Returns true if the variable was changed.
+    fn assign_existing(
+        &self,
+        name: &str,
+        val: &Value,
+        semi_global: bool,
+    ) -> bool;
+
+    /// Set a variable, as by a `$name: val` declaration.
+    ///
+    /// If the variable is already defined in an enclosing scope, it
+    /// is changed there (see `assign_existing`), otherwise it is
+    /// defined in this scope.
+    fn set_variable(&mut self, name: &str, val: &Value) {
+        if !self.assign_existing(name, val, true) {
+            self.define(name, val)
+        }
+    }
+
+    /// Handle a variable declaration, with the `!default` and
+    /// `!global` flags.
+    ///
+    /// A namespaced `name` (`ns.name`) sets an existing variable in
+    /// a used module.
+    fn declare_variable(
+        &mut self,
+        name: &str,
+        val: &Value,
+        default: bool,
+        global: bool,
+    ) -> Result<(), Error> {
+        if name.contains('.') {
+            if global {
+                return Err(Error::S(
+                    "!global isn't allowed for variables in other modules."
+                        .into(),
+                ));
+            }
+            return self
+                .get_global_scope()
+                .set_module_variable(name, val, default);
+        }
+        let is_set = || match self.get_or_none(name) {
+            Some(Value::Null) | None => false,
+            Some(_) => true,
+        };
+        if global
+            && self.get_global_or_none(name).is_none()
+            && !(default && is_set())
+        {
+            let msg = if self.as_global_mut().is_some() {
+                "Since this assignment is at the root of the stylesheet, \
+                 the !global flag is\nunnecessary and can safely be \
+                 removed."
+                    .to_string()
+            } else {
+                format!(
+                    "Recommendation: add `${}: null` at the stylesheet \
+                     root.",
+                    name
+                )
+            };
+            self.get_logger().deprecation(
+                &format!(
+                    "As of Dart Sass 2.0.0, !global assignments won't be \
+                     able to declare new variables.\n\n{}",
+                    msg
+                ),
+                &logger::trace(),
+            );
+        }
+        if default {
+            self.define_default(name, val, global);
+        } else if global {
+            self.define_global(name, val);
+        } else {
+            self.set_variable(name, val);
+        }
+        Ok(())
+    }
+
     /// Define multiple names from a value that is a list.
     /// Special case: in names is a single name, value is used directly.
     fn define_multi(&mut self, names: &[String], value: &Value) {
@@ -49,12 +138,17 @@ pub trait Scope {
 
     fn get_format(&self) -> Format;
 
+    /// Get the logger for messages from this scope.
+    fn get_logger(&self) -> &dyn Logger;
+
     /// Get the Value for a variable.
     fn get_or_none(&self, name: &str) -> Option<Value>;
     fn get(&self, name: &str) -> Result<Value, Error> {
         match self.get_or_none(name) {
             Some(value) => Ok(value),
-            None => Err(Error::undefined_variable(name)),
+            None => Err(self
+                .get_global_scope()
+                .undefined_member(name, Member::Variable)),
         }
     }
 
@@ -62,7 +156,9 @@ pub trait Scope {
     fn get_global(&self, name: &str) -> Result<Value, Error> {
         match self.get_global_or_none(name) {
             Some(value) => Ok(value),
-            None => Err(Error::undefined_variable(name)),
+            None => Err(self
+                .get_global_scope()
+                .undefined_member(name, Member::Variable)),
         }
     }
 
@@ -73,6 +169,12 @@ pub trait Scope {
         body: &[Item],
     );
     fn get_mixin(&self, name: &str) -> Option<(sass::FormalArgs, Vec<Item>)>;
+    /// Get the scope where a mixin is defined.
+    ///
+    /// Mixins are lexically scoped, so the body of a mixin should be
+    /// evaluated in the scope where it is defined (or the module
+    /// that defines it) rather than in the calling scope.
+    fn get_mixin_scope(&self, name: &str) -> Option<&dyn Scope>;
 
     fn define_function(&mut self, name: &str, func: SassFunction);
     fn get_function(&self, name: &str) -> Option<&SassFunction>;
@@ -87,113 +189,222 @@ pub trait Scope {
         Self: Sized,
     {
         for b in body {
-            let result = match *b {
-                Item::IfStatement(ref cond, ref do_if, ref do_else) => {
-                    if cond.evaluate(self)?.is_true() {
-                        self.eval_body(do_if)?
-                    } else {
-                        self.eval_body(do_else)?
-                    }
-                }
-                Item::Each(ref names, ref values, ref body) => {
-                    for value in values.evaluate(self)?.iter_items() {
-                        self.define_multi(names, &value);
-                        if let Some(r) = self.eval_body(body)? {
-                            return Ok(Some(r));
-                        }
-                    }
-                    None
-                }
-                Item::For {
-                    ref name,
-                    ref from,
-                    ref to,
-                    inclusive,
-                    ref body,
-                } => {
-                    let from = from.evaluate(self)?.integer_value()?;
-                    let to = to.evaluate(self)?.integer_value()?;
-                    let to = if inclusive { to + 1 } else { to };
-                    for value in from..to {
-                        self.define(name, &Value::scalar(value));
-                        if let Some(r) = self.eval_body(body)? {
-                            return Ok(Some(r));
-                        }
-                    }
-                    None
-                }
-                Item::VariableDeclaration {
-                    ref name,
-                    ref val,
-                    default,
-                    global,
-                } => {
-                    let val = val.evaluate(self)?;
-                    if default {
-                        self.define_default(name, &val, global);
-                    } else if global {
-                        self.define_global(name, &val);
-                    } else {
-                        self.define(name, &val);
-                    }
-                    None
-                }
-                Item::Return(ref v) => Some(v.evaluate(self)?),
-                Item::While(ref cond, ref body) => {
-                    let mut scope = ScopeImpl::sub(self);
-                    while cond.evaluate(&scope)?.is_true() {
-                        if let Some(r) = scope.eval_body(body)? {
-                            return Ok(Some(r));
-                        }
-                    }
-                    None
-                }
-                Item::Warn(ref value) => {
-                    eprintln!(
-                        "WARNING: {}",
-                        value.evaluate(self)?.format(self.get_format())
-                    );
-                    None
-                }
-                Item::Error(ref value) => {
-                    return Err(Error::S(format!(
-                        "Error: {}",
-                        value.evaluate(self)?.format(self.get_format()),
-                    )));
-                }
-                Item::None => None,
-                Item::Comment(..) => None,
-                ref x => {
-                    return Err(Error::S(format!(
-                        "Not implemented in fuction: {:?}",
-                        x
-                    )))
-                }
-            };
-            if let Some(result) = result {
+            logger::at(b.pos());
+            if let Some(result) =
+                eval_item(self, b).map_err(|e| e.at(b.pos()))?
+            {
                 return Ok(Some(result));
             }
         }
         Ok(None)
     }
     fn get_selectors(&self) -> &Selectors;
+
+    /// Get the global scope of the module this scope is part of.
+    fn get_global_scope(&self) -> &GlobalScope;
+
+    /// Get the content block of the innermost mixin call this scope
+    /// is part of, if it is in a mixin.
+    fn get_content(&self) -> Option<Content<'_>> {
+        None
+    }
+
+    /// Get this scope as a global scope, if it is one.
+    ///
+    /// Module-level things, like `@use` rules, can only be handled
+    /// directly in a global scope.
+    fn as_global_mut(&mut self) -> Option<&mut GlobalScope> {
+        None
+    }
+}
+
+/// Evaluate an item in the body of a function.
+///
+/// Returns the return value of the function, if the item returns.
+fn eval_item<S: Scope>(
+    scope: &mut S,
+    b: &Item,
+) -> Result<Option<Value>, Error> {
+    Ok(match *b {
+        Item::IfStatement(ref cond, ref do_if, ref do_else, _) => {
+            let cond = cond.evaluate(scope)?.is_true();
+            let items = if cond { do_if } else { do_else };
+            if needs_flow_scope(items) {
+                ScopeImpl::sub_flow(scope).eval_body(items)?
+            } else {
+                scope.eval_body(items)?
+            }
+        }
+        Item::Each(ref names, ref values, ref body, _) => {
+            let mut scope = ScopeImpl::sub_flow(scope);
+            for value in values.evaluate(&scope)?.iter_items() {
+                scope.define_multi(names, &value);
+                if let Some(r) = scope.eval_body(body)? {
+                    return Ok(Some(r));
+                }
+            }
+            None
+        }
+        Item::For {
+            ref name,
+            ref from,
+            ref to,
+            inclusive,
+            ref body,
+            ..
+        } => {
+            let from = from.evaluate(scope)?.integer_value()?;
+            let to = to.evaluate(scope)?.integer_value()?;
+            let to = if inclusive {
+                to.checked_add(1).ok_or_else(|| {
+                    Error::S(format!("The @for bound {} is too large.", to))
+                })?
+            } else {
+                to
+            };
+            let mut scope = ScopeImpl::sub_flow(scope);
+            for value in from..to {
+                scope.define(name, &Value::scalar(value));
+                if let Some(r) = scope.eval_body(body)? {
+                    return Ok(Some(r));
+                }
+            }
+            None
+        }
+        Item::VariableDeclaration {
+            ref name,
+            ref val,
+            default,
+            global,
+            ..
+        } => {
+            let val = val.evaluate(scope)?;
+            scope.declare_variable(name, &val, default, global)?;
+            None
+        }
+        Item::Return(ref v, _) => Some(v.evaluate(scope)?),
+        Item::While(ref cond, ref body, _) if !needs_flow_scope(body) => {
+            while cond.evaluate(scope)?.is_true() {
+                if let Some(r) = scope.eval_body(body)? {
+                    return Ok(Some(r));
+                }
+            }
+            None
+        }
+        Item::While(ref cond, ref body, _) => {
+            let mut scope = ScopeImpl::sub_flow(scope);
+            while cond.evaluate(&scope)?.is_true() {
+                if let Some(r) = scope.eval_body(body)? {
+                    return Ok(Some(r));
+                }
+            }
+            None
+        }
+        Item::Warn(ref value, _) => {
+            let msg = value.evaluate(scope)?.unquote();
+            let msg = msg.format(scope.get_format()).to_string();
+            scope.get_logger().warn(&msg, &logger::trace());
+            None
+        }
+        Item::Debug(ref value, ref pos) => {
+            let msg = value.evaluate(scope)?.unquote();
+            let msg = msg.format(scope.get_format()).to_string();
+            scope.get_logger().debug(&msg, pos);
+            None
+        }
+        Item::Error(ref value, _) => {
+            return Err(Error::S(format!(
+                "Error: {}",
+                value.evaluate(scope)?.format(scope.get_format()),
+            )));
+        }
+        Item::None => None,
+        Item::Comment(..) => None,
+        _ => {
+            return Err(Error::S(
+                "Functions can only contain variable declarations and \
+                 control directives."
+                    .into(),
+            ))
+        }
+    })
+}
+
+/// Check if the body of a flow control rule needs a scope of its own.
+///
+/// A body that declares no variables, mixins or functions can
+/// be evaluated directly in the enclosing scope, which avoids creating
+/// a scope for each `@if` or `@while` body.
+pub fn needs_flow_scope(body: &[Item]) -> bool {
+    body.iter().any(|item| {
+        matches!(
+            item,
+            Item::VariableDeclaration { .. }
+                | Item::MixinDeclaration { .. }
+                | Item::FunctionDeclaration { .. }
+        )
+    })
+}
+
+/// The content block given to a mixin call (as in
+/// `@include foo using ($x) { ... }`), with the scope of the call.
+///
+/// The content block is evaluated by `@content` in the scope where
+/// the block is written, so it sees the variables of that scope
+/// rather than those of the mixin.
+#[derive(Clone, Copy)]
+pub struct Content<'a> {
+    /// The `using` arguments and the body of the block, if the mixin
+    /// was called with a content block.
+    pub block: Option<(&'a sass::FormalArgs, &'a [Item])>,
+    /// The scope where the mixin was called, which is where the
+    /// content block is written.
+    pub scope: &'a dyn Scope,
+}
+
+/// The kind of a local scope.
+///
+/// The kind decides how variable declarations in the scope affect
+/// variables of enclosing scopes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ScopeKind {
+    /// A plain local scope, like the body of a rule.
+    Local,
+    /// The body of a flow control rule (`@if`, `@each`, `@for` or
+    /// `@while`).
+    ///
+    /// Declarations in flow control blocks at the root of a module
+    /// can change existing global variables.
+    Flow,
+    /// The scope of a mixin or function call.
+    ///
+    /// Declarations in a call never change local variables of the
+    /// calling scope.
+    Call,
 }
 
 pub struct ScopeImpl<'a> {
     parent: &'a dyn Scope,
-    variables: BTreeMap<String, Value>,
+    kind: ScopeKind,
+    variables: Mutex<BTreeMap<String, Value>>,
     mixins: BTreeMap<String, (sass::FormalArgs, Vec<Item>)>,
     functions: BTreeMap<String, SassFunction>,
     selectors: Option<Selectors>,
+    content: Option<Content<'a>>,
 }
 
 impl<'a> Scope for ScopeImpl<'a> {
     fn get_format(&self) -> Format {
         self.parent.get_format()
     }
+    fn get_logger(&self) -> &dyn Logger {
+        self.parent.get_logger()
+    }
 
     fn define(&mut self, name: &str, val: &Value) {
         self.variables
+            .get_mut()
+            .unwrap()
             .insert(name.replace('-', "_"), val.unrequote());
     }
     fn define_default(&mut self, name: &str, val: &Value, global: bool) {
@@ -202,7 +413,7 @@ impl<'a> Scope for ScopeImpl<'a> {
                 if global {
                     self.define_global(name, val)
                 } else {
-                    self.define(name, val)
+                    self.set_variable(name, val)
                 }
             }
             _ => {}
@@ -211,18 +422,42 @@ impl<'a> Scope for ScopeImpl<'a> {
     fn define_global(&self, name: &str, val: &Value) {
         self.parent.define_global(name, val);
     }
+    fn assign_existing(
+        &self,
+        name: &str,
+        val: &Value,
+        semi_global: bool,
+    ) -> bool {
+        let name = name.replace('-', "_");
+        let mut variables = self.variables.lock().unwrap();
+        if let Some(var) = variables.get_mut(&name) {
+            *var = val.unrequote();
+            true
+        } else if self.kind == ScopeKind::Call {
+            false
+        } else {
+            drop(variables);
+            let semi_global = semi_global && self.kind == ScopeKind::Flow;
+            self.parent.assign_existing(&name, val, semi_global)
+        }
+    }
     fn get_mixin(&self, name: &str) -> Option<(sass::FormalArgs, Vec<Item>)> {
         self.mixins
             .get(&name.replace('-', "_"))
             .cloned()
             .or_else(|| self.parent.get_mixin(name))
     }
+    fn get_mixin_scope(&self, name: &str) -> Option<&dyn Scope> {
+        if self.mixins.contains_key(&name.replace('-', "_")) {
+            Some(self)
+        } else {
+            self.parent.get_mixin_scope(name)
+        }
+    }
     fn get_or_none(&self, name: &str) -> Option<Value> {
         let name = name.replace('-', "_");
-        self.variables
-            .get(&name)
-            .cloned()
-            .or_else(|| self.parent.get_or_none(&name))
+        let local = self.variables.lock().unwrap().get(&name).cloned();
+        local.or_else(|| self.parent.get_or_none(&name))
     }
     fn get_global_or_none(&self, name: &str) -> Option<Value> {
         self.parent.get_global_or_none(name)
@@ -262,16 +497,24 @@ impl<'a> Scope for ScopeImpl<'a> {
             .as_ref()
             .unwrap_or_else(|| self.parent.get_selectors())
     }
+    fn get_global_scope(&self) -> &GlobalScope {
+        self.parent.get_global_scope()
+    }
+    fn get_content(&self) -> Option<Content<'_>> {
+        self.content.or_else(|| self.parent.get_content())
+    }
 }
 
 impl<'a> ScopeImpl<'a> {
     pub fn sub(parent: &'a dyn Scope) -> Self {
         ScopeImpl {
             parent,
-            variables: BTreeMap::new(),
+            kind: ScopeKind::Local,
+            variables: Mutex::new(BTreeMap::new()),
             mixins: BTreeMap::new(),
             functions: BTreeMap::new(),
             selectors: None,
+            content: None,
         }
     }
     pub fn sub_selectors(
@@ -279,13 +522,28 @@ impl<'a> ScopeImpl<'a> {
         selectors: Selectors,
     ) -> Self {
         ScopeImpl {
-            parent,
-            variables: BTreeMap::new(),
-            mixins: BTreeMap::new(),
-            functions: BTreeMap::new(),
             selectors: Some(selectors),
+            ..ScopeImpl::sub(parent)
+        }
+    }
+    /// Create a scope for the body of a flow control rule.
+    pub fn sub_flow(parent: &'a dyn Scope) -> Self {
+        ScopeImpl {
+            kind: ScopeKind::Flow,
+            ..ScopeImpl::sub(parent)
         }
     }
+    /// Make this the scope of a mixin or function call.
+    ///
+    /// Variable declarations in the call will not change variables
+    /// of the calling scope.
+    pub fn set_call(&mut self) {
+        self.kind = ScopeKind::Call;
+    }
+    /// Make this the scope of a mixin call with the given content.
+    pub fn set_content(&mut self, content: Content<'a>) {
+        self.content = Some(content);
+    }
 }
 
 /// A `Scope` that can be created without allready having a scope as a
@@ -293,12 +551,29 @@ impl<'a> ScopeImpl<'a> {
 ///
 /// There can be multiple "global" scopes in the same process, they
 /// are global to the handling of a scss document.
+///
+/// Each module (loaded by `@use` or `@forward`) also has its own
+/// global scope, containing the members of that module.
 pub struct GlobalScope {
     format: Format,
+    logger: Arc<dyn Logger>,
     variables: Mutex<BTreeMap<String, Value>>,
     mixins: BTreeMap<String, (sass::FormalArgs, Vec<Item>)>,
     functions: BTreeMap<String, SassFunction>,
     selectors: Selectors,
+    /// Modules used with a namespace.
+    modules: BTreeMap<String, Arc<GlobalScope>>,
+    /// Modules used without a namespace (`as *`).
+    global_modules: Vec<Arc<GlobalScope>>,
+    /// Modules forwarded by this module.
+    forwarded: Vec<Forwarded>,
+    /// Configuration (from `with`) not yet used by a `!default`
+    /// variable declaration.
+    config: BTreeMap<String, Value>,
+    /// All modules loaded (or being loaded) in this compilation.
+    loaded: Arc<Mutex<BTreeMap<PathBuf, Option<Arc<GlobalScope>>>>>,
+    /// True for a builtin module, like `sass:math`.
+    builtin: bool,
 }
 
 impl GlobalScope {
@@ -306,10 +581,373 @@ impl GlobalScope {
     pub fn new(format: Format) -> Self {
         GlobalScope {
             format,
+            logger: Arc::new(StdErrLogger),
             variables: Mutex::new(BTreeMap::new()),
             mixins: BTreeMap::new(),
             functions: BTreeMap::new(),
             selectors: Selectors::root(),
+            modules: BTreeMap::new(),
+            global_modules: Vec::new(),
+            forwarded: Vec::new(),
+            config: BTreeMap::new(),
+            loaded: Arc::new(Mutex::new(BTreeMap::new())),
+            builtin: false,
+        }
+    }
+
+    /// Create an empty builtin module.
+    ///
+    /// The variables of a builtin module can't be modified.
+    pub(crate) fn new_builtin() -> Self {
+        GlobalScope {
+            builtin: true,
+            ..GlobalScope::new(Default::default())
+        }
+    }
+
+    /// Use `logger` for warnings and debug messages from this scope,
+    /// and from all modules loaded from it.
+    ///
+    /// By default, messages are written to stderr.
+    pub fn with_logger(self, logger: impl Logger + 'static) -> Self {
+        GlobalScope {
+            logger: Arc::new(logger),
+            ..self
+        }
+    }
+
+    /// Create a global scope for a module loaded from this scope.
+    ///
+    /// The `config` is values for `!default` variables in the module.
+    pub(crate) fn new_module(&self, config: BTreeMap<String, Value>) -> Self {
+        GlobalScope {
+            config,
+            loaded: self.loaded.clone(),
+            logger: self.logger.clone(),
+            ..GlobalScope::new(self.format)
+        }
+    }
+
+    /// Create a scope for evaluating a stylesheet loaded by
+    /// `meta.load-css`.
+    ///
+    /// Rules in the loaded stylesheet are nested in `selectors`.
+    pub(crate) fn new_load_css(
+        &self,
+        config: BTreeMap<String, Value>,
+        selectors: Selectors,
+    ) -> Self {
+        GlobalScope {
+            selectors,
+            ..self.new_module(config)
+        }
+    }
+
+    /// Get the module used with `namespace` in this scope, if any.
+    pub(crate) fn get_module(&self, namespace: &str) -> Option<&GlobalScope> {
+        self.modules.get(&namespace.replace('-', "_")).map(|m| &**m)
+    }
+
+    /// Get the variables exported by this module.
+    pub(crate) fn exported_variables(&self) -> Vec<(String, Value)> {
+        self.exported(Member::Variable, &|m| {
+            m.variables
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect()
+        })
+    }
+
+    /// Get the functions exported by this module.
+    pub(crate) fn exported_functions(&self) -> Vec<(String, SassFunction)> {
+        self.exported(Member::Function, &|m| {
+            m.functions
+                .iter()
+                .map(|(name, f)| (name.clone(), f.clone()))
+                .collect()
+        })
+    }
+
+    /// Check if the module at `path` is loaded.
+    ///
+    /// If the module is loaded, it is returned.
+    /// If it is currently being loaded, a module loop error is returned.
+    /// Otherwise, it is marked as being loaded and `None` is returned.
+    pub(crate) fn start_loading(
+        &self,
+        path: &Path,
+    ) -> Result<Option<Arc<GlobalScope>>, Error> {
+        let mut loaded = self.loaded.lock().unwrap();
+        match loaded.get(path) {
+            Some(Some(module)) => Ok(Some(module.clone())),
+            Some(None) => Err(Error::S(
+                "Module loop: this module is already being loaded.".into(),
+            )),
+            None => {
+                loaded.insert(path.into(), None);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Register `module` as loaded from `path`.
+    pub(crate) fn set_loaded(&self, path: &Path, module: Arc<GlobalScope>) {
+        self.loaded
+            .lock()
+            .unwrap()
+            .insert(path.into(), Some(module));
+    }
+
+    /// Check that all configuration given to this module is used.
+    pub(crate) fn check_config(&self) -> Result<(), Error> {
+        match self.config.keys().next() {
+            Some(name) => Err(Error::S(format!(
+                "${}: This variable was not declared with !default in \
+                 the @used module.",
+                name.replace('_', "-"),
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the configuration to pass on to a forwarded module.
+    pub(crate) fn forward_config(&self) -> BTreeMap<String, Value> {
+        self.config.clone()
+    }
+
+    /// Forget the configuration used by a forwarded `module`.
+    pub(crate) fn config_used(&mut self, module: &GlobalScope) {
+        let unused = &module.config;
+        self.config.retain(|name, _| unused.contains_key(name));
+    }
+
+    /// Make the members of `module` available in this scope.
+    ///
+    /// If `namespace` is `"*"`, the members are available without
+    /// namespace.
+    pub(crate) fn use_module(
+        &mut self,
+        namespace: &str,
+        module: Arc<GlobalScope>,
+    ) -> Result<(), Error> {
+        if namespace == "*" {
+            self.global_modules.push(module);
+        } else {
+            let namespace = namespace.replace('-', "_");
+            if self.modules.contains_key(&namespace) {
+                return Err(Error::S(format!(
+                    "There's already a module with namespace \"{}\".",
+                    namespace.replace('_', "-"),
+                )));
+            }
+            self.modules.insert(namespace, module);
+        }
+        Ok(())
+    }
+
+    /// Make the members of `module` available to users of this module.
+    pub(crate) fn forward_module(
+        &mut self,
+        module: Arc<GlobalScope>,
+        prefix: Option<&str>,
+        show: Option<&[String]>,
+        hide: &[String],
+    ) {
+        let norm = |names: &[String]| -> Vec<String> {
+            names.iter().map(|n| n.replace('-', "_")).collect()
+        };
+        self.forwarded.push(Forwarded {
+            module,
+            prefix: prefix.map(|p| p.replace('-', "_")).unwrap_or_default(),
+            show: show.map(norm),
+            hide: norm(hide),
+        });
+    }
+
+    /// Find a member that is visible in this scope.
+    ///
+    /// The `name` may be namespaced (`ns.name`), otherwise it is
+    /// searched for in this scope and in modules used without
+    /// namespace.
+    /// The `get` function should find the member in the given module.
+    fn find_member<'a, T>(
+        &'a self,
+        name: &str,
+        kind: Member,
+        get: &dyn Fn(&'a GlobalScope, &str) -> Option<T>,
+    ) -> Result<Option<T>, Error> {
+        if let Some(dot) = name.find('.') {
+            let (ns, name) = (&name[..dot], &name[dot + 1..]);
+            match self.modules.get(ns) {
+                Some(_) if name.starts_with('_') => Err(Error::S(
+                    "Private members can't be accessed from outside \
+                     their modules."
+                        .into(),
+                )),
+                Some(module) => Ok(module.find_exported(name, kind, get)),
+                None => Err(Error::S(format!(
+                    "There is no module with the namespace \"{}\".",
+                    ns.replace('_', "-"),
+                ))),
+            }
+        } else {
+            Ok(get(self, name).or_else(|| {
+                self.global_modules
+                    .iter()
+                    .find_map(|m| m.find_exported(name, kind, get))
+            }))
+        }
+    }
+
+    /// Get the error for a member that is not found by `find_member`.
+    ///
+    /// For a namespaced name, this is the error for an unknown
+    /// namespace or a private member, if any.
+    fn undefined_member(&self, name: &str, kind: Member) -> Error {
+        let norm = name.replace('-', "_");
+        if let Err(e) = self.find_member(&norm, kind, &|_, _| None::<()>) {
+            return e;
+        }
+        match kind {
+            Member::Variable if name.contains('.') => {
+                Error::S("Undefined variable.".into())
+            }
+            Member::Variable => Error::undefined_variable(name),
+            Member::Function => Error::S("Undefined function.".into()),
+            Member::Mixin => Error::S("Undefined mixin.".into()),
+        }
+    }
+
+    /// Get the error for calling a function that does not exist.
+    ///
+    /// Only a namespaced function is an error, other unknown functions
+    /// are plain css functions.
+    pub(crate) fn undefined_function(&self, name: &str) -> Error {
+        self.undefined_member(name, Member::Function)
+    }
+
+    /// Get the error for including a mixin that does not exist.
+    pub(crate) fn undefined_mixin(&self, name: &str) -> Error {
+        self.undefined_member(name, Member::Mixin)
+    }
+
+    /// Set an existing variable in a used module, as by a
+    /// `ns.$name: val` declaration.
+    fn set_module_variable(
+        &self,
+        name: &str,
+        val: &Value,
+        default: bool,
+    ) -> Result<(), Error> {
+        let name = name.replace('-', "_");
+        let found =
+            self.find_member(&name, Member::Variable, &|m, name| {
+                if m.variables.lock().unwrap().contains_key(name) {
+                    Some((m, name.to_string()))
+                } else {
+                    None
+                }
+            })?;
+        let (module, name) = found
+            .ok_or_else(|| self.undefined_member(&name, Member::Variable))?;
+        if module.builtin {
+            return Err(Error::S("Cannot modify built-in variable.".into()));
+        }
+        let mut variables = module.variables.lock().unwrap();
+        let var = variables.get_mut(&name).unwrap();
+        if !default || *var == Value::Null {
+            *var = val.unrequote();
+        }
+        Ok(())
+    }
+
+    /// Find a member exported by this module.
+    fn find_exported<'a, T>(
+        &'a self,
+        name: &str,
+        kind: Member,
+        get: &dyn Fn(&'a GlobalScope, &str) -> Option<T>,
+    ) -> Option<T> {
+        if name.starts_with('_') {
+            // Members starting with - or _ are private to the module.
+            return None;
+        }
+        get(self, name).or_else(|| {
+            self.forwarded.iter().find_map(|fw| {
+                let name = fw.inner_name(name, kind)?;
+                fw.module.find_exported(name, kind, get)
+            })
+        })
+    }
+
+    /// Get all members of a kind exported by this module, including
+    /// forwarded members.
+    ///
+    /// The `own` function should get the members of the given module.
+    fn exported<T>(
+        &self,
+        kind: Member,
+        own: &dyn Fn(&GlobalScope) -> Vec<(String, T)>,
+    ) -> Vec<(String, T)> {
+        let mut result = own(self)
+            .into_iter()
+            .filter(|(name, _)| !name.starts_with('_'))
+            .collect::<Vec<_>>();
+        for fw in &self.forwarded {
+            for (name, member) in fw.module.exported(kind, own) {
+                let name = format!("{}{}", fw.prefix, name);
+                if fw.inner_name(&name, kind).is_some()
+                    && result.iter().all(|(n, _)| *n != name)
+                {
+                    result.push((name, member));
+                }
+            }
+        }
+        result
+    }
+}
+
+/// The kinds of members of a module.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Member {
+    Variable,
+    Function,
+    Mixin,
+}
+
+/// A module forwarded by `@forward`.
+struct Forwarded {
+    module: Arc<GlobalScope>,
+    prefix: String,
+    show: Option<Vec<String>>,
+    hide: Vec<String>,
+}
+
+impl Forwarded {
+    /// Get the name in the forwarded module of an exported member.
+    ///
+    /// The `show` and `hide` lists use the exported (prefixed) names.
+    /// Returns `None` if the member is not forwarded.
+    fn inner_name<'a>(&self, name: &'a str, kind: Member) -> Option<&'a str> {
+        let inner = if name.starts_with(&self.prefix) {
+            &name[self.prefix.len()..]
+        } else {
+            return None;
+        };
+        let listed = |names: &[String]| {
+            names.iter().any(|n| match kind {
+                Member::Variable => n.starts_with('$') && n[1..] == *name,
+                _ => n == name,
+            })
+        };
+        if self.show.as_ref().map(|s| listed(s)).unwrap_or(true)
+            && !listed(&self.hide)
+        {
+            Some(inner)
+        } else {
+            None
         }
     }
 }
@@ -318,16 +956,38 @@ impl Scope for GlobalScope {
     fn get_format(&self) -> Format {
         self.format
     }
+    fn get_logger(&self) -> &dyn Logger {
+        &*self.logger
+    }
 
     fn define(&mut self, name: &str, val: &Value) {
         self.define_global(name, val)
     }
     fn define_default(&mut self, name: &str, val: &Value, _global: bool) {
+        if let Some(val) = self.config.remove(&name.replace('-', "_")) {
+            self.define(name, &val);
+            return;
+        }
         match self.get_or_none(name) {
             Some(Value::Null) | None => self.define(name, val),
             _ => {}
         }
     }
+    fn assign_existing(
+        &self,
+        name: &str,
+        val: &Value,
+        semi_global: bool,
+    ) -> bool {
+        let mut variables = self.variables.lock().unwrap();
+        match variables.get_mut(&name.replace('-', "_")) {
+            Some(var) if semi_global => {
+                *var = val.unrequote();
+                true
+            }
+            _ => false,
+        }
+    }
     fn define_global(&self, name: &str, val: &Value) {
         self.variables
             .lock()
@@ -335,14 +995,28 @@ impl Scope for GlobalScope {
             .insert(name.replace('-', "_"), val.unrequote());
     }
     fn get_mixin(&self, name: &str) -> Option<(sass::FormalArgs, Vec<Item>)> {
-        self.mixins.get(&name.replace('-', "_")).cloned()
+        let name = name.replace('-', "_");
+        self.find_member(&name, Member::Mixin, &|m, name| {
+            m.mixins.get(name).cloned()
+        })
+        .unwrap_or(None)
+    }
+    fn get_mixin_scope(&self, name: &str) -> Option<&dyn Scope> {
+        let name = name.replace('-', "_");
+        self.find_member(&name, Member::Mixin, &|m, name| {
+            m.mixins.get(name).map(|_| m as &dyn Scope)
+        })
+        .unwrap_or(None)
     }
     fn get_or_none(&self, name: &str) -> Option<Value> {
         self.get_global_or_none(name)
     }
     fn get_global_or_none(&self, name: &str) -> Option<Value> {
         let name = name.replace('-', "_");
-        self.variables.lock().unwrap().get(&name).cloned()
+        self.find_member(&name, Member::Variable, &|m, name| {
+            m.variables.lock().unwrap().get(name).cloned()
+        })
+        .unwrap_or(None)
     }
     fn define_mixin(
         &mut self,
@@ -358,10 +1032,11 @@ impl Scope for GlobalScope {
     }
     fn get_function(&self, name: &str) -> Option<&SassFunction> {
         let name = name.replace('-', "_");
-        if let Some(f) = self.functions.get(&name) {
-            return Some(f);
-        }
-        get_builtin_function(&name)
+        self.find_member(&name, Member::Function, &|m, name| {
+            m.functions.get(name)
+        })
+        .unwrap_or(None)
+        .or_else(|| get_builtin_function(&name))
     }
     fn call_function(
         &self,
@@ -369,14 +1044,29 @@ impl Scope for GlobalScope {
         args: &css::CallArgs,
     ) -> Option<Result<Value, Error>> {
         let name = name.replace('-', "_");
-        if let Some(f) = self.functions.get(&name).cloned() {
-            return Some(f.call(self, args));
+        // Builtin functions are called with the calling scope, by
+        // the caller.
+        let found = self.find_member(&name, Member::Function, &|m, name| {
+            m.functions
+                .get(name)
+                .filter(|f| !f.is_builtin())
+                .map(|f| (f, m))
+        });
+        match found {
+            Ok(Some((f, module))) => Some(f.clone().call(module, args)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
         }
-        None
     }
     fn get_selectors(&self) -> &Selectors {
         &self.selectors
     }
+    fn get_global_scope(&self) -> &GlobalScope {
+        self
+    }
+    fn as_global_mut(&mut self) -> Option<&mut GlobalScope> {
+        Some(self)
+    }
 }
 
 #[cfg(test)]
@@ -460,7 +1150,159 @@ pub mod test {
     // ...
     #[test]
     fn div_by_zero() {
-        assert_eq!("500px/0", do_evaluate(&[], b"(500px/0);"))
+        assert_eq!("Infinitypx", do_evaluate(&[], b"(500px/0);"))
+    }
+
+    #[test]
+    fn compound_units() {
+        assert_eq!("100px*px", do_evaluate(&[], b"10px * 10px;"));
+        assert_eq!("2px/em", do_evaluate(&[("w", "4px")], b"$w / 2em;"));
+        assert_eq!("8px", do_evaluate(&[], b"(4px / 2em) * 4em;"));
+    }
+
+    #[test]
+    fn units_convert_when_cancelled() {
+        assert_eq!("1000px", do_evaluate(&[], b"(1px / 1ms) * 1s;"));
+    }
+
+    #[test]
+    fn add_converts_units() {
+        assert_eq!("97px", do_evaluate(&[], b"1px + 1in;"));
+    }
+
+    #[test]
+    fn unit_aware_equality() {
+        assert_eq!("true", do_evaluate(&[], b"1in == 96px;"));
+        assert_eq!("false", do_evaluate(&[], b"1px == 1em;"));
+        assert_eq!("false", do_evaluate(&[], b"1 == 1px;"));
+        assert_eq!("true", do_evaluate(&[], b"180deg == 0.5turn;"));
+    }
+
+    #[test]
+    fn unit_aware_comparison() {
+        assert_eq!("true", do_evaluate(&[], b"1in > 95px;"));
+        assert_eq!("true", do_evaluate(&[], b"999ms < 1s;"));
+        assert_eq!("true", do_evaluate(&[], b"2 > 1px;"));
+        assert_eq!(
+            do_evaluate_or_error(&[], b"1px < 1em;")
+                .unwrap_err()
+                .to_string(),
+            "Incompatible units px and em."
+        );
+    }
+
+    #[test]
+    fn unknown_units() {
+        assert_eq!("30foo", do_evaluate(&[], b"10foo * 3;"));
+        assert_eq!("2", do_evaluate(&[], b"(10foo / 5foo);"));
+    }
+
+    #[test]
+    fn scientific_notation() {
+        assert_eq!("1500px", do_evaluate(&[], b"1.5e3px;"));
+        assert_eq!("0.02", do_evaluate(&[], b"2E-2;"));
+    }
+
+    #[test]
+    fn calc_simplified() {
+        assert_eq!("15px", do_evaluate(&[], b"calc(10px + 5px);"));
+        assert_eq!("1.5em", do_evaluate(&[], b"calc(3em / 2);"));
+        assert_eq!("97px", do_evaluate(&[], b"calc(1px + 1in);"));
+    }
+
+    #[test]
+    fn calc_kept() {
+        assert_eq!(
+            "calc(100% - 2em)",
+            do_evaluate(&[("gutter", "2em")], b"calc(100% - $gutter);")
+        );
+        assert_eq!(
+            "calc((100% - 2em) / 3)",
+            do_evaluate(&[], b"calc((100% - 2em) / 3);")
+        );
+        assert_eq!(
+            "calc(var(--x) * 2)",
+            do_evaluate(&[], b"calc(var(--x) * 2);")
+        );
+    }
+
+    #[test]
+    fn calc_min_max_clamp() {
+        assert_eq!("1px", do_evaluate(&[], b"min(1px, 2px);"));
+        assert_eq!("1in", do_evaluate(&[], b"max(90px, 1in);"));
+        assert_eq!("3px", do_evaluate(&[], b"clamp(1px, 5px, 3px);"));
+        assert_eq!(
+            "min(10%, 2em + 1px)",
+            do_evaluate(&[], b"min(10%, 2em + 1px);")
+        );
+        assert_eq!(
+            "calculation",
+            do_evaluate(&[], b"type-of(clamp(1px, 50%, 3px));")
+        );
+    }
+
+    #[test]
+    fn calc_bad_value() {
+        assert_eq!(
+            do_evaluate_or_error(&[("s", "\"a\"")], b"calc(1px + $s);")
+                .unwrap_err()
+                .to_string(),
+            "Value \"a\" can't be used in a calculation."
+        );
+    }
+
+    #[test]
+    fn calc_complex_intermediate_units() {
+        assert_eq!(
+            "8.5px",
+            do_evaluate(&[], b"calc(10px - 2px * 3px / 4px);")
+        );
+        // Not valid css, so an error when written as output.
+        assert_eq!("6px*px", do_evaluate(&[], b"calc(2px * 3px);"));
+    }
+
+    #[test]
+    fn calc_min_unitless_incompatible() {
+        // As the sass function, a top-level min accepts unitless numbers.
+        assert_eq!("2px", do_evaluate(&[("a", "2px")], b"max($a, 1);"));
+        assert_eq!(
+            do_evaluate_or_error(&[], b"calc(1px + min(1, 2px));")
+                .unwrap_err()
+                .to_string(),
+            "1 and 2px are incompatible."
+        );
+        assert_eq!(
+            do_evaluate_or_error(&[], b"clamp(1, 2px, 3px);")
+                .unwrap_err()
+                .to_string(),
+            "1 and 2px are incompatible."
+        );
+    }
+
+    #[test]
+    fn calc_incompatible_units() {
+        assert_eq!(
+            do_evaluate_or_error(&[], b"calc(1px + 1s);")
+                .unwrap_err()
+                .to_string(),
+            "1px and 1s are incompatible."
+        );
+        assert_eq!(
+            do_evaluate_or_error(&[], b"calc(1 - 1px);")
+                .unwrap_err()
+                .to_string(),
+            "1 and 1px are incompatible."
+        );
+    }
+
+    #[test]
+    fn add_incompatible_units() {
+        assert_eq!(
+            do_evaluate_or_error(&[], b"1px + 1em;")
+                .unwrap_err()
+                .to_string(),
+            "Incompatible units px and em."
+        );
     }
 
     #[test]
@@ -712,6 +1554,32 @@ pub mod test {
         assert_eq!("false", do_evaluate(&[], b"18 != 10 + 8;"))
     }
 
+    #[test]
+    fn nan_not_equal() {
+        assert_eq!("false", do_evaluate(&[], b"(0/0) == (0/0);"));
+        assert_eq!("true", do_evaluate(&[], b"(0/0) != (0/0);"));
+        assert_eq!("false", do_evaluate(&[], b"(0/0) < 1 or (0/0) >= 1;"));
+    }
+    #[test]
+    fn integer_out_of_range() {
+        assert_eq!(
+            do_evaluate_or_error(&[], b"nth(1 2 3, 1e20);")
+                .unwrap_err()
+                .to_string(),
+            "100000000000000000000 is not an integer in a usable range."
+        );
+    }
+
+    #[test]
+    fn bad_arguments_message() {
+        assert_eq!(
+            do_evaluate_or_error(&[], b"str-index(1, \"a\");")
+                .unwrap_err()
+                .to_string(),
+            "expected string and string, got number = 1 and string = \"a\""
+        );
+    }
+
     #[test]
     fn simple_boolean() {
         assert_eq!("true", do_evaluate(&[], b"3 >= 2 and 1 < 10;"))
@@ -0,0 +1,117 @@
+//! Tests for passing arguments to mixins and functions.
+mod common;
+use common::{check, check_err};
+
+#[test]
+fn arglist_keeps_keywords() {
+    check(
+        "@mixin m($args...) {\
+         \n  a {pos: $args; kw: inspect(keywords($args))}\
+         \n}\
+         \n@include m(1, 2, $c: 3, $d-e: 4);\n",
+        "a {\n  pos: 1, 2;\n  kw: (c: 3, d-e: 4);\n}\n",
+    )
+}
+
+#[test]
+fn spread_map() {
+    check(
+        "@function f($a, $b: 2, $c: 3) {@return $a $b $c}\
+         \n$map: (a: 1, c: 4);\
+         \na {b: f($map...)}\n",
+        "a {\n  b: 1 2 4;\n}\n",
+    )
+}
+
+#[test]
+fn spread_list_and_map() {
+    check(
+        "@function f($a, $b, $c: 3, $d: 4) {@return $a $b $c $d}\
+         \n$list: 1 2;\
+         \n$map: (d: 5);\
+         \na {b: f($list..., $map...)}\n",
+        "a {\n  b: 1 2 3 5;\n}\n",
+    )
+}
+
+#[test]
+fn pass_through_arglist() {
+    check(
+        "@function f($a, $b) {@return $a $b}\
+         \n@function g($args...) {@return f($args...)}\
+         \na {b: g(1, $b: 2)}\n",
+        "a {\n  b: 1 2;\n}\n",
+    )
+}
+
+#[test]
+fn unknown_keyword() {
+    check_err(
+        "@function f($a) {@return $a}\na {b: f(1, $c: 2)}\n",
+        "No argument named $c.",
+    )
+}
+
+#[test]
+fn unknown_keywords() {
+    check_err(
+        "@mixin m($a) {}\na {@include m($a: 1, $b-c: 2, $d: 3, $e: 4)}\n",
+        "No arguments named $b-c, $d or $e.",
+    )
+}
+
+#[test]
+fn unknown_keyword_in_map() {
+    check_err(
+        "@function f($a) {@return $a}\na {b: f((a: 1, b: 2)...)}\n",
+        "No argument named $b.",
+    )
+}
+
+#[test]
+fn missing_argument() {
+    check_err(
+        "@function f($a, $b-c) {@return $a}\na {b: f(1)}\n",
+        "Missing argument $b-c.",
+    )
+}
+
+#[test]
+fn both_position_and_name() {
+    check_err(
+        "@function f($a) {@return $a}\na {b: f(1, $a: 2)}\n",
+        "Argument $a was passed both by position and by name.",
+    )
+}
+
+#[test]
+fn keyword_rest_not_map() {
+    check_err(
+        "@function f($a) {@return $a}\na {b: f(1 2..., 3...)}\n",
+        "Variable keyword arguments must be a map (was 3).",
+    )
+}
+
+#[test]
+fn too_many_positional() {
+    check_err(
+        "@function f($a) {@return $a}\na {b: f(1, 2)}\n",
+        "Only 1 argument allowed, but 2 were passed.",
+    )
+}
+
+#[test]
+fn too_many_positional_mixin() {
+    check_err(
+        "@mixin m {b: c}\na {@include m(1)}\n",
+        "Only 0 arguments allowed, but 1 was passed.",
+    )
+}
+
+#[test]
+fn too_many_spread() {
+    check_err(
+        "@function f($x, $y) {@return null}\na {b: f(1 2 3...)}\n",
+        "Only 2 arguments allowed, but 3 were passed.",
+    )
+}
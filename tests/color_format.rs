@@ -0,0 +1,95 @@
+//! Tests for the color output options of `Format`.
+use rsass::compile_scss;
+use rsass::output::{ColorFormat, Format, Style};
+
+#[test]
+fn default_keeps_original() {
+    check(
+        b"a {b: #FFFFFF; c: rgba(255, 0, 0, 0.5); d: mix(red, blue)}",
+        Default::default(),
+        "a {\n  b: #FFFFFF;\n  c: rgba(255, 0, 0, 0.5);\n  d: purple;\n}\n",
+    )
+}
+
+#[test]
+fn normalize() {
+    check(
+        b"a {b: #FFFFFF; c: #fa0}",
+        ColorFormat {
+            keep_original: false,
+            ..Default::default()
+        },
+        "a {\n  b: white;\n  c: #ffaa00;\n}\n",
+    )
+}
+
+#[test]
+fn shortest() {
+    check(
+        b"a {b: #FFFFFF; c: #fa0; d: rgba(0, 0, 0, 0); e: #ff0000}",
+        ColorFormat {
+            keep_original: false,
+            shortest: true,
+            ..Default::default()
+        },
+        "a {\n  b: #fff;\n  c: #fa0;\n  d: transparent;\n  e: red;\n}\n",
+    )
+}
+
+#[test]
+fn shortest_without_normalize() {
+    check(
+        b"a {b: #FFFFFF; c: #ff0000; d: white; e: #f00}",
+        ColorFormat {
+            shortest: true,
+            ..Default::default()
+        },
+        "a {\n  b: #fff;\n  c: red;\n  d: #fff;\n  e: red;\n}\n",
+    )
+}
+
+#[test]
+fn compressed_keeps_original() {
+    let format = Format::default().with_style(Style::Compressed);
+    assert_eq!(
+        compile_scss(b"a {b: #FFFFFF; c: mix(#fff, #fff)}", format).unwrap(),
+        b"a{b:#FFFFFF;c:#fff}\n"
+    );
+}
+
+#[test]
+fn modern_syntax() {
+    check(
+        b"a {b: rgba(red, 0.5)}",
+        ColorFormat {
+            modern_syntax: true,
+            ..Default::default()
+        },
+        "a {\n  b: rgb(255 0 0 / 0.5);\n}\n",
+    )
+}
+
+#[test]
+fn alpha_hex() {
+    check(
+        b"a {b: rgba(red, 0.5); c: rgba(#fff, 0.6)}",
+        ColorFormat {
+            alpha_hex: true,
+            shortest: true,
+            ..Default::default()
+        },
+        "a {\n  b: #ff000080;\n  c: #fff9;\n}\n",
+    )
+}
+
+fn check(input: &[u8], colors: ColorFormat, expected: &str) {
+    let format = Format::default()
+        .with_style(Style::Expanded)
+        .with_colors(colors);
+    assert_eq!(
+        compile_scss(input, format)
+            .and_then(|s| Ok(String::from_utf8(s)?))
+            .unwrap(),
+        expected
+    );
+}
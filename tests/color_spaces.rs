@@ -0,0 +1,130 @@
+//! Tests for colors in the color spaces of css color level 4.
+mod common;
+use common::{check, check_err};
+use rsass::compile_scss;
+
+#[test]
+fn round_trip() {
+    check(
+        "a {\n  b: lab(50% 40 30);\n  c: lch(50% 30 120deg / 50%);\n  \
+         d: oklab(60% 0.1 -0.05);\n  e: color(display-p3 1 0 0);\n}\n",
+        "a {\n  b: lab(50% 40 30);\n  c: lch(50% 30 120deg / 0.5);\n  \
+         d: oklab(60% 0.1 -0.05);\n  e: color(display-p3 1 0 0);\n}\n",
+    )
+}
+
+#[test]
+fn hwb() {
+    check(
+        "@use \"sass:color\";\n\
+         a {\n  b: hwb(120 30% 40%);\n  c: color.hwb(120, 30%, 40%, 0.5);\n  \
+         d: color.whiteness(#4d994d);\n  e: color.blackness(#4d994d);\n}\n",
+        "a {\n  b: #4d994d;\n  c: rgba(77, 153, 77, 0.5);\n  \
+         d: 30.196078%;\n  e: 40%;\n}\n",
+    )
+}
+
+#[test]
+fn to_space() {
+    check(
+        "@use \"sass:color\";\n\
+         a {\n  b: color.to-space(red, lab);\n  \
+         c: color.to-space(color.to-space(red, oklch), rgb);\n  \
+         d: color.space(lab(50% 40 30));\n}\n",
+        "a {\n  b: lab(54.290543% 80.80492 69.890988);\n  c: red;\n  \
+         d: lab;\n}\n",
+    )
+}
+
+#[test]
+fn channel() {
+    check(
+        "@use \"sass:color\";\n\
+         a {\n  b: color.channel(lab(50% 40 30), \"lightness\");\n  \
+         c: color.channel(red, \"hue\", $space: oklch);\n  \
+         d: color.channel(red, \"red\");\n  \
+         e: color.channel(oklch(60% 0.15 50deg / 0.5), \"alpha\");\n}\n",
+        "a {\n  b: 50%;\n  c: 29.23388deg;\n  d: 255;\n  e: 0.5;\n}\n",
+    )
+}
+
+#[test]
+fn gamut() {
+    check(
+        "@use \"sass:color\";\n\
+         $p3: color(display-p3 1 0 0);\n\
+         $srgb: color.to-space($p3, srgb);\n\
+         a {\n  b: color.is-in-gamut($p3);\n  \
+         c: color.is-in-gamut($p3, srgb);\n  \
+         d: color.to-gamut($srgb, $method: clip);\n  \
+         e: color.to-gamut($srgb, $method: local-minde);\n}\n",
+        "a {\n  b: true;\n  c: false;\n  d: color(srgb 1 0 0);\n  \
+         e: color(srgb 1 0.04457 0.045932);\n}\n",
+    )
+}
+
+#[test]
+fn mix_with_method() {
+    check(
+        "a {\n  b: mix(red, blue);\n  \
+         c: mix(lab(50% 40 30), lab(70% -20 10), $method: lab);\n  \
+         d: mix(lch(50% 30 350deg), lch(50% 30 30deg), $method: lch);\n  \
+         e: mix(lch(50% 30 350deg), lch(50% 30 30deg), \
+         $method: lch longer hue);\n}\n",
+        "a {\n  b: purple;\n  c: lab(60% 10 20);\n  \
+         d: lch(50% 30 10deg);\n  e: lch(50% 30 190deg);\n}\n",
+    )
+}
+
+#[test]
+fn mix_needs_method() {
+    assert_eq!(
+        compile_scss(b"a {b: mix(lab(50% 40 30), red)}", Default::default())
+            .unwrap_err()
+            .inner()
+            .to_string(),
+        "$method: To use mix() with non-legacy colors, \
+         you must provide a $method."
+    )
+}
+
+#[test]
+fn equality() {
+    check(
+        "@use \"sass:color\";\n\
+         a {\n  b: red == #f00;\n  \
+         c: red == color.to-space(color.to-space(red, oklch), rgb);\n  \
+         d: lab(50% 40 -20) == \
+         color.to-space(color.to-space(lab(50% 40 -20), oklch), lab);\n  \
+         e: lab(50% 40 -20) == lab(50% 40 -21);\n  \
+         f: red == color.to-space(red, oklch);\n}\n",
+        "a {\n  b: true;\n  c: true;\n  d: true;\n  e: false;\n  \
+         f: false;\n}\n",
+    )
+}
+
+#[test]
+fn arithmetic_is_legacy_only() {
+    check_err(
+        "a {b: lab(50% 40 -20) + 1}",
+        "Undefined operation \"lab(50% 40 -20) + 1\".",
+    )
+}
+
+#[test]
+fn lighten_is_legacy_only() {
+    check_err(
+        "a {b: lighten(oklch(50% 0.1 120deg), 10%)}",
+        "$color: lighten() is only supported for legacy colors. \
+         Please use color.adjust() instead with an explicit $space argument.",
+    )
+}
+
+#[test]
+fn red_is_legacy_only() {
+    check_err(
+        "a {b: red(lab(50% 40 -20))}",
+        "$color: red() is only supported for legacy colors. \
+         Please use color.channel() instead with an explicit $space argument.",
+    )
+}
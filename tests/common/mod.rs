@@ -0,0 +1,24 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+use rsass::compile_scss;
+
+/// Compile `input` with the default format and check the css output.
+pub fn check(input: &str, expected: &str) {
+    assert_eq!(
+        compile_scss(input.as_bytes(), Default::default())
+            .and_then(|s| Ok(String::from_utf8(s)?))
+            .unwrap(),
+        expected
+    );
+}
+
+/// Compile `input` and check the message of the resulting error.
+pub fn check_err(input: &str, expected: &str) {
+    assert_eq!(
+        compile_scss(input.as_bytes(), Default::default())
+            .unwrap_err()
+            .inner()
+            .to_string(),
+        expected
+    );
+}
@@ -298,11 +298,19 @@ fn t50_wrapped_pseudo_selectors() {
     )
 }
 
+#[test]
+fn calculation() {
+    check(
+        b"a {\n  width: calc((100% - 2em) / 3 + 1px);\n  \
+          height: max(10vh, 1em * 3);\n}\n",
+        "a{width:calc((100% - 2em)/3 + 1px);height:max(10vh,3em)}\n",
+    )
+}
+
 fn check(input: &[u8], expected: &str) {
-    let format = Format {
-        style: Style::Compressed,
-        precision: 5,
-    };
+    let format = Format::default()
+        .with_style(Style::Compressed)
+        .with_precision(5);
     assert_eq!(
         compile_scss(input, format)
             .and_then(|s| Ok(String::from_utf8(s)?))
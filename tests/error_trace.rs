@@ -0,0 +1,113 @@
+//! Tests for the source location and trace of errors.
+//! The sources used are in the `tests/error_trace` directory.
+use rsass::{compile_scss_file, Error};
+
+#[test]
+fn undefined_variable() {
+    check(
+        "undefined",
+        "Error: Undefined variable: \"$x\"\n  \
+         \u{2577}\n\
+         2 \u{2502}   b: $x;\n  \
+         \u{2502}   ^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/undefined.scss 2:3  root stylesheet",
+    )
+}
+
+#[test]
+fn in_mixin() {
+    check(
+        "mixin",
+        "Error: Undefined variable: \"$x\"\n  \
+         \u{2577}\n\
+         2 \u{2502}   b: $x;\n  \
+         \u{2502}   ^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/mixin.scss 2:3  m()\n  \
+         tests/error_trace/mixin.scss 5:3  root stylesheet",
+    )
+}
+
+#[test]
+fn in_function() {
+    check(
+        "function",
+        "Error: Undefined variable: \"$x\"\n  \
+         \u{2577}\n\
+         2 \u{2502}   @return $x;\n  \
+         \u{2502}   ^^^^^^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/function.scss 2:3  f()\n  \
+         tests/error_trace/function.scss 5:3  root stylesheet",
+    )
+}
+
+#[test]
+fn in_import() {
+    check(
+        "import",
+        "Error: Undefined variable: \"$x\"\n  \
+         \u{2577}\n\
+         2 \u{2502}   b: $x;\n  \
+         \u{2502}   ^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/_partial.scss 2:3  @import\n  \
+         tests/error_trace/import.scss 1:1    root stylesheet",
+    )
+}
+
+#[test]
+fn compound_unit_in_output() {
+    check(
+        "compound_unit",
+        "Error: 6px*px isn't a valid CSS value.\n  \
+         \u{2577}\n\
+         2 \u{2502}   b: 2px * 3px;\n  \
+         \u{2502}   ^^^^^^^^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/compound_unit.scss 2:3  root stylesheet",
+    )
+}
+
+#[test]
+fn semicolon_in_string() {
+    check(
+        "semicolon_in_string",
+        "Error: Undefined variable: \"$x\"\n  \
+         \u{2577}\n\
+         2 \u{2502}   b: \"x;y\" $x; c: d;\n  \
+         \u{2502}   ^^^^^^^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/semicolon_in_string.scss 2:3  root stylesheet",
+    )
+}
+
+#[test]
+fn undefined_mixin() {
+    check(
+        "undefined_mixin",
+        "Error: Undefined mixin.\n  \
+         \u{2577}\n\
+         2 \u{2502}   @include nope(1, 2); b: c;\n  \
+         \u{2502}   ^^^^^^^^^^^^^^^^^^^\n  \
+         \u{2575}\n  \
+         tests/error_trace/undefined_mixin.scss 2:3  root stylesheet",
+    )
+}
+
+#[test]
+fn error_rule() {
+    let err = compile("error");
+    assert_eq!(err.inner().to_string(), "Error: \"Custom failure\"");
+    assert!(err.to_string().starts_with("Error: \"Custom failure\"\n"));
+}
+
+fn compile(name: &str) -> Error {
+    let path = format!("tests/error_trace/{}.scss", name);
+    compile_scss_file(path.as_ref(), Default::default()).unwrap_err()
+}
+
+fn check(name: &str, expected: &str) {
+    assert_eq!(compile(name).to_string(), expected);
+}
@@ -0,0 +1,91 @@
+//! Tests for `@extend` not covered by sass-spec.
+mod common;
+use common::{check, check_err};
+use rsass::{compile_scss, output};
+
+#[test]
+fn extend_later_rule() {
+    check(
+        ".a {@extend .b; x: y}\n.b {c: d}\n",
+        ".a {\n  x: y;\n}\n\n.b, .a {\n  c: d;\n}\n",
+    )
+}
+
+#[test]
+fn extend_nested() {
+    check(
+        ".p {\n  .x {a: b}\n  .y {@extend .x}\n}\n",
+        ".p .x, .p .y {\n  a: b;\n}\n",
+    )
+}
+
+#[test]
+fn unused_placeholder_is_removed() {
+    check("%p {a: b}\n.q {c: d}\n", ".q {\n  c: d;\n}\n")
+}
+
+#[test]
+fn placeholder_compressed() {
+    let format = output::Format::default()
+        .with_style(output::Style::Compressed)
+        .with_precision(5);
+    assert_eq!(
+        compile_scss(b"%p {a: b}\n.q {@extend %p}\n", format).unwrap(),
+        b".q{a:b}\n".to_vec(),
+    )
+}
+
+#[test]
+fn extend_within_media() {
+    check(
+        "@media print {\n  .a {x: y}\n  .b {@extend .a}\n}\n",
+        "@media print {\n  .a, .b {\n    x: y;\n  }\n}\n",
+    )
+}
+
+#[test]
+fn missing_target() {
+    check_err(
+        ".a {@extend .b}\n",
+        "The target selector was not found.\n\
+         Use \"@extend .b !optional\" to avoid this error.",
+    )
+}
+
+#[test]
+fn missing_target_optional() {
+    check(".a {@extend .b !optional; x: y}\n", ".a {\n  x: y;\n}\n")
+}
+
+#[test]
+fn across_media() {
+    check_err(
+        ".a {x: y}\n@media print {\n  .b {@extend .a}\n}\n",
+        "You may not @extend selectors across media queries.",
+    )
+}
+
+#[test]
+fn complex_target() {
+    check_err(
+        ".a .b {x: y}\n.c {@extend .a .b}\n",
+        "complex selectors may not be extended.",
+    )
+}
+
+#[test]
+fn extend_outside_rule() {
+    check_err(
+        "@extend .a;\n",
+        "@extend may only be used within style rules.",
+    )
+}
+
+#[test]
+fn extend_in_selector_pseudo() {
+    check(
+        ".a:not(.b) {x: y}\n:is(.b, .d) {z: w}\n.c {@extend .b}\n",
+        ".a:not(.b):not(.c) {\n  x: y;\n}\n\n\
+         :is(.b, .c, .d) {\n  z: w;\n}\n",
+    )
+}
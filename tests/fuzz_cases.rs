@@ -2,9 +2,8 @@ use rsass::{compile_scss, output};
 
 #[test]
 fn bad_escape() {
-    let format = output::Format {
-        style: output::Style::Compressed,
-        precision: 5,
-    };
+    let format = output::Format::default()
+        .with_style(output::Style::Compressed)
+        .with_precision(5);
     assert!(compile_scss(b"\\d00000", format).is_err());
 }
@@ -0,0 +1,115 @@
+//! Tests for loading files by custom importers.
+use rsass::output::Format;
+use rsass::{FileContext, FsImporter, GlobalScope, MemoryImporter};
+
+#[test]
+fn memory_relative_import() {
+    let mut files = MemoryImporter::new();
+    files.add("src/main.scss", "@import 'parts/a';");
+    files.add("src/parts/_a.scss", "@import 'b';\na { x: $b; }");
+    files.add("src/parts/b.scss", "$b: 17;");
+    let context = FileContext::with_importer(files);
+    assert_eq!(
+        compile(&context, "src/main.scss"),
+        Ok("a {\n  x: 17;\n}\n".into()),
+    );
+    assert_eq!(
+        context.loaded_files(),
+        ["src/parts/_a.scss", "src/parts/b.scss"]
+            .iter()
+            .map(Into::into)
+            .collect::<Vec<std::path::PathBuf>>(),
+    );
+}
+
+#[test]
+fn memory_missing() {
+    let mut files = MemoryImporter::new();
+    files.add("main.scss", "@use 'nothere';");
+    let context = FileContext::with_importer(files);
+    let err = compile(&context, "main.scss").unwrap_err();
+    assert!(err.starts_with("Error: Can't find stylesheet to import."));
+    assert!(err.ends_with("\n  main.scss 1:1  root stylesheet"));
+}
+
+#[test]
+fn import_missing() {
+    let mut files = MemoryImporter::new();
+    files.add("src/main.scss", "@import 'nope';");
+    let mut context = FileContext::with_importer(files);
+    context.push_path("lib".as_ref());
+    let err = compile(&context, "src/main.scss").unwrap_err();
+    assert!(err.starts_with(
+        "Error: Can't find stylesheet to import.\n\
+         Looked for \"nope\", as given, as a partial and as an index \
+         file, with .scss and .sass extensions, in these directories:\n  \
+         - src\n  \
+         - lib\n  \
+         - . (current directory)\n"
+    ));
+}
+
+#[test]
+fn import_plain_css() {
+    let mut files = MemoryImporter::new();
+    files.add(
+        "main.scss",
+        "@import 'theme.css', 'http://x.y/z', url(a.css);",
+    );
+    let context = FileContext::with_importer(files);
+    assert_eq!(
+        compile(&context, "main.scss"),
+        Ok("@import url(theme.css);\n\
+            @import url(http://x.y/z);\n\
+            @import url(a.css);\n"
+            .into()),
+    );
+}
+
+#[test]
+fn load_path_before_current_dir() {
+    let mut files = MemoryImporter::new();
+    files.add("src/main.scss", "@import 'x';");
+    files.add("_x.scss", "a { from: current-dir; }");
+    files.add("lib/_x.scss", "a { from: load-path; }");
+    let mut context = FileContext::with_importer(files);
+    context.push_path("lib".as_ref());
+    assert_eq!(
+        compile(&context, "src/main.scss"),
+        Ok("a {\n  from: load-path;\n}\n".into()),
+    );
+}
+
+#[test]
+fn pkg_scheme() {
+    let mut files = MemoryImporter::new();
+    files.add("main.scss", "@use 'pkg:lib';\np { q: lib.$color; }");
+    let mut context = FileContext::with_importer(files);
+    context.add_scheme("pkg", FsImporter::in_dir("tests/modules"));
+    assert_eq!(
+        compile(&context, "main.scss"),
+        Ok(".lib {\n  a: b;\n}\n\np {\n  q: red;\n}\n".into()),
+    );
+}
+
+#[test]
+fn use_in_importer_dir() {
+    let context =
+        FileContext::with_importer(FsImporter::in_dir("tests/modules"));
+    assert_eq!(
+        compile(&context, "use_twice.scss"),
+        Ok(".lib {\n  a: b;\n}\n\np {\n  q: red;\n}\n".into()),
+    );
+}
+
+fn compile(context: &FileContext, name: &str) -> Result<String, String> {
+    let (context, file) = context.file(name.as_ref());
+    let format = Format::default();
+    context
+        .parse(&file)
+        .and_then(|items| {
+            format.write_root(&items, &mut GlobalScope::new(format), &context)
+        })
+        .map(|css| String::from_utf8(css).unwrap())
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,93 @@
+//! Tests for the indented syntax, in `.sass` files.
+use rsass::{
+    compile_scss, compile_scss_file, output::Format, parse_sass_data,
+};
+
+#[test]
+fn sass_file() {
+    check_file(
+        "main.sass",
+        ".partial {\n  from: scss;\n}\n\n\
+         .sub2 {\n  from: sass;\n}\n\n\
+         /* loud\n   comment */\n\
+         .a, .b, .c {\n  width: 20px;\n  color: red;\n  \
+         font-family: serif;\n  font-size: 12px;\n  x: big;\n  \
+         background: url(http://example.com/a.png);\n  q: 5px;\n}\n\
+         .a:hover, .b:hover, .c:hover {\n  color: blue;\n}\n\
+         .a .n-1, .b .n-1, .c .n-1 {\n  z: 1;\n}\n\
+         .a .n-2, .b .n-2, .c .n-2 {\n  z: 2;\n}\n",
+    );
+}
+
+#[test]
+fn sass_imported_from_scss() {
+    check_file("from_scss.scss", ".sub2 {\n  from: sass;\n}\n");
+}
+
+#[test]
+fn same_as_scss() {
+    let sass = b"$c: red\n=m\n  a: $c\np\n  +m\n  q\n    b: c\n";
+    let scss = b"$c: red;\n@mixin m { a: $c }\np { @include m; q { b: c } }";
+    let format = Format::default();
+    let mut globals = rsass::GlobalScope::new(format);
+    let sass_css = format
+        .write_root(
+            &parse_sass_data(sass).unwrap(),
+            &mut globals,
+            &rsass::FileContext::new(),
+        )
+        .unwrap();
+    assert_eq!(sass_css, compile_scss(scss, format).unwrap());
+}
+
+#[test]
+fn error_position() {
+    assert_eq!(parse_sass_data(b"a\n  b: (c\n").unwrap_err().0, 7);
+}
+
+fn check_file(name: &str, expected: &str) {
+    let path = format!("tests/indented/{}", name);
+    let result = compile_scss_file(path.as_ref(), Format::default())
+        .map_err(|e| e.to_string());
+    assert_eq!(result.map(String::from_utf8), Ok(Ok(expected.into())));
+}
+
+#[test]
+fn semicolon_error() {
+    let err = compile_scss_file(
+        "tests/indented/semicolon.sass".as_ref(),
+        Format::default(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Error: semicolons aren't allowed in the indented syntax.\n  \
+         \u{2577}\n\
+         2 \u{2502}   b: c;\n  \
+         \u{2502}       ^\n  \
+         \u{2575}\n  \
+         tests/indented/semicolon.sass 2:7  root stylesheet",
+    );
+}
+
+#[test]
+fn semicolon_in_url() {
+    let sass = b"a\n  b: url(http://x.com/a;b)\n  c: \"d;e\"\n";
+    let format = Format::default();
+    let css = format
+        .write_root(
+            &parse_sass_data(sass).unwrap(),
+            &mut rsass::GlobalScope::new(format),
+            &rsass::FileContext::new(),
+        )
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(css).unwrap(),
+        "a {\n  b: url(http://x.com/a;b);\n  c: \"d;e\";\n}\n",
+    );
+}
+
+#[test]
+fn mixed_indentation_position() {
+    assert_eq!(parse_sass_data(b"a\n  b\n  \tc: d\n").unwrap_err().0, 6);
+}
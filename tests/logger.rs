@@ -0,0 +1,150 @@
+//! Tests for the messages of `@warn` and `@debug` rules, and of
+//! deprecation warnings.
+use rsass::output::Format;
+use rsass::{FileContext, Frame, GlobalScope, Logger, MemoryImporter};
+use rsass::{SilentLogger, SourcePos};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn warn_with_trace() {
+    let messages = compile(
+        "@mixin m($a) {\n  @warn \"Beware of #{$a}\";\n  b: $a;\n}\n\
+         a {\n  @include m(x);\n}\n",
+    );
+    assert_eq!(
+        messages,
+        ["WARNING: Beware of x [main.scss 2:3 m(), main.scss 6:3 root]"],
+    );
+}
+
+#[test]
+fn warn_in_function() {
+    let messages = compile(
+        "@function f($a) {\n  @warn $a;\n  @return $a;\n}\n\
+         a {\n  b: f(1 + 2);\n}\n",
+    );
+    assert_eq!(
+        messages,
+        ["WARNING: 3 [main.scss 2:3 f(), main.scss 6:3 root]"],
+    );
+}
+
+#[test]
+fn debug() {
+    let messages = compile("a {\n  @debug 1 + 2;\n}\n@debug 'done';\n");
+    assert_eq!(
+        messages,
+        ["DEBUG: 3 main.scss 2:3", "DEBUG: done main.scss 4:1"]
+    );
+}
+
+#[test]
+fn deprecation() {
+    let messages = compile("a {\n  b: unquote(17);\n}\n");
+    assert_eq!(
+        messages,
+        ["DEPRECATION: Passing 17, a non-string value, to unquote() \
+          [main.scss 2:3 root]"],
+    );
+}
+
+#[test]
+fn global_declares_new_variable() {
+    let messages = compile("a {\n  $x: 1 !global;\n}\n$y: 2 !global;\n");
+    assert_eq!(
+        messages,
+        [
+            "DEPRECATION: As of Dart Sass 2.0.0, !global assignments \
+             won't be able to declare new variables.\n\n\
+             Recommendation: add `$x: null` at the stylesheet root. \
+             [main.scss 2:3 root]",
+            "DEPRECATION: As of Dart Sass 2.0.0, !global assignments \
+             won't be able to declare new variables.\n\n\
+             Since this assignment is at the root of the stylesheet, \
+             the !global flag is\nunnecessary and can safely be removed. \
+             [main.scss 4:1 root]",
+        ],
+    );
+}
+
+#[test]
+fn global_existing_variable() {
+    let messages = compile("$x: null;\na {\n  $x: 1 !global;\n}\n");
+    assert_eq!(messages, Vec::<String>::new());
+}
+
+#[test]
+fn silent() {
+    let mut files = MemoryImporter::new();
+    files.add("main.scss", "@warn 'hidden';\na { b: c; }\n");
+    let format = Format::default();
+    let (context, file) =
+        FileContext::with_importer(files).file("main.scss".as_ref());
+    let css = format
+        .write_root(
+            &context.parse(&file).unwrap(),
+            &mut GlobalScope::new(format).with_logger(SilentLogger),
+            &context,
+        )
+        .unwrap();
+    assert_eq!(css, b"a {\n  b: c;\n}\n");
+}
+
+/// Compile `input` as `main.scss` and get the logged messages.
+fn compile(input: &str) -> Vec<String> {
+    let mut files = MemoryImporter::new();
+    files.add("main.scss", input);
+    let format = Format::default();
+    let (context, file) =
+        FileContext::with_importer(files).file("main.scss".as_ref());
+    let logger = Arc::new(Collect::default());
+    format
+        .write_root(
+            &context.parse(&file).unwrap(),
+            &mut GlobalScope::new(format).with_logger(logger.clone()),
+            &context,
+        )
+        .unwrap();
+    let messages = logger.0.lock().unwrap().clone();
+    messages
+}
+
+#[derive(Debug, Default)]
+struct Collect(Mutex<Vec<String>>);
+
+impl Logger for Collect {
+    fn warn(&self, message: &str, trace: &[Frame]) {
+        self.push(format!("WARNING: {} {}", message, show(trace)));
+    }
+    fn debug(&self, message: &str, pos: &SourcePos) {
+        self.push(format!("DEBUG: {} {}", message, place(pos)));
+    }
+    fn deprecation(&self, message: &str, trace: &[Frame]) {
+        self.push(format!("DEPRECATION: {} {}", message, show(trace)));
+    }
+}
+
+impl Collect {
+    fn push(&self, message: String) {
+        self.0.lock().unwrap().push(message);
+    }
+}
+
+fn show(trace: &[Frame]) -> String {
+    let frames = trace
+        .iter()
+        .map(|f| {
+            format!(
+                "{} {}",
+                f.pos.as_ref().map(place).unwrap_or_default(),
+                f.name.as_deref().unwrap_or("root"),
+            )
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", frames.join(", "))
+}
+
+fn place(pos: &SourcePos) -> String {
+    let (line, col) = pos.line_col();
+    format!("{} {}:{}", pos.file().name(), line, col)
+}
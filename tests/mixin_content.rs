@@ -0,0 +1,134 @@
+//! Tests for mixin content blocks, `@content` and `using`.
+mod common;
+use common::{check, check_err};
+
+#[test]
+fn content_arguments() {
+    check(
+        "@mixin m {@content(1, $b: 2)}\
+         \na {\
+         \n  @include m using ($a, $b, $c: 3) {x: $a $b $c}\
+         \n}\n",
+        "a {\n  x: 1 2 3;\n}\n",
+    )
+}
+
+#[test]
+fn content_sees_caller_variables() {
+    check(
+        "@mixin m($x) {@content; y: $x}\
+         \na {\
+         \n  $x: caller;\
+         \n  @include m(mixin) {x: $x}\
+         \n}\n",
+        "a {\n  x: caller;\n  y: mixin;\n}\n",
+    )
+}
+
+#[test]
+fn content_in_nested_mixins() {
+    check(
+        "@mixin inner {b {@content}}\
+         \n@mixin outer($v) {@include inner {@content($v)}}\
+         \na {\
+         \n  @include outer(d) using ($v) {c: $v}\
+         \n}\n",
+        "a b {\n  c: d;\n}\n",
+    )
+}
+
+#[test]
+fn content_exists_in_nested_include() {
+    check(
+        "@mixin inner {x: content-exists()}\
+         \n@mixin outer {@include inner; @content}\
+         \na {\
+         \n  @include outer {y: z}\
+         \n}\n",
+        "a {\n  x: false;\n  y: z;\n}\n",
+    )
+}
+
+#[test]
+fn content_exists_empty() {
+    check(
+        "@mixin m {x: content-exists()}\
+         \na {@include m {}}\
+         \nb {@include m}\n",
+        "a {\n  x: true;\n}\n\nb {\n  x: false;\n}\n",
+    )
+}
+
+#[test]
+fn content_at_root() {
+    check(
+        "@mixin m {@content}\n@include m {a {b: c}}\n",
+        "a {\n  b: c;\n}\n",
+    )
+}
+
+#[test]
+fn content_outside_mixin() {
+    check_err(
+        "a {@content}\n",
+        "@content is only allowed within mixin declarations.",
+    )
+}
+
+#[test]
+fn content_exists_outside_mixin() {
+    check_err(
+        "a {b: content-exists()}\n",
+        "content-exists() may only be called within a mixin.",
+    )
+}
+
+#[test]
+fn too_many_content_arguments() {
+    check_err(
+        "@mixin m {@content(1, 2)}\na {@include m using ($a) {b: $a}}\n",
+        "Only 1 argument allowed, but 2 were passed.",
+    )
+}
+
+#[test]
+fn mixin_lexical_scope() {
+    check(
+        "$x: global;\
+         \n@mixin show {v: $x}\
+         \na {\
+         \n  $x: local;\
+         \n  @include show;\
+         \n}\n",
+        "a {\n  v: global;\n}\n",
+    )
+}
+
+#[test]
+fn local_mixin_lexical_scope() {
+    check(
+        "a {\
+         \n  $x: outer;\
+         \n  @mixin show {v: $x}\
+         \n  @mixin call($x) {@include show}\
+         \n  @include call(arg);\
+         \n}\n",
+        "a {\n  v: outer;\n}\n",
+    )
+}
+
+#[test]
+fn content_lexical_scope() {
+    check(
+        "$x: global;\
+         \n@mixin inner {@content}\
+         \n@mixin outer {\
+         \n  @include inner {inner: $x}\
+         \n}\
+         \na {\
+         \n  $x: caller;\
+         \n  @include outer;\
+         \n}\n",
+        "a {\n  inner: global;\n}\n",
+    )
+}
@@ -0,0 +1,347 @@
+//! Tests for the module system (`@use` and `@forward`).
+//! The modules used are in the `tests/modules` directory.
+use rsass::output::Format;
+use rsass::{
+    compile_scss, compile_scss_file, parse_file, FileContext, GlobalScope,
+};
+
+#[test]
+fn use_namespaced() {
+    check(
+        "use_namespaced",
+        ".lib {\n  a: b;\n}\n\n\
+         .x {\n  width: 6px;\n  color: red;\n  c: red;\n  d: 4;\n}\n",
+    )
+}
+
+#[test]
+fn use_configured() {
+    check(
+        "use_configured",
+        ".lib {\n  a: b;\n}\n\n.x {\n  c: blue;\n}\n",
+    )
+}
+
+#[test]
+fn use_star_and_twice() {
+    // The css of the module is only included once.
+    check(
+        "use_star",
+        ".lib {\n  a: b;\n}\n\n.x {\n  c: red;\n  d: 4;\n  e: red;\n}\n",
+    )
+}
+
+#[test]
+fn use_forwarded() {
+    check(
+        "use_forwarded",
+        ".lib {\n  a: b;\n}\n\n.x {\n  d: 2;\n  e: 3;\n}\n",
+    )
+}
+
+#[test]
+fn use_hidden() {
+    check_err("use_hidden", "Undefined variable.")
+}
+
+#[test]
+fn use_hidden_mixin() {
+    check_err("use_hidden_mixin", "Undefined mixin.")
+}
+
+#[test]
+fn use_private() {
+    check_err(
+        "use_private",
+        "Private members can't be accessed from outside their modules.",
+    )
+}
+
+#[test]
+fn use_assign() {
+    check(
+        "use_assign",
+        ".lib {\n  a: b;\n}\n\n\
+         .x {\n  c: pink;\n  width: 2px;\n  color: pink;\n}\n",
+    )
+}
+
+#[test]
+fn use_assign_undefined() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\nmath.$nope: 1;\n").unwrap_err(),
+        "Undefined variable."
+    )
+}
+
+#[test]
+fn use_undefined_variable() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.$nope}\n").unwrap_err(),
+        "Undefined variable."
+    )
+}
+
+#[test]
+fn use_after_rule() {
+    assert_eq!(
+        compile_str("a {b: c}\n@use \"sass:math\";\n").unwrap_err(),
+        "@use rules must be written before any other rules."
+    )
+}
+
+#[test]
+fn use_after_variable() {
+    assert_eq!(
+        compile_str("$a: 1;\n// comment\n@use \"sass:math\";\nb {c: $a}\n")
+            .unwrap(),
+        "b {\n  c: 1;\n}\n"
+    )
+}
+
+#[test]
+fn use_assign_builtin() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\nmath.$pi: 3;\n").unwrap_err(),
+        "Cannot modify built-in variable."
+    )
+}
+
+#[test]
+fn use_assign_global() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {math.$pi: 1 !global;}\n")
+            .unwrap_err(),
+        "!global isn't allowed for variables in other modules."
+    )
+}
+
+#[test]
+fn use_bad_config() {
+    check_err(
+        "use_bad_config",
+        "$nope: This variable was not declared with !default in the \
+         @used module.",
+    )
+}
+
+#[test]
+fn use_loop() {
+    check_err(
+        "use_loop",
+        "Module loop: this module is already being loaded.",
+    )
+}
+
+#[test]
+fn use_missing() {
+    check_err(
+        "use_missing",
+        "Can't find stylesheet to import.\n\
+         Looked for \"nowhere\", as given, as a partial and as an index \
+         file, with .scss and .sass extensions, in these directories:\n  \
+         - tests/modules\n  \
+         - . (current directory)",
+    )
+}
+
+#[test]
+fn use_load_path() {
+    let mut context = FileContext::new();
+    context.push_path("tests/modules/path_a".as_ref());
+    context.push_path("tests/modules/path_b".as_ref());
+    let (context, file) =
+        context.file("tests/modules/use_load_path.scss".as_ref());
+    let format = Format::default();
+    let css = format
+        .write_root(
+            &parse_file(&file).unwrap(),
+            &mut GlobalScope::new(format),
+            &context,
+        )
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(css).unwrap(),
+        ".a {\n  x: a;\n}\n\n.b {\n  only: b;\n}\n",
+    );
+}
+
+#[test]
+fn use_builtin_math() {
+    assert_eq!(
+        compile_str(
+            "@use \"sass:math\";\n\
+             a {b: math.div(10px, 4); c: math.$pi; d: math.abs(-2)}\n"
+        )
+        .unwrap(),
+        "a {\n  b: 2.5px;\n  c: 3.141593;\n  d: 2;\n}\n"
+    )
+}
+
+#[test]
+fn math_div_not_a_number() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.div(1, \"a\")}\n"),
+        Err("$number2: \"a\" is not a number.".into())
+    )
+}
+
+#[test]
+fn math_unitless_only() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.sqrt(4px)}\n"),
+        Err("$number: Expected 4px to have no units.".into())
+    );
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.log(8, 2px)}\n"),
+        Err("$base: Expected 2px to have no units.".into())
+    );
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.sqrt(a)}\n"),
+        Err("$number: a is not a number.".into())
+    );
+}
+
+#[test]
+fn math_angle() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.sin(1px)}\n"),
+        Err("$number: Expected 1px to be an angle.".into())
+    );
+}
+
+#[test]
+fn math_mixed_units() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.hypot(1px, 1)}\n"),
+        Err("Argument 1 has unit px but argument 2 is unitless. \
+             Arguments must all have units or all be unitless."
+            .into())
+    );
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.clamp(1px, 2, 3px)}\n"),
+        Err("$min has unit px but $number is unitless. \
+             Arguments must all have units or all be unitless."
+            .into())
+    );
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.hypot(1px, 1s)}\n"),
+        Err("Incompatible units px and s.".into())
+    );
+}
+
+#[test]
+fn math_tan_asymptote() {
+    assert_eq!(
+        compile_str(
+            "@use \"sass:math\";\n\
+             a {b: math.tan(90deg); c: math.tan(-90deg)}\n"
+        ),
+        Ok("a {\n  b: Infinity;\n  c: -Infinity;\n}\n".into())
+    )
+}
+
+#[test]
+fn math_unit_order() {
+    assert_eq!(
+        compile_str(
+            "@use \"sass:math\";\n\
+             a {b: math.unit(1px * 1em); c: math.unit(1em * 1px / 1s)}\n"
+        ),
+        Ok("a {\n  b: \"px*em\";\n  c: \"em*px/s\";\n}\n".into())
+    )
+}
+
+#[test]
+fn use_builtin_undefined_function() {
+    assert_eq!(
+        compile_str("@use \"sass:math\";\na {b: math.map-get((a: 1), a)}\n"),
+        Err("Undefined function.".into())
+    )
+}
+
+#[test]
+fn use_builtin_as() {
+    assert_eq!(
+        compile_str(
+            "@use \"sass:map\" as m;\n\
+             a {b: m.get((x: 1, y: 2), y); c: map-get((x: 3), x)}\n"
+        )
+        .unwrap(),
+        "a {\n  b: 2;\n  c: 3;\n}\n"
+    )
+}
+
+#[test]
+fn use_builtin_configured() {
+    assert_eq!(
+        compile_str("@use \"sass:math\" with ($pi: 3);\n").unwrap_err(),
+        "Built-in modules can't be configured."
+    )
+}
+
+#[test]
+fn meta_module_members() {
+    check(
+        "meta_module_members",
+        ".lib {\n  a: b;\n}\n\n\
+         .x {\n  functions: (\"double\": get-function(\"double\"));\n  \
+         variables: (\"color\": red);\n  call: 8;\n}\n",
+    )
+}
+
+#[test]
+fn meta_module_unknown() {
+    assert_eq!(
+        compile_str(
+            "@use \"sass:meta\";\na {b: meta.module-variables(\"x\")}\n"
+        )
+        .unwrap_err(),
+        "There is no module with namespace \"x\"."
+    )
+}
+
+#[test]
+fn meta_load_css() {
+    // Unlike @use, load-css includes the css each time, and may
+    // be nested in a rule.
+    check(
+        "meta_load_css",
+        ".lib {\n  a: b;\n}\n\n.x .lib {\n  a: b;\n}\n",
+    )
+}
+
+#[test]
+fn use_builtin_unknown() {
+    assert_eq!(
+        compile_str("@use \"sass:nope\";\n").unwrap_err(),
+        "Invalid internal module \"sass:nope\"."
+    )
+}
+
+fn compile_str(input: &str) -> Result<String, String> {
+    compile_scss(input.as_bytes(), Default::default())
+        .map(|s| String::from_utf8(s).unwrap())
+        .map_err(|e| e.inner().to_string())
+}
+
+fn check(name: &str, expected: &str) {
+    let path = format!("tests/modules/{}.scss", name);
+    assert_eq!(
+        compile_scss_file(path.as_ref(), Default::default())
+            .and_then(|s| Ok(String::from_utf8(s)?))
+            .unwrap(),
+        expected
+    );
+}
+
+fn check_err(name: &str, expected: &str) {
+    let path = format!("tests/modules/{}.scss", name);
+    assert_eq!(
+        compile_scss_file(path.as_ref(), Default::default())
+            .unwrap_err()
+            .inner()
+            .to_string(),
+        expected
+    );
+}
@@ -4,10 +4,9 @@ use std::sync::Arc;
 #[test]
 fn simple_value() {
     let parsed = parse_scss_data(b"p { color: $color }").unwrap();
-    let format = output::Format {
-        style: output::Style::Compressed,
-        precision: 5,
-    };
+    let format = output::Format::default()
+        .with_style(output::Style::Compressed)
+        .with_precision(5);
     let mut scope = GlobalScope::new(format);
     scope.define("color", &css::Value::black());
     let file_context = FileContext::new();
@@ -22,10 +21,9 @@ fn simple_value() {
 
 #[test]
 fn simple_function() {
-    let format = output::Format {
-        style: output::Style::Compressed,
-        precision: 5,
-    };
+    let format = output::Format::default()
+        .with_style(output::Style::Compressed)
+        .with_precision(5);
     let mut scope = GlobalScope::new(format);
     scope.define_function(
         "get_answer",
@@ -48,7 +46,7 @@ fn simple_function() {
 
 #[cfg(test)]
 fn avg(a: Number, b: Number) -> Number {
-    Number::from((a.value + b.value) / 2)
+    &(a + b) / &Number::from(2)
 }
 
 #[test]
@@ -82,10 +80,9 @@ fn function_with_args() {
         ),
     );
     let parsed = parse_scss_data(b"p { x: halfway(10, 18); }").unwrap();
-    let format = output::Format {
-        style: output::Style::Compressed,
-        precision: 5,
-    };
+    let format = output::Format::default()
+        .with_style(output::Style::Compressed)
+        .with_precision(5);
     let file_context = FileContext::new();
     assert_eq!(
         format
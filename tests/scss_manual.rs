@@ -81,6 +81,35 @@ fn precision() {
     )
 }
 
+#[test]
+fn for_with_huge_bound() {
+    let err = compile_scss(
+        b"@for $i from 1 through 1e30 {a {b: $i}}\n",
+        Default::default(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.inner().to_string(),
+        "1000000000000000000000000000000 is not an integer in a usable range."
+    )
+}
+
+#[test]
+fn repeated_squaring() {
+    check(
+        "$x: 1.1;\n@for $i from 1 through 60 {$x: $x * $x;}\n\
+         a {b: $x > 1000}\n",
+        "a {\n  b: true;\n}\n",
+    )
+}
+
+#[test]
+fn calc_with_complex_unit() {
+    let err = compile_scss(b"a {b: calc(2px * 3px)}\n", Default::default())
+        .unwrap_err();
+    assert_eq!(err.inner().to_string(), "6px*px isn't a valid CSS value.")
+}
+
 fn check(input: &str, expected: &str) {
     assert_eq!(
         compile_scss(input.as_bytes(), Default::default())
@@ -26,6 +26,53 @@ fn parse() {
     )
 }
 
+#[test]
+fn extend() {
+    check(
+        "a {\n  b: selector-extend(\"a.disabled\", \"a\", \".link\");\n  \
+         c: selector-extend(\".a\", \".a\", \"%b\");\n}\n",
+        "a {\n  b: a.disabled, .disabled.link;\n  c: .a, %b;\n}\n",
+    )
+}
+
+#[test]
+fn unify() {
+    check(
+        "a {\n  b: selector-unify(\"a\", \".disabled\");\n  \
+         c: inspect(selector-unify(\"a\", \"h1\"));\n  \
+         d: selector-unify(\"#a\", \".b > .c\");\n}\n",
+        "a {\n  b: a.disabled;\n  c: null;\n  d: .b > #a.c;\n}\n",
+    )
+}
+
+#[test]
+fn is_superselector() {
+    check(
+        "a {\n  b: is-superselector(\"a\", \"a.disabled\");\n  \
+         c: is-superselector(\"a.disabled\", \"a\");\n  \
+         d: is-superselector(\".a, .b\", \".b\");\n}\n",
+        "a {\n  b: true;\n  c: false;\n  d: true;\n}\n",
+    )
+}
+
+#[test]
+fn selector_pseudo_args() {
+    check(
+        "a {\n  b: selector-extend(\".a:not(.b)\", \".b\", \".c\");\n  \
+         c: is-superselector(\":is(.a, .b)\", \".a\");\n}\n",
+        "a {\n  b: .a:not(.b):not(.c);\n  c: true;\n}\n",
+    )
+}
+
+#[test]
+fn parse_structured() {
+    check(
+        "$s: nth(selector-parse(\".a > .b :not(.c .d)\"), 1);\n\
+         a {\n  b: length($s);\n  c: nth($s, 2);\n  d: nth($s, 4);\n}\n",
+        "a {\n  b: 4;\n  c: >;\n  d: :not(.c .d);\n}\n",
+    )
+}
+
 fn check(input: &str, expected: &str) {
     assert_eq!(
         compile_scss(input.as_bytes(), Default::default())
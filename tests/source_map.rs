@@ -0,0 +1,53 @@
+//! Tests for source map generation.
+use rsass::{compile_scss_file_with_source_map, output::Format};
+
+#[test]
+fn mappings() {
+    let (css, map) = compile_scss_file_with_source_map(
+        "tests/source_map/main.scss".as_ref(),
+        Format::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(css).unwrap(),
+        "q {\n  z: 1;\n}\n\na {\n  color: red;\n}\na b {\n  x: y;\n}\n",
+    );
+    assert_eq!(
+        map.to_json(Some("main.css"), false),
+        "{\"version\":3,\"file\":\"main.css\",\
+         \"sources\":[\"tests/source_map/_imported.scss\",\
+         \"tests/source_map/main.scss\"],\
+         \"names\":[],\
+         \"mappings\":\"AAAA;EACE;;;ACCF;EACE;;AACA;EAAI\"}",
+    );
+}
+
+#[test]
+fn embedded_content() {
+    let (_, map) = compile_scss_file_with_source_map(
+        "tests/source_map/_imported.scss".as_ref(),
+        Format::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        map.to_json(None, true),
+        "{\"version\":3,\
+         \"sources\":[\"tests/source_map/_imported.scss\"],\
+         \"sourcesContent\":[\"q {\\n  z: 1;\\n}\\n\"],\
+         \"names\":[],\"mappings\":\"AAAA;EACE\"}",
+    );
+    assert!(map
+        .to_data_url(None)
+        .starts_with("data:application/json;charset=utf-8;base64,eyJ2"));
+}
+
+#[test]
+fn sources_relative_to_output() {
+    let (_, mut map) = compile_scss_file_with_source_map(
+        "tests/source_map/main.scss".as_ref(),
+        Format::default(),
+    )
+    .unwrap();
+    map.relative_to("tests/source_map/css".as_ref());
+    assert_eq!(map.sources(), ["../_imported.scss", "../main.scss"]);
+}
@@ -38,7 +38,6 @@ mod alpha {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn min() {
             assert_eq!(
                 rsass(
@@ -53,7 +52,6 @@ mod alpha {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative() {
             assert_eq!(
                 rsass(
@@ -115,7 +113,6 @@ mod alpha {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn min() {
             assert_eq!(
                 rsass(
@@ -130,7 +127,6 @@ mod alpha {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative() {
             assert_eq!(
                 rsass(
@@ -145,7 +141,6 @@ mod alpha {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn positive() {
             assert_eq!(
                 rsass(
@@ -199,7 +194,6 @@ mod clamped {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn blue() {
         assert_eq!(
             rsass(
@@ -214,7 +208,6 @@ mod clamped {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn saturation() {
         assert_eq!(
             rsass(
@@ -235,7 +228,6 @@ mod in_gamut {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn named() {
         assert_eq!(
         rsass(
@@ -264,7 +256,6 @@ mod in_gamut {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn partial() {
         assert_eq!(
             rsass(
@@ -279,7 +270,6 @@ mod in_gamut {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn transparent() {
         assert_eq!(
             rsass(
@@ -303,7 +293,6 @@ mod special_functions {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn arg_1() {
             assert_eq!(
                 rsass(
@@ -318,7 +307,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_2() {
             assert_eq!(
                 rsass(
@@ -333,7 +321,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_3() {
             assert_eq!(
                 rsass(
@@ -348,7 +335,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_4() {
             assert_eq!(
                 rsass(
@@ -367,7 +353,6 @@ mod special_functions {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn arg_1() {
             assert_eq!(
                 rsass(
@@ -382,7 +367,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_2() {
             assert_eq!(
                 rsass(
@@ -397,7 +381,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_3() {
             assert_eq!(
                 rsass(
@@ -412,7 +395,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_4() {
             assert_eq!(
                 rsass(
@@ -431,7 +413,6 @@ mod special_functions {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn arg_1() {
             assert_eq!(
                 rsass(
@@ -446,7 +427,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_2() {
             assert_eq!(
                 rsass(
@@ -461,7 +441,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_3() {
             assert_eq!(
                 rsass(
@@ -476,7 +455,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_4() {
             assert_eq!(
                 rsass(
@@ -495,7 +473,6 @@ mod special_functions {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn arg_1() {
             assert_eq!(
                 rsass(
@@ -510,7 +487,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_2() {
             assert_eq!(
                 rsass(
@@ -525,7 +501,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_3() {
             assert_eq!(
                 rsass(
@@ -540,7 +515,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_4() {
             assert_eq!(
                 rsass(
@@ -559,7 +533,6 @@ mod special_functions {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn arg_1() {
             assert_eq!(
                 rsass(
@@ -574,7 +547,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_2() {
             assert_eq!(
                 rsass(
@@ -589,7 +561,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_3() {
             assert_eq!(
                 rsass(
@@ -604,7 +575,6 @@ mod special_functions {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn arg_4() {
             assert_eq!(
                 rsass(
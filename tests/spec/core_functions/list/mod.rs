@@ -1597,7 +1597,6 @@ mod zip {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // unexepected error
         fn empty() {
             assert_eq!(
                 rsass(
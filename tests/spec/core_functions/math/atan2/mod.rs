@@ -7,7 +7,6 @@ mod arguments {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn compatible_units() {
         assert_eq!(
             rsass(
@@ -43,7 +42,6 @@ mod arguments {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn named_args() {
         assert_eq!(
             rsass(
@@ -71,7 +69,6 @@ mod y_infinite {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -87,7 +84,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -103,7 +99,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -119,7 +114,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -135,7 +129,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero() {
                 assert_eq!(
                     rsass(
@@ -151,7 +144,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -175,7 +167,6 @@ mod y_infinite {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -191,7 +182,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -207,7 +197,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -223,7 +212,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -239,7 +227,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero() {
                 assert_eq!(
                     rsass(
@@ -255,7 +242,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -271,7 +257,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -287,7 +272,6 @@ mod y_infinite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -317,7 +301,6 @@ mod y_non_zero_finite {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -333,7 +316,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -349,7 +331,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -365,7 +346,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -381,7 +361,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero() {
                 assert_eq!(
                     rsass(
@@ -397,7 +376,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -413,7 +391,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -429,7 +406,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -453,7 +429,6 @@ mod y_non_zero_finite {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -469,7 +444,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -485,7 +459,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -501,7 +474,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -517,7 +489,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero() {
                 assert_eq!(
                     rsass(
@@ -533,7 +504,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -549,7 +519,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -565,7 +534,6 @@ mod y_non_zero_finite {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -595,7 +563,6 @@ mod y_zero {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -611,7 +578,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -691,7 +657,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -707,7 +672,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -731,7 +695,6 @@ mod y_zero {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -747,7 +710,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -763,7 +725,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -779,7 +740,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -835,7 +795,6 @@ mod y_zero {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -851,7 +810,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -867,7 +825,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -883,7 +840,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -915,7 +871,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -931,7 +886,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -947,7 +901,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero_fuzzy() {
                 assert_eq!(
                     rsass(
@@ -971,7 +924,6 @@ mod y_zero {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn finite() {
                 assert_eq!(
                     rsass(
@@ -987,7 +939,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -1003,7 +954,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_finite() {
                 assert_eq!(
                     rsass(
@@ -1019,7 +969,6 @@ mod y_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -95,7 +95,6 @@ mod abs {
         }
     }
     #[test]
-    #[ignore] // unexepected error
     fn preserves_units() {
         assert_eq!(
             rsass(
@@ -130,7 +129,6 @@ mod acos {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn decimal() {
         assert_eq!(
             rsass(
@@ -158,7 +156,6 @@ mod acos {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn greater_than_one() {
         assert_eq!(
             rsass(
@@ -174,7 +171,6 @@ mod acos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn less_than_negative_one() {
         assert_eq!(
             rsass(
@@ -190,7 +186,6 @@ mod acos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_decimal() {
         assert_eq!(
             rsass(
@@ -206,7 +201,6 @@ mod acos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn one() {
         assert_eq!(
             rsass(
@@ -222,7 +216,6 @@ mod acos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn one_fuzzy() {
         assert_eq!(
             rsass(
@@ -244,7 +237,6 @@ mod asin {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn decimal() {
         assert_eq!(
             rsass(
@@ -272,7 +264,6 @@ mod asin {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn greater_than_one() {
         assert_eq!(
             rsass(
@@ -288,7 +279,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn less_than_negative_one() {
         assert_eq!(
             rsass(
@@ -304,7 +294,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_decimal() {
         assert_eq!(
             rsass(
@@ -320,7 +309,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero() {
         assert_eq!(
             rsass(
@@ -336,7 +324,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -352,7 +339,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn one() {
         assert_eq!(
             rsass(
@@ -368,7 +354,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn one_fuzzy() {
         assert_eq!(
             rsass(
@@ -384,7 +369,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero() {
         assert_eq!(
             rsass(
@@ -400,7 +384,6 @@ mod asin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -434,7 +417,6 @@ mod atan {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn infinity() {
         assert_eq!(
             rsass(
@@ -450,7 +432,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative() {
         assert_eq!(
             rsass(
@@ -466,7 +447,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_infinity() {
         assert_eq!(
             rsass(
@@ -482,7 +462,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero() {
         assert_eq!(
             rsass(
@@ -498,7 +477,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -514,7 +492,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn positive() {
         assert_eq!(
             rsass(
@@ -530,7 +507,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero() {
         assert_eq!(
             rsass(
@@ -546,7 +522,6 @@ mod atan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -650,7 +625,6 @@ mod ceil {
         );
     }
     #[test]
-    #[ignore] // unexepected error
     fn preserves_units() {
         assert_eq!(
             rsass(
@@ -671,7 +645,6 @@ mod clamp {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn chooses_max() {
         assert_eq!(
             rsass(
@@ -687,7 +660,6 @@ mod clamp {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn chooses_min() {
         assert_eq!(
             rsass(
@@ -703,7 +675,6 @@ mod clamp {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn chooses_number() {
         assert_eq!(
             rsass(
@@ -769,7 +740,6 @@ mod clamp {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn min_equals_max() {
         assert_eq!(
             rsass(
@@ -787,7 +757,6 @@ mod clamp {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn min_greater_than_max() {
         assert_eq!(
             rsass(
@@ -805,7 +774,6 @@ mod clamp {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn named_args() {
         assert_eq!(
             rsass(
@@ -824,7 +792,6 @@ mod clamp {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn max() {
             assert_eq!(
                 rsass(
@@ -840,7 +807,6 @@ mod clamp {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn min() {
             assert_eq!(
                 rsass(
@@ -856,7 +822,6 @@ mod clamp {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn number() {
             assert_eq!(
                 rsass(
@@ -912,7 +877,6 @@ mod comparable {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn to_compatible() {
             assert_eq!(
                 rsass(
@@ -941,7 +905,6 @@ mod comparable {
             );
         }
         #[test]
-        #[ignore] // unexepected error
         fn to_inverse() {
             assert_eq!(
                 rsass(
@@ -974,7 +937,6 @@ mod comparable {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn to_unit() {
             assert_eq!(
                 rsass(
@@ -1054,7 +1016,6 @@ mod cos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn infinity() {
         assert_eq!(
             rsass(
@@ -1086,7 +1047,6 @@ mod cos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_infinity() {
         assert_eq!(
             rsass(
@@ -1118,7 +1078,6 @@ mod cos {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn turn() {
         assert_eq!(
             rsass(
@@ -1236,7 +1195,6 @@ mod floor {
         );
     }
     #[test]
-    #[ignore] // unexepected error
     fn preserves_units() {
         assert_eq!(
             rsass(
@@ -1320,7 +1278,6 @@ mod hypot {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn first() {
             assert_eq!(
                 rsass(
@@ -1336,7 +1293,6 @@ mod hypot {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn second() {
             assert_eq!(
                 rsass(
@@ -1352,7 +1308,6 @@ mod hypot {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn third() {
             assert_eq!(
                 rsass(
@@ -1394,7 +1349,6 @@ mod log {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn between_zero_and_one() {
             assert_eq!(
                 rsass(
@@ -1410,7 +1364,6 @@ mod log {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative() {
             assert_eq!(
                 rsass(
@@ -1442,7 +1395,6 @@ mod log {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn one() {
             assert_eq!(
                 rsass(
@@ -1458,7 +1410,6 @@ mod log {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn one_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1490,7 +1441,6 @@ mod log {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn zero() {
             assert_eq!(
                 rsass(
@@ -1506,7 +1456,6 @@ mod log {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn zero_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1537,7 +1486,6 @@ mod log {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn infinity() {
         assert_eq!(
             rsass(
@@ -1593,7 +1541,6 @@ mod log {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn negative() {
         assert_eq!(
             rsass(
@@ -1625,7 +1572,6 @@ mod log {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero() {
         assert_eq!(
             rsass(
@@ -2236,7 +2182,6 @@ mod round {
         );
     }
     #[test]
-    #[ignore] // unexepected error
     fn preserves_units() {
         assert_eq!(
             rsass(
@@ -2378,7 +2323,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn infinity() {
         assert_eq!(
             rsass(
@@ -2410,7 +2354,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_infinity() {
         assert_eq!(
             rsass(
@@ -2426,7 +2369,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero() {
         assert_eq!(
             rsass(
@@ -2442,7 +2384,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -2474,7 +2415,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn turn() {
         assert_eq!(
             rsass(
@@ -2506,7 +2446,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero() {
         assert_eq!(
             rsass(
@@ -2522,7 +2461,6 @@ mod sin {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -2556,7 +2494,6 @@ mod sqrt {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn infinity() {
         assert_eq!(
             rsass(
@@ -2588,7 +2525,6 @@ mod sqrt {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative() {
         assert_eq!(
             rsass(
@@ -2604,7 +2540,6 @@ mod sqrt {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero() {
         assert_eq!(
             rsass(
@@ -2652,7 +2587,6 @@ mod sqrt {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero() {
         assert_eq!(
             rsass(
@@ -2693,7 +2627,6 @@ mod tan {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // unexepected error
         fn radian() {
             assert_eq!(
                 rsass(
@@ -2754,7 +2687,6 @@ mod tan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn infinity() {
         assert_eq!(
             rsass(
@@ -2789,7 +2721,6 @@ mod tan {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // unexepected error
         fn radian() {
             assert_eq!(
                 rsass(
@@ -2806,7 +2737,6 @@ mod tan {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_infinity() {
         assert_eq!(
             rsass(
@@ -2822,7 +2752,6 @@ mod tan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero() {
         assert_eq!(
             rsass(
@@ -2838,7 +2767,6 @@ mod tan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn negative_zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -2870,7 +2798,6 @@ mod tan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn turn() {
         assert_eq!(
             rsass(
@@ -2902,7 +2829,6 @@ mod tan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero() {
         assert_eq!(
             rsass(
@@ -2918,7 +2844,6 @@ mod tan {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn zero_fuzzy() {
         assert_eq!(
             rsass(
@@ -3026,7 +2951,6 @@ mod unit {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn single() {
             assert_eq!(
                 rsass(
@@ -3042,7 +2966,6 @@ mod unit {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn one_denominator() {
         assert_eq!(
             rsass(
@@ -3077,7 +3000,6 @@ mod unitless {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // unexepected error
     fn denominator() {
         assert_eq!(
             rsass(
@@ -3130,7 +3052,6 @@ mod unitless {
         );
     }
     #[test]
-    #[ignore] // unexepected error
     fn numerator_and_denominator() {
         assert_eq!(
             rsass(
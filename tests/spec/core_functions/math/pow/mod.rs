@@ -25,7 +25,6 @@ mod arguments {
         // Ignoring "zero_args", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn named_args() {
         assert_eq!(
             rsass(
@@ -72,7 +71,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn infinity() {
                     assert_eq!(
                         rsass(
@@ -88,7 +86,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn integer() {
                     assert_eq!(
                         rsass(
@@ -104,7 +101,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn integer_fuzzy() {
                     assert_eq!(
                         rsass(
@@ -120,7 +116,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn negative_infinity() {
                     assert_eq!(
                         rsass(
@@ -136,7 +131,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn zero() {
                     assert_eq!(
                         rsass(
@@ -152,7 +146,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn zero_fuzzy() {
                     assert_eq!(
                         rsass(
@@ -176,7 +169,6 @@ mod base_greater_than_zero {
                 #[allow(unused)]
                 use super::rsass;
                 #[test]
-                #[ignore] // wrong result
                 fn infinity() {
                     assert_eq!(
                         rsass(
@@ -192,7 +184,6 @@ mod base_greater_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn negative_infinity() {
                     assert_eq!(
                         rsass(
@@ -306,7 +297,6 @@ mod base_less_than_zero {
                 #[allow(unused)]
                 use super::rsass;
                 #[test]
-                #[ignore] // wrong result
                 fn infinity() {
                     assert_eq!(
                         rsass(
@@ -322,7 +312,6 @@ mod base_less_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn negative_infinity() {
                     assert_eq!(
                         rsass(
@@ -346,7 +335,6 @@ mod base_less_than_zero {
                 #[allow(unused)]
                 use super::rsass;
                 #[test]
-                #[ignore] // wrong result
                 fn decimal() {
                     assert_eq!(
                         rsass(
@@ -362,7 +350,6 @@ mod base_less_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn infinity() {
                     assert_eq!(
                         rsass(
@@ -378,7 +365,6 @@ mod base_less_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn integer() {
                     assert_eq!(
                         rsass(
@@ -410,7 +396,6 @@ mod base_less_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn negative_infinity() {
                     assert_eq!(
                         rsass(
@@ -426,7 +411,6 @@ mod base_less_than_zero {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn zero() {
                     assert_eq!(
                         rsass(
@@ -550,7 +534,6 @@ mod base_negative_infinity {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn decimal() {
             assert_eq!(
                 rsass(
@@ -566,7 +549,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer() {
             assert_eq!(
                 rsass(
@@ -582,7 +564,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -598,7 +579,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn infinity() {
             assert_eq!(
                 rsass(
@@ -614,7 +594,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_decimal() {
             assert_eq!(
                 rsass(
@@ -630,7 +609,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer() {
             assert_eq!(
                 rsass(
@@ -646,7 +624,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -662,7 +639,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_infinity() {
             assert_eq!(
                 rsass(
@@ -678,7 +654,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_odd_integer() {
             assert_eq!(
                 rsass(
@@ -694,7 +669,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_odd_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -710,7 +684,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer() {
             assert_eq!(
                 rsass(
@@ -742,7 +715,6 @@ mod base_negative_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn zero() {
             assert_eq!(
                 rsass(
@@ -803,7 +775,6 @@ mod base_negative_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn even_integer() {
                 assert_eq!(
                     rsass(
@@ -819,7 +790,6 @@ mod base_negative_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -867,7 +837,6 @@ mod base_negative_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -899,7 +868,6 @@ mod base_negative_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn odd_integer() {
                 assert_eq!(
                     rsass(
@@ -915,7 +883,6 @@ mod base_negative_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -936,7 +903,6 @@ mod base_negative_zero {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn decimal() {
             assert_eq!(
                 rsass(
@@ -952,7 +918,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer() {
             assert_eq!(
                 rsass(
@@ -968,7 +933,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -984,7 +948,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn infinity() {
             assert_eq!(
                 rsass(
@@ -1000,7 +963,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_decimal() {
             assert_eq!(
                 rsass(
@@ -1016,7 +978,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer() {
             assert_eq!(
                 rsass(
@@ -1032,7 +993,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1048,7 +1008,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_infinity() {
             assert_eq!(
                 rsass(
@@ -1096,7 +1055,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer() {
             assert_eq!(
                 rsass(
@@ -1112,7 +1070,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1128,7 +1085,6 @@ mod base_negative_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn zero() {
             assert_eq!(
                 rsass(
@@ -1170,7 +1126,6 @@ mod base_positive_infinity {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn decimal() {
             assert_eq!(
                 rsass(
@@ -1186,7 +1141,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer() {
             assert_eq!(
                 rsass(
@@ -1202,7 +1156,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1218,7 +1171,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn infinity() {
             assert_eq!(
                 rsass(
@@ -1234,7 +1186,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_decimal() {
             assert_eq!(
                 rsass(
@@ -1250,7 +1201,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer() {
             assert_eq!(
                 rsass(
@@ -1266,7 +1216,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1282,7 +1231,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_infinity() {
             assert_eq!(
                 rsass(
@@ -1298,7 +1246,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_odd_integer() {
             assert_eq!(
                 rsass(
@@ -1314,7 +1261,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_odd_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1330,7 +1276,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer() {
             assert_eq!(
                 rsass(
@@ -1346,7 +1291,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1362,7 +1306,6 @@ mod base_positive_infinity {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn zero() {
             assert_eq!(
                 rsass(
@@ -1423,7 +1366,6 @@ mod base_positive_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn even_integer() {
                 assert_eq!(
                     rsass(
@@ -1439,7 +1381,6 @@ mod base_positive_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn infinity() {
                 assert_eq!(
                     rsass(
@@ -1487,7 +1428,6 @@ mod base_positive_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn negative_infinity() {
                 assert_eq!(
                     rsass(
@@ -1519,7 +1459,6 @@ mod base_positive_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn odd_integer() {
                 assert_eq!(
                     rsass(
@@ -1535,7 +1474,6 @@ mod base_positive_zero {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn zero() {
                 assert_eq!(
                     rsass(
@@ -1556,7 +1494,6 @@ mod base_positive_zero {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn decimal() {
             assert_eq!(
                 rsass(
@@ -1572,7 +1509,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer() {
             assert_eq!(
                 rsass(
@@ -1588,7 +1524,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1604,7 +1539,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn infinity() {
             assert_eq!(
                 rsass(
@@ -1620,7 +1554,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_decimal() {
             assert_eq!(
                 rsass(
@@ -1636,7 +1569,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer() {
             assert_eq!(
                 rsass(
@@ -1652,7 +1584,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_even_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1668,7 +1599,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_infinity() {
             assert_eq!(
                 rsass(
@@ -1684,7 +1614,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_odd_integer() {
             assert_eq!(
                 rsass(
@@ -1700,7 +1629,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn negative_odd_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1716,7 +1644,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer() {
             assert_eq!(
                 rsass(
@@ -1732,7 +1659,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn odd_integer_fuzzy() {
             assert_eq!(
                 rsass(
@@ -1748,7 +1674,6 @@ mod base_positive_zero {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn zero() {
             assert_eq!(
                 rsass(
@@ -10,7 +10,6 @@ mod call {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn named() {
             assert_eq!(
         rsass(
@@ -57,7 +56,6 @@ mod call {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn combined() {
                 assert_eq!(
                     rsass(
@@ -74,7 +72,6 @@ mod call {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn named() {
                 assert_eq!(
                     rsass(
@@ -90,7 +87,6 @@ mod call {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn positional() {
                 assert_eq!(
                     rsass(
@@ -187,7 +183,6 @@ mod content_exists {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // unexepected error
         fn through_content() {
             assert_eq!(
                 rsass(
@@ -233,7 +228,6 @@ mod content_exists {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // unexepected error
         fn empty() {
             assert_eq!(
                 rsass(
@@ -252,7 +246,6 @@ mod content_exists {
             );
         }
         #[test]
-        #[ignore] // unexepected error
         fn non_empty() {
             assert_eq!(
                 rsass(
@@ -430,7 +423,6 @@ mod function_exists {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn chosen_prefix() {
             assert_eq!(
                 rsass(
@@ -446,7 +438,6 @@ mod function_exists {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn defined() {
             assert_eq!(
                 rsass(
@@ -558,7 +549,6 @@ mod function_exists {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn undefined() {
             assert_eq!(
                 rsass(
@@ -904,7 +894,6 @@ mod global_variable_exists {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn undefined() {
             assert_eq!(
                 rsass(
@@ -2241,7 +2230,6 @@ mod keywords {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn named() {
         assert_eq!(
             rsass(
@@ -2415,7 +2403,6 @@ mod mixin_exists {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn undefined() {
             assert_eq!(
                 rsass(
@@ -2576,7 +2563,6 @@ mod module_functions {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn core_module() {
         assert_eq!(
         rsass(
@@ -2831,7 +2817,6 @@ mod module_variables {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn core_module() {
         assert_eq!(
             rsass(
@@ -3038,7 +3023,6 @@ mod type_of {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn arglist() {
         assert_eq!(
             rsass(
@@ -3222,7 +3206,6 @@ mod type_of {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn unit() {
             assert_eq!(
                 rsass(
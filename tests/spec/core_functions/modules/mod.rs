@@ -7,7 +7,6 @@ mod color {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn adjust() {
         assert_eq!(
             rsass(
@@ -23,7 +22,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn alpha() {
         assert_eq!(
             rsass(
@@ -39,7 +37,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn blue() {
         assert_eq!(
             rsass(
@@ -55,7 +52,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn change() {
         assert_eq!(
             rsass(
@@ -71,7 +67,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn complement() {
         assert_eq!(
             rsass(
@@ -93,7 +88,6 @@ mod color {
             #[allow(unused)]
             use super::rsass;
             #[test]
-            #[ignore] // wrong result
             fn multi_arg() {
                 assert_eq!(
                     rsass(
@@ -109,7 +103,6 @@ mod color {
                 );
             }
             #[test]
-            #[ignore] // wrong result
             fn one_arg() {
                 assert_eq!(
                     rsass(
@@ -126,7 +119,6 @@ mod color {
             }
         }
         #[test]
-        #[ignore] // wrong result
         fn grayscale() {
             assert_eq!(
                 rsass(
@@ -142,7 +134,6 @@ mod color {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn invert() {
             assert_eq!(
                 rsass(
@@ -158,7 +149,6 @@ mod color {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn opacity() {
             assert_eq!(
                 rsass(
@@ -203,7 +193,6 @@ mod color {
         // Ignoring "transparentize", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn green() {
         assert_eq!(
             rsass(
@@ -219,7 +208,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn hue() {
         assert_eq!(
             rsass(
@@ -235,7 +223,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn ie_hex_str() {
         assert_eq!(
             rsass(
@@ -251,7 +238,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn invert() {
         assert_eq!(
             rsass(
@@ -283,7 +269,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn mix() {
         assert_eq!(
             rsass(
@@ -299,7 +284,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn red() {
         assert_eq!(
             rsass(
@@ -315,7 +299,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn saturation() {
         assert_eq!(
             rsass(
@@ -331,7 +314,6 @@ mod color {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn scale() {
         assert_eq!(
             rsass(
@@ -353,7 +335,6 @@ mod general {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn test_as() {
         assert_eq!(
             rsass(
@@ -451,7 +432,6 @@ mod general {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn global() {
         assert_eq!(
             rsass(
@@ -489,7 +469,6 @@ mod map {
         // Ignoring "map_values", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn get() {
         assert_eq!(
             rsass(
@@ -505,7 +484,6 @@ mod map {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn has_key() {
         assert_eq!(
             rsass(
@@ -537,7 +515,6 @@ mod map {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn merge() {
         assert_eq!(
             rsass(
@@ -554,7 +531,6 @@ mod map {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn remove() {
         assert_eq!(
             rsass(
@@ -593,7 +569,6 @@ mod math {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn abs() {
         assert_eq!(
             rsass(
@@ -609,7 +584,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn ceil() {
         assert_eq!(
             rsass(
@@ -625,7 +599,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn compatible() {
         assert_eq!(
             rsass(
@@ -649,7 +622,6 @@ mod math {
         // Ignoring "unitless", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn floor() {
         assert_eq!(
             rsass(
@@ -665,7 +637,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn is_unitless() {
         assert_eq!(
             rsass(
@@ -681,7 +652,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn max() {
         assert_eq!(
             rsass(
@@ -697,7 +667,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn min() {
         assert_eq!(
             rsass(
@@ -713,7 +682,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn percentage() {
         assert_eq!(
             rsass(
@@ -729,7 +697,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn random() {
         assert_eq!(
             rsass(
@@ -745,7 +712,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn round() {
         assert_eq!(
             rsass(
@@ -761,7 +727,6 @@ mod math {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn unit() {
         assert_eq!(
             rsass(
@@ -783,7 +748,6 @@ mod meta {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn call() {
         assert_eq!(
             rsass(
@@ -799,7 +763,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn content_exists() {
         assert_eq!(
             rsass(
@@ -820,7 +783,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn feature_exists() {
         assert_eq!(
             rsass(
@@ -836,7 +798,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn function_exists() {
         assert_eq!(
             rsass(
@@ -852,7 +813,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn get_function() {
         assert_eq!(
             rsass(
@@ -868,7 +828,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn global_variable_exists() {
         assert_eq!(
             rsass(
@@ -884,7 +843,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn inspect() {
         assert_eq!(
             rsass(
@@ -900,7 +858,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn keywords() {
         assert_eq!(
             rsass(
@@ -921,7 +878,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn mixin_exists() {
         assert_eq!(
             rsass(
@@ -937,7 +893,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn type_of() {
         assert_eq!(
             rsass(
@@ -953,7 +908,6 @@ mod meta {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn variable_exists() {
         assert_eq!(
             rsass(
@@ -975,7 +929,6 @@ mod selector {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn append() {
         assert_eq!(
             rsass(
@@ -1007,7 +960,6 @@ mod selector {
         // Ignoring "selector_unify", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn extend() {
         assert_eq!(
             rsass(
@@ -1023,7 +975,6 @@ mod selector {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn is_superselector() {
         assert_eq!(
             rsass(
@@ -1039,7 +990,6 @@ mod selector {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn nest() {
         assert_eq!(
             rsass(
@@ -1055,7 +1005,6 @@ mod selector {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn parse() {
         assert_eq!(
             rsass(
@@ -1071,7 +1020,6 @@ mod selector {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn replace() {
         assert_eq!(
             rsass(
@@ -1087,7 +1035,6 @@ mod selector {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn simple_selectors() {
         assert_eq!(
             rsass(
@@ -1103,7 +1050,6 @@ mod selector {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn unify() {
         assert_eq!(
             rsass(
@@ -1137,7 +1083,6 @@ mod string {
         // Ignoring "str_slice", error tests are not supported yet.
     }
     #[test]
-    #[ignore] // wrong result
     fn index() {
         assert_eq!(
             rsass(
@@ -1153,7 +1098,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn insert() {
         assert_eq!(
             rsass(
@@ -1169,7 +1113,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn length() {
         assert_eq!(
             rsass(
@@ -1185,7 +1128,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn quote() {
         assert_eq!(
             rsass(
@@ -1201,7 +1143,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn slice() {
         assert_eq!(
             rsass(
@@ -1217,7 +1158,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn to_upper_case() {
         assert_eq!(
             rsass(
@@ -1233,7 +1173,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn unique_id() {
         assert_eq!(
             rsass(
@@ -1250,7 +1189,6 @@ mod string {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn unquote() {
         assert_eq!(
             rsass(
@@ -596,7 +596,6 @@ mod replace {
     #[allow(unused)]
     use super::rsass;
     #[test]
-    #[ignore] // wrong result
     fn complex() {
         assert_eq!(
             rsass(
@@ -611,7 +610,6 @@ mod replace {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn compound() {
         assert_eq!(
             rsass(
@@ -681,7 +679,6 @@ mod replace {
                 #[allow(unused)]
                 use super::rsass;
                 #[test]
-                #[ignore] // wrong result
                 fn compound() {
                     assert_eq!(
         rsass(
@@ -696,7 +693,6 @@ mod replace {
     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn list() {
                     assert_eq!(
         rsass(
@@ -711,7 +707,6 @@ mod replace {
     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn list_of_compound() {
                     assert_eq!(
         rsass(
@@ -730,7 +725,6 @@ mod replace {
                 #[allow(unused)]
                 use super::rsass;
                 #[test]
-                #[ignore] // wrong result
                 fn extendee() {
                     assert_eq!(
         rsass(
@@ -745,7 +739,6 @@ mod replace {
     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn extender() {
                     assert_eq!(
                         rsass(
@@ -760,7 +753,6 @@ mod replace {
                     );
                 }
                 #[test]
-                #[ignore] // wrong result
                 fn selector() {
                     assert_eq!(
                         rsass(
@@ -798,7 +790,6 @@ mod replace {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn named() {
         assert_eq!(
         rsass(
@@ -813,7 +804,6 @@ mod replace {
     );
     }
     #[test]
-    #[ignore] // wrong result
     fn no_op() {
         assert_eq!(
             rsass(
@@ -828,7 +818,6 @@ mod replace {
         );
     }
     #[test]
-    #[ignore] // wrong result
     fn partial_no_op() {
         assert_eq!(
             rsass(
@@ -846,7 +835,6 @@ mod replace {
         #[allow(unused)]
         use super::rsass;
         #[test]
-        #[ignore] // wrong result
         fn matches() {
             assert_eq!(
                 rsass(
@@ -861,7 +849,6 @@ mod replace {
             );
         }
         #[test]
-        #[ignore] // wrong result
         fn not() {
             assert_eq!(
                 rsass(
@@ -877,7 +864,6 @@ mod replace {
         }
     }
     #[test]
-    #[ignore] // wrong result
     fn simple() {
         assert_eq!(
             rsass(
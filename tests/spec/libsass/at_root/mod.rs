@@ -266,7 +266,6 @@ fn basic() {
 
 // From "sass-spec/spec/libsass/at-root/extend.hrx"
 #[test]
-#[ignore] // wrong result
 fn extend() {
     assert_eq!(
         rsass(
@@ -288,6 +288,7 @@ fn bourbon() {
 
 // From "sass-spec/spec/libsass/calc.hrx"
 #[test]
+#[ignore] // wrong result
 fn calc() {
     assert_eq!(
         rsass(
@@ -337,7 +338,6 @@ mod color_functions;
 
 // From "sass-spec/spec/libsass/conversions.hrx"
 #[test]
-#[ignore] // wrong result
 fn conversions() {
     assert_eq!(
         rsass(
@@ -541,7 +541,6 @@ fn div() {
 
 // From "sass-spec/spec/libsass/env.hrx"
 #[test]
-#[ignore] // wrong result
 fn env() {
     assert_eq!(
         rsass(
@@ -716,7 +715,6 @@ fn import() {
 
 // From "sass-spec/spec/libsass/inh.hrx"
 #[test]
-#[ignore] // wrong result
 fn inh() {
     assert_eq!(
         rsass(
@@ -1257,7 +1255,6 @@ fn media() {
 
 // From "sass-spec/spec/libsass/mixin.hrx"
 #[test]
-#[ignore] // wrong result
 fn mixin() {
     assert_eq!(
         rsass(
@@ -1540,7 +1537,6 @@ mod parent_selector;
 
 // From "sass-spec/spec/libsass/placeholder-mediaquery.hrx"
 #[test]
-#[ignore] // wrong result
 fn placeholder_mediaquery() {
     assert_eq!(
         rsass(
@@ -1567,7 +1563,6 @@ fn placeholder_mediaquery() {
 
 // From "sass-spec/spec/libsass/placeholder-nested.hrx"
 #[test]
-#[ignore] // unexepected error
 fn placeholder_nested() {
     assert_eq!(
         rsass(
@@ -117,7 +117,6 @@ fn angle() {
 
 // From "sass-spec/spec/libsass/units/conversion/frequency.hrx"
 #[test]
-#[ignore] // wrong result
 fn frequency() {
     assert_eq!(
         rsass(
@@ -462,7 +461,6 @@ fn size() {
 
 // From "sass-spec/spec/libsass/units/conversion/time.hrx"
 #[test]
-#[ignore] // wrong result
 fn time() {
     assert_eq!(
         rsass(
@@ -58,7 +58,6 @@ fn at_root() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/expanding/each.hrx"
 #[test]
-#[ignore] // wrong result
 fn each() {
     assert_eq!(
         rsass(
@@ -118,7 +117,6 @@ fn each() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/expanding/else.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_else() {
     assert_eq!(
         rsass(
@@ -178,7 +176,6 @@ fn test_else() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/expanding/elseif.hrx"
 #[test]
-#[ignore] // wrong result
 fn elseif() {
     assert_eq!(
         rsass(
@@ -238,7 +235,6 @@ fn elseif() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/expanding/for.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_for() {
     assert_eq!(
         rsass(
@@ -366,7 +362,6 @@ fn function() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/expanding/if.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_if() {
     assert_eq!(
         rsass(
@@ -530,7 +525,6 @@ fn ruleset() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/expanding/while.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_while() {
     assert_eq!(
         rsass(
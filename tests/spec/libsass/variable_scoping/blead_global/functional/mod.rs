@@ -4,7 +4,6 @@ use super::rsass;
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/functional/each.hrx"
 #[test]
-#[ignore] // wrong result
 fn each() {
     assert_eq!(
         rsass(
@@ -83,7 +82,6 @@ fn each() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/functional/else.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_else() {
     assert_eq!(
         rsass(
@@ -168,7 +166,6 @@ fn test_else() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/functional/elseif.hrx"
 #[test]
-#[ignore] // wrong result
 fn elseif() {
     assert_eq!(
         rsass(
@@ -253,7 +250,6 @@ fn elseif() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/functional/for.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_for() {
     assert_eq!(
         rsass(
@@ -332,7 +328,6 @@ fn test_for() {
 
 // From "sass-spec/spec/libsass/variable-scoping/blead-global/functional/if.hrx"
 #[test]
-#[ignore] // wrong result
 fn test_if() {
     assert_eq!(
         rsass(
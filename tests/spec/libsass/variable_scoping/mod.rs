@@ -126,7 +126,6 @@ fn defaults_null() {
 
 // From "sass-spec/spec/libsass/variable-scoping/defaults.hrx"
 #[test]
-#[ignore] // wrong result
 fn defaults() {
     assert_eq!(
         rsass(
@@ -210,7 +209,6 @@ fn feature_test() {
 
 // From "sass-spec/spec/libsass/variable-scoping/lexical-scope.hrx"
 #[test]
-#[ignore] // wrong result
 fn lexical_scope() {
     assert_eq!(
         rsass(
@@ -262,7 +260,6 @@ fn lexical_scope() {
 
 // From "sass-spec/spec/libsass/variable-scoping/root-scope.hrx"
 #[test]
-#[ignore] // wrong result
 fn root_scope() {
     assert_eq!(
         rsass(
@@ -175,7 +175,6 @@ fn basic() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1210/extend.hrx"
 #[test]
-#[ignore] // wrong result
 fn extend() {
     assert_eq!(
         rsass(
@@ -4,7 +4,6 @@ use super::rsass;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2031/extended-not.hrx"
 #[test]
-#[ignore] // wrong result
 fn extended_not() {
     assert_eq!(
         rsass(
@@ -4,7 +4,6 @@ use super::rsass;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2366/global.hrx"
 #[test]
-#[ignore] // wrong result
 fn global() {
     assert_eq!(
         rsass(
@@ -44,7 +43,6 @@ fn global() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2366/has.hrx"
 #[test]
-#[ignore] // wrong result
 fn has() {
     assert_eq!(
         rsass(
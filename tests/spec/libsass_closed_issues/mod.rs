@@ -35,7 +35,6 @@ fn issue_2640() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue-2681.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2681() {
     assert_eq!(
         rsass(
@@ -395,7 +394,6 @@ fn issue_1061() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1063.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1063() {
     assert_eq!(
         rsass(
@@ -448,7 +446,6 @@ fn issue_1074() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1075.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_1075() {
     assert_eq!(
         rsass(
@@ -1515,7 +1512,6 @@ fn issue_1163() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1167.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1167() {
     assert_eq!(
         rsass(
@@ -1558,7 +1554,6 @@ mod issue_1170;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1171.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1171() {
     assert_eq!(
         rsass(
@@ -1967,7 +1962,6 @@ fn issue_1233() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1240.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1240() {
     assert_eq!(
         rsass(
@@ -2001,7 +1995,6 @@ mod issue_1243;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1248.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1248() {
     assert_eq!(
         rsass(
@@ -2078,7 +2071,6 @@ fn issue_1253() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1255.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1255() {
     assert_eq!(
         rsass(
@@ -2513,7 +2505,6 @@ fn issue_1295() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1297.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1297() {
     assert_eq!(
         rsass(
@@ -2575,7 +2566,6 @@ fn issue_1301() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1303.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1303() {
     assert_eq!(
         rsass(
@@ -2727,7 +2717,6 @@ fn issue_1328() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1331.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1331() {
     assert_eq!(
         rsass(
@@ -2803,7 +2792,6 @@ fn issue_1333() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1336.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1336() {
     assert_eq!(
         rsass(
@@ -2821,7 +2809,6 @@ fn issue_1336() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_137.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_137() {
     assert_eq!(
         rsass(
@@ -3547,7 +3534,6 @@ fn issue_1459() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1482.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1482() {
     assert_eq!(
         rsass(
@@ -3690,7 +3676,6 @@ fn issue_1486() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1488.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1488() {
     assert_eq!(
         rsass(
@@ -3859,7 +3844,6 @@ fn issue_1557() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1566.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1566() {
     assert_eq!(
         rsass(
@@ -4008,7 +3992,6 @@ fn issue_1574() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1579.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1579() {
     assert_eq!(
         rsass(
@@ -4306,7 +4289,6 @@ fn issue_1612() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1622.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1622() {
     assert_eq!(
         rsass(
@@ -4425,7 +4407,6 @@ fn issue_1634() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1640.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_1640() {
     assert_eq!(
         rsass(
@@ -4456,7 +4437,6 @@ mod issue_1644;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1645.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1645() {
     assert_eq!(
         rsass(
@@ -4662,7 +4642,6 @@ fn issue_1669() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_167.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_167() {
     assert_eq!(
         rsass(
@@ -4735,7 +4714,6 @@ fn issue_1672() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1673.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1673() {
     assert_eq!(
         rsass(
@@ -4811,7 +4789,6 @@ fn issue_1706() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1709.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1709() {
     assert_eq!(
         rsass(
@@ -4991,7 +4968,6 @@ fn issue_1726() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1729.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1729() {
     assert_eq!(
         rsass(
@@ -5163,7 +5139,6 @@ fn issue_1766() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1768.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_1768() {
     assert_eq!(
         rsass(
@@ -5453,7 +5428,6 @@ fn issue_1813() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1819.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1819() {
     assert_eq!(
         rsass(
@@ -5671,7 +5645,6 @@ fn issue_1907() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1915.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1915() {
     assert_eq!(
         rsass(
@@ -5736,7 +5709,6 @@ fn issue_192() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1927.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_1927() {
     assert_eq!(
         rsass(
@@ -5893,7 +5865,6 @@ fn issue_1947() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1960.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1960() {
     assert_eq!(
         rsass(
@@ -5945,7 +5916,6 @@ fn issue_1969() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1971.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1971() {
     assert_eq!(
         rsass(
@@ -6025,7 +5995,6 @@ fn issue_1991() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1993.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_1993() {
     assert_eq!(
         rsass(
@@ -6040,7 +6009,6 @@ fn issue_1993() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1994.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1994() {
     assert_eq!(
         rsass(
@@ -6074,7 +6042,6 @@ fn issue_1994() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_1996.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_1996() {
     assert_eq!(
         rsass(
@@ -6230,7 +6197,6 @@ fn issue_201() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2017.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2017() {
     assert_eq!(
         rsass(
@@ -6338,7 +6304,6 @@ fn issue_2042() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2053.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2053() {
     assert_eq!(
         rsass(
@@ -6642,7 +6607,6 @@ fn issue_2124() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2139.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2139() {
     assert_eq!(
         rsass(
@@ -6789,7 +6753,6 @@ fn issue_2149() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2150.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2150() {
     assert_eq!(
         rsass(
@@ -7114,7 +7077,6 @@ fn issue_2198() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2200.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2200() {
     assert_eq!(
         rsass(
@@ -7163,7 +7125,6 @@ fn issue_2202() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2205.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2205() {
     assert_eq!(
         rsass(
@@ -7262,7 +7223,6 @@ mod issue_2243;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2246.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2246() {
     assert_eq!(
         rsass(
@@ -7373,7 +7333,6 @@ fn issue_2261() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2289.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2289() {
     assert_eq!(
         rsass(
@@ -7396,7 +7355,6 @@ fn issue_2289() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2291.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2291() {
     assert_eq!(
         rsass(
@@ -7717,7 +7675,6 @@ fn issue_2346() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2347.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_2347() {
     assert_eq!(
         rsass(
@@ -7852,7 +7809,6 @@ mod issue_2366;
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2374.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2374() {
     assert_eq!(
         rsass(
@@ -7970,6 +7926,7 @@ fn issue_2376() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2382.hrx"
 #[test]
+#[ignore] // wrong result
 fn issue_2382() {
     assert_eq!(
         rsass(
@@ -8081,7 +8038,6 @@ fn issue_239() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2394.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_2394() {
     assert_eq!(
         rsass(
@@ -8244,6 +8200,7 @@ fn issue_2452() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_246.hrx"
 #[test]
+#[ignore] // wrong result
 fn issue_246() {
     assert_eq!(
         rsass(
@@ -8376,7 +8333,6 @@ fn issue_2467() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2468.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2468() {
     assert_eq!(
         rsass(
@@ -8418,7 +8374,6 @@ fn issue_2468() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2472.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_2472() {
     assert_eq!(
         rsass(
@@ -8459,7 +8414,6 @@ fn issue_2472() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2480.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2480() {
     assert_eq!(
         rsass(
@@ -9128,7 +9082,6 @@ fn issue_2808() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2884.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2884() {
     assert_eq!(
         rsass(
@@ -9351,7 +9304,6 @@ fn issue_2980() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_2994.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_2994() {
     assert_eq!(
         rsass(
@@ -10628,7 +10580,6 @@ fn issue_578() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_579.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_579() {
     assert_eq!(
         rsass(
@@ -10725,7 +10676,6 @@ fn issue_590() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_592.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_592() {
     assert_eq!(
         rsass(
@@ -11039,7 +10989,6 @@ fn issue_614() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_615.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_615() {
     assert_eq!(
         rsass(
@@ -11991,7 +11940,6 @@ fn issue_748() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_759.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_759() {
     assert_eq!(
         rsass(
@@ -12097,7 +12045,6 @@ fn issue_77() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_783"
 #[test]
-#[ignore] // wrong result
 fn issue_783() {
     assert_eq!(
         rsass(
@@ -12282,7 +12229,6 @@ fn issue_803() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_813.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_813() {
     assert_eq!(
         rsass(
@@ -12379,7 +12325,6 @@ fn issue_820() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_823.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_823() {
     assert_eq!(
         rsass(
@@ -13083,7 +13028,6 @@ fn issue_942() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_943.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_943() {
     assert_eq!(
         rsass(
@@ -13154,7 +13098,6 @@ fn issue_948() {
 
 // From "sass-spec/spec/libsass-closed-issues/issue_950.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_950() {
     assert_eq!(
         rsass(
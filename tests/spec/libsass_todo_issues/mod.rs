@@ -139,7 +139,6 @@ mod issue_2295;
 
 // From "sass-spec/spec/libsass-todo-issues/issue_2818.hrx"
 #[test]
-#[ignore] // unexepected error
 fn issue_2818() {
     assert_eq!(
         rsass(
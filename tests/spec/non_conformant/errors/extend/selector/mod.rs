@@ -8,7 +8,6 @@ use super::rsass;
 
 // From "sass-spec/spec/non_conformant/errors/extend/selector/optional.hrx"
 #[test]
-#[ignore] // wrong result
 fn optional() {
     assert_eq!(
         rsass(
@@ -28,7 +27,6 @@ fn optional() {
 
 // From "sass-spec/spec/non_conformant/errors/extend/selector/simple.hrx"
 #[test]
-#[ignore] // wrong result
 fn simple() {
     assert_eq!(
         rsass(
@@ -8,7 +8,6 @@ use super::rsass;
 
 // From "sass-spec/spec/non_conformant/errors/fn-debug/ruleset.hrx"
 #[test]
-#[ignore] // wrong result
 fn ruleset() {
     assert_eq!(
         rsass(
@@ -27,7 +26,6 @@ fn ruleset() {
 
 // From "sass-spec/spec/non_conformant/errors/fn-debug/simple.hrx"
 #[test]
-#[ignore] // wrong result
 fn simple() {
     assert_eq!(rsass("@debug \"debug\";").unwrap(), "");
 }
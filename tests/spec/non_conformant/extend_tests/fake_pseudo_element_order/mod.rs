@@ -4,7 +4,6 @@ use super::rsass;
 
 // From "sass-spec/spec/non_conformant/extend-tests/fake-pseudo-element-order/after.hrx"
 #[test]
-#[ignore] // unexepected error
 fn after() {
     assert_eq!(
         rsass(
@@ -22,7 +21,6 @@ fn after() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/fake-pseudo-element-order/before.hrx"
 #[test]
-#[ignore] // unexepected error
 fn before() {
     assert_eq!(
         rsass(
@@ -40,7 +38,6 @@ fn before() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/fake-pseudo-element-order/first-letter.hrx"
 #[test]
-#[ignore] // unexepected error
 fn first_letter() {
     assert_eq!(
         rsass(
@@ -58,7 +55,6 @@ fn first_letter() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/fake-pseudo-element-order/first-line.hrx"
 #[test]
-#[ignore] // unexepected error
 fn first_line() {
     assert_eq!(
         rsass(
@@ -4,7 +4,6 @@ use super::rsass;
 
 // From "sass-spec/spec/non_conformant/extend-tests/001_test_basic.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t001_test_basic() {
     assert_eq!(
         rsass(
@@ -22,7 +21,6 @@ fn t001_test_basic() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/002_test_basic.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t002_test_basic() {
     assert_eq!(
         rsass(
@@ -40,7 +38,6 @@ fn t002_test_basic() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/003_test_basic.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t003_test_basic() {
     assert_eq!(
         rsass(
@@ -61,7 +58,6 @@ fn t003_test_basic() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/004_test_basic.hrx"
 #[test]
-#[ignore] // wrong result
 fn t004_test_basic() {
     assert_eq!(
         rsass(
@@ -82,7 +78,6 @@ fn t004_test_basic() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/005_test_multiple_targets.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t005_test_multiple_targets() {
     assert_eq!(
         rsass(
@@ -104,7 +99,6 @@ fn t005_test_multiple_targets() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/006_test_multiple_extendees.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t006_test_multiple_extendees() {
     assert_eq!(
         rsass(
@@ -126,7 +120,6 @@ fn t006_test_multiple_extendees() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/007_test_multiple_extends_with_single_extender_and_single_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t007_test_multiple_extends_with_single_extender_and_single_target() {
     assert_eq!(
         rsass(
@@ -144,7 +137,6 @@ fn t007_test_multiple_extends_with_single_extender_and_single_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/008_test_multiple_extends_with_single_extender_and_single_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t008_test_multiple_extends_with_single_extender_and_single_target() {
     assert_eq!(
         rsass(
@@ -162,7 +154,6 @@ fn t008_test_multiple_extends_with_single_extender_and_single_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/009_test_multiple_extends_with_multiple_extenders_and_single_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t009_test_multiple_extends_with_multiple_extenders_and_single_target() {
     assert_eq!(
         rsass(
@@ -181,7 +172,6 @@ fn t009_test_multiple_extends_with_multiple_extenders_and_single_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/010_test_multiple_extends_with_multiple_extenders_and_single_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t010_test_multiple_extends_with_multiple_extenders_and_single_target() {
     assert_eq!(
         rsass(
@@ -200,7 +190,6 @@ fn t010_test_multiple_extends_with_multiple_extenders_and_single_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/011_test_chained_extends.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t011_test_chained_extends() {
     assert_eq!(
         rsass(
@@ -220,7 +209,6 @@ fn t011_test_chained_extends() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/012_test_dynamic_extendee.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t012_test_dynamic_extendee() {
     assert_eq!(
         rsass(
@@ -238,7 +226,6 @@ fn t012_test_dynamic_extendee() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/013_test_dynamic_extendee.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t013_test_dynamic_extendee() {
     assert_eq!(
         rsass(
@@ -256,7 +243,6 @@ fn t013_test_dynamic_extendee() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/014_test_nested_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t014_test_nested_target() {
     assert_eq!(
         rsass(
@@ -274,7 +260,6 @@ fn t014_test_nested_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/015_test_target_with_child.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t015_test_target_with_child() {
     assert_eq!(
         rsass(
@@ -292,7 +277,6 @@ fn t015_test_target_with_child() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/016_test_class_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t016_test_class_unification() {
     assert_eq!(
         rsass(
@@ -310,7 +294,6 @@ fn t016_test_class_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/017_test_class_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t017_test_class_unification() {
     assert_eq!(
         rsass(
@@ -328,7 +311,6 @@ fn t017_test_class_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/018_test_id_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t018_test_id_unification() {
     assert_eq!(
         rsass(
@@ -346,7 +328,6 @@ fn t018_test_id_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/019_test_id_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t019_test_id_unification() {
     assert_eq!(
         rsass(
@@ -364,7 +345,6 @@ fn t019_test_id_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/020_test_universal_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t020_test_universal_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -382,7 +362,6 @@ fn t020_test_universal_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/021_test_universal_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t021_test_universal_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -400,7 +379,6 @@ fn t021_test_universal_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/022_test_universal_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t022_test_universal_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -418,7 +396,6 @@ fn t022_test_universal_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/023_test_universal_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t023_test_universal_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -436,7 +413,6 @@ fn t023_test_universal_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/024_test_universal_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t024_test_universal_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -454,7 +430,6 @@ fn t024_test_universal_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/025_test_universal_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t025_test_universal_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -472,7 +447,6 @@ fn t025_test_universal_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/026_test_universal_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t026_test_universal_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -490,7 +464,6 @@ fn t026_test_universal_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/027_test_universal_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t027_test_universal_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -508,7 +481,6 @@ fn t027_test_universal_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/028_test_universal_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t028_test_universal_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -526,7 +498,6 @@ fn t028_test_universal_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/029_test_universal_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t029_test_universal_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -544,7 +515,6 @@ fn t029_test_universal_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/030_test_universal_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t030_test_universal_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -562,7 +532,6 @@ fn t030_test_universal_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/031_test_universal_unification_with_namespaced_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t031_test_universal_unification_with_namespaced_universal_target() {
     assert_eq!(
         rsass(
@@ -580,7 +549,6 @@ fn t031_test_universal_unification_with_namespaced_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/032_test_universal_unification_with_namespaced_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t032_test_universal_unification_with_namespaced_universal_target() {
     assert_eq!(
         rsass(
@@ -598,7 +566,6 @@ fn t032_test_universal_unification_with_namespaced_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/033_test_universal_unification_with_namespaced_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t033_test_universal_unification_with_namespaced_universal_target() {
     assert_eq!(
         rsass(
@@ -616,7 +583,6 @@ fn t033_test_universal_unification_with_namespaced_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/034_test_universal_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t034_test_universal_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -634,7 +600,6 @@ fn t034_test_universal_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/035_test_universal_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t035_test_universal_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -652,7 +617,6 @@ fn t035_test_universal_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/036_test_universal_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t036_test_universal_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -670,7 +634,6 @@ fn t036_test_universal_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/037_test_universal_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t037_test_universal_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -688,7 +651,6 @@ fn t037_test_universal_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/038_test_universal_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t038_test_universal_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -706,7 +668,6 @@ fn t038_test_universal_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/039_test_universal_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t039_test_universal_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -724,7 +685,6 @@ fn t039_test_universal_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/040_test_universal_unification_with_namespaced_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t040_test_universal_unification_with_namespaced_element_target() {
     assert_eq!(
         rsass(
@@ -742,7 +702,6 @@ fn t040_test_universal_unification_with_namespaced_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/041_test_universal_unification_with_namespaced_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t041_test_universal_unification_with_namespaced_element_target() {
     assert_eq!(
         rsass(
@@ -760,7 +719,6 @@ fn t041_test_universal_unification_with_namespaced_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/042_test_universal_unification_with_namespaced_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t042_test_universal_unification_with_namespaced_element_target() {
     assert_eq!(
         rsass(
@@ -778,7 +736,6 @@ fn t042_test_universal_unification_with_namespaced_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/043_test_element_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t043_test_element_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -796,7 +753,6 @@ fn t043_test_element_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/044_test_element_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t044_test_element_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -814,7 +770,6 @@ fn t044_test_element_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/045_test_element_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t045_test_element_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -832,7 +787,6 @@ fn t045_test_element_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/046_test_element_unification_with_simple_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t046_test_element_unification_with_simple_target() {
     assert_eq!(
         rsass(
@@ -850,7 +804,6 @@ fn t046_test_element_unification_with_simple_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/047_test_element_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t047_test_element_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -868,7 +821,6 @@ fn t047_test_element_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/048_test_element_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t048_test_element_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -886,7 +838,6 @@ fn t048_test_element_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/049_test_element_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t049_test_element_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -904,7 +855,6 @@ fn t049_test_element_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/050_test_element_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t050_test_element_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -922,7 +872,6 @@ fn t050_test_element_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/051_test_element_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t051_test_element_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -940,7 +889,6 @@ fn t051_test_element_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/052_test_element_unification_with_namespaceless_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t052_test_element_unification_with_namespaceless_universal_target() {
     assert_eq!(
         rsass(
@@ -958,7 +906,6 @@ fn t052_test_element_unification_with_namespaceless_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/053_test_element_unification_with_namespaced_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t053_test_element_unification_with_namespaced_universal_target() {
     assert_eq!(
         rsass(
@@ -976,7 +923,6 @@ fn t053_test_element_unification_with_namespaced_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/054_test_element_unification_with_namespaced_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t054_test_element_unification_with_namespaced_universal_target() {
     assert_eq!(
         rsass(
@@ -994,7 +940,6 @@ fn t054_test_element_unification_with_namespaced_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/055_test_element_unification_with_namespaced_universal_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t055_test_element_unification_with_namespaced_universal_target() {
     assert_eq!(
         rsass(
@@ -1012,7 +957,6 @@ fn t055_test_element_unification_with_namespaced_universal_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/056_test_element_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t056_test_element_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -1030,7 +974,6 @@ fn t056_test_element_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/057_test_element_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t057_test_element_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -1048,7 +991,6 @@ fn t057_test_element_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/058_test_element_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t058_test_element_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -1066,7 +1008,6 @@ fn t058_test_element_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/059_test_element_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t059_test_element_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -1084,7 +1025,6 @@ fn t059_test_element_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/060_test_element_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t060_test_element_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -1102,7 +1042,6 @@ fn t060_test_element_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/061_test_element_unification_with_namespaceless_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t061_test_element_unification_with_namespaceless_element_target() {
     assert_eq!(
         rsass(
@@ -1120,7 +1059,6 @@ fn t061_test_element_unification_with_namespaceless_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/062_test_element_unification_with_namespaced_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t062_test_element_unification_with_namespaced_element_target() {
     assert_eq!(
         rsass(
@@ -1138,7 +1076,6 @@ fn t062_test_element_unification_with_namespaced_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/063_test_element_unification_with_namespaced_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t063_test_element_unification_with_namespaced_element_target() {
     assert_eq!(
         rsass(
@@ -1156,7 +1093,6 @@ fn t063_test_element_unification_with_namespaced_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/064_test_element_unification_with_namespaced_element_target.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t064_test_element_unification_with_namespaced_element_target() {
     assert_eq!(
         rsass(
@@ -1174,7 +1110,6 @@ fn t064_test_element_unification_with_namespaced_element_target() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/065_test_attribute_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t065_test_attribute_unification() {
     assert_eq!(
         rsass(
@@ -1192,7 +1127,6 @@ fn t065_test_attribute_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/066_test_attribute_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t066_test_attribute_unification() {
     assert_eq!(
         rsass(
@@ -1210,7 +1144,6 @@ fn t066_test_attribute_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/067_test_attribute_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t067_test_attribute_unification() {
     assert_eq!(
         rsass(
@@ -1228,7 +1161,6 @@ fn t067_test_attribute_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/068_test_attribute_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t068_test_attribute_unification() {
     assert_eq!(
         rsass(
@@ -1246,7 +1178,6 @@ fn t068_test_attribute_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/069_test_attribute_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t069_test_attribute_unification() {
     assert_eq!(
         rsass(
@@ -1282,7 +1213,6 @@ fn t070_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/071_test_pseudo_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t071_test_pseudo_unification() {
     assert_eq!(
         rsass(
@@ -1300,7 +1230,6 @@ fn t071_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/072_test_pseudo_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t072_test_pseudo_unification() {
     assert_eq!(
         rsass(
@@ -1336,7 +1265,6 @@ fn t073_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/074_test_pseudo_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t074_test_pseudo_unification() {
     assert_eq!(
         rsass(
@@ -1354,7 +1282,6 @@ fn t074_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/075_test_pseudo_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t075_test_pseudo_unification() {
     assert_eq!(
         rsass(
@@ -1372,7 +1299,6 @@ fn t075_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/076_test_pseudo_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t076_test_pseudo_unification() {
     assert_eq!(
         rsass(
@@ -1390,7 +1316,6 @@ fn t076_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/077_test_pseudo_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t077_test_pseudo_unification() {
     assert_eq!(
         rsass(
@@ -1408,7 +1333,6 @@ fn t077_test_pseudo_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/078_test_pseudoelement_remains_at_end_of_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t078_test_pseudoelement_remains_at_end_of_selector() {
     assert_eq!(
         rsass(
@@ -1426,7 +1350,6 @@ fn t078_test_pseudoelement_remains_at_end_of_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/079_test_pseudoelement_remains_at_end_of_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t079_test_pseudoelement_remains_at_end_of_selector() {
     assert_eq!(
         rsass(
@@ -1444,7 +1367,6 @@ fn t079_test_pseudoelement_remains_at_end_of_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/080_test_pseudoclass_remains_at_end_of_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t080_test_pseudoclass_remains_at_end_of_selector() {
     assert_eq!(
         rsass(
@@ -1462,7 +1384,6 @@ fn t080_test_pseudoclass_remains_at_end_of_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/081_test_pseudoclass_remains_at_end_of_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t081_test_pseudoclass_remains_at_end_of_selector() {
     assert_eq!(
         rsass(
@@ -1480,7 +1401,6 @@ fn t081_test_pseudoclass_remains_at_end_of_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/082_test_not_remains_at_end_of_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t082_test_not_remains_at_end_of_selector() {
     assert_eq!(
         rsass(
@@ -1498,7 +1418,6 @@ fn t082_test_not_remains_at_end_of_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/083_test_pseudoelement_goes_lefter_than_pseudoclass.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t083_test_pseudoelement_goes_lefter_than_pseudoclass() {
     assert_eq!(
         rsass(
@@ -1516,7 +1435,6 @@ fn t083_test_pseudoelement_goes_lefter_than_pseudoclass() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/084_test_pseudoelement_goes_lefter_than_pseudoclass.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t084_test_pseudoelement_goes_lefter_than_pseudoclass() {
     assert_eq!(
         rsass(
@@ -1534,7 +1452,6 @@ fn t084_test_pseudoelement_goes_lefter_than_pseudoclass() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/085_test_pseudoelement_goes_lefter_than_not.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t085_test_pseudoelement_goes_lefter_than_not() {
     assert_eq!(
         rsass(
@@ -1579,7 +1496,6 @@ fn t086_1_test_pseudoelement_goes_lefter_than_not() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/086_test_pseudoelement_goes_lefter_than_not.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t086_test_pseudoelement_goes_lefter_than_not() {
     assert_eq!(
         rsass(
@@ -1597,7 +1513,6 @@ fn t086_test_pseudoelement_goes_lefter_than_not() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/087_test_negation_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t087_test_negation_unification() {
     assert_eq!(
         rsass(
@@ -1615,7 +1530,6 @@ fn t087_test_negation_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/088_test_negation_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t088_test_negation_unification() {
     assert_eq!(
         rsass(
@@ -1633,7 +1547,6 @@ fn t088_test_negation_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/089_test_negation_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t089_test_negation_unification() {
     assert_eq!(
         rsass(
@@ -1651,7 +1564,6 @@ fn t089_test_negation_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/090_test_comma_extendee.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t090_test_comma_extendee() {
     assert_eq!(
         rsass(
@@ -1673,7 +1585,6 @@ fn t090_test_comma_extendee() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/091_test_redundant_selector_elimination.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t091_test_redundant_selector_elimination() {
     assert_eq!(
         rsass(
@@ -1696,7 +1607,6 @@ fn t091_test_redundant_selector_elimination() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/095_test_long_extender.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t095_test_long_extender() {
     assert_eq!(
         rsass(
@@ -1714,7 +1624,6 @@ fn t095_test_long_extender() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/096_test_long_extender_runs_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t096_test_long_extender_runs_unification() {
     assert_eq!(
         rsass(
@@ -1732,7 +1641,6 @@ fn t096_test_long_extender_runs_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/097_test_nested_extender.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t097_test_nested_extender() {
     assert_eq!(
         rsass(
@@ -1750,7 +1658,6 @@ fn t097_test_nested_extender() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/098_test_nested_extender_runs_unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t098_test_nested_extender_runs_unification() {
     assert_eq!(
         rsass(
@@ -1768,7 +1675,6 @@ fn t098_test_nested_extender_runs_unification() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/099_test_nested_extender_alternates_parents.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t099_test_nested_extender_alternates_parents() {
     assert_eq!(
         rsass(
@@ -1786,7 +1692,6 @@ fn t099_test_nested_extender_alternates_parents() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/100_test_nested_extender_unifies_identical_parents.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t100_test_nested_extender_unifies_identical_parents() {
     assert_eq!(
         rsass(
@@ -1804,7 +1709,6 @@ fn t100_test_nested_extender_unifies_identical_parents() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/101_test_nested_extender_unifies_common_substring.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t101_test_nested_extender_unifies_common_substring() {
     assert_eq!(
         rsass(
@@ -1822,7 +1726,6 @@ fn t101_test_nested_extender_unifies_common_substring() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/102_test_nested_extender_unifies_common_subseq.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t102_test_nested_extender_unifies_common_subseq() {
     assert_eq!(
         rsass(
@@ -1840,7 +1743,6 @@ fn t102_test_nested_extender_unifies_common_subseq() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/103_test_nested_extender_chooses_first_subseq.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t103_test_nested_extender_chooses_first_subseq() {
     assert_eq!(
         rsass(
@@ -1878,7 +1780,6 @@ fn t105_test_nested_extender_counts_extended_superselectors() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/106_test_nested_extender_with_child_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t106_test_nested_extender_with_child_selector() {
     assert_eq!(
         rsass(
@@ -1932,7 +1833,6 @@ fn t108_test_nested_extender_finds_common_selectors_around_child_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/109_test_nested_extender_finds_common_selectors_around_adjacent_sibling.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t109_test_nested_extender_finds_common_selectors_around_adjacent_sibling()
 {
     assert_eq!(
@@ -1989,7 +1889,6 @@ fn t111_test_nested_extender_finds_common_selectors_around_adjacent_sibling()
 
 // From "sass-spec/spec/non_conformant/extend-tests/112_test_nested_extender_finds_common_selectors_around_sibling_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t112_test_nested_extender_finds_common_selectors_around_sibling_selector()
 {
     assert_eq!(
@@ -2046,7 +1945,6 @@ fn t114_test_nested_extender_finds_common_selectors_around_sibling_selector()
 
 // From "sass-spec/spec/non_conformant/extend-tests/118_test_nested_extender_with_early_child_selectors_doesnt_subseq_them.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t118_test_nested_extender_with_early_child_selectors_doesnt_subseq_them() {
     assert_eq!(
         rsass(
@@ -2064,7 +1962,6 @@ fn t118_test_nested_extender_with_early_child_selectors_doesnt_subseq_them() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/119_test_nested_extender_with_early_child_selectors_doesnt_subseq_them.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t119_test_nested_extender_with_early_child_selectors_doesnt_subseq_them() {
     assert_eq!(
         rsass(
@@ -2082,7 +1979,6 @@ fn t119_test_nested_extender_with_early_child_selectors_doesnt_subseq_them() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/120_test_nested_extender_with_child_selector_unifies.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t120_test_nested_extender_with_child_selector_unifies() {
     assert_eq!(
         rsass(
@@ -2100,7 +1996,6 @@ fn t120_test_nested_extender_with_child_selector_unifies() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/121_test_nested_extender_with_child_selector_unifies.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t121_test_nested_extender_with_child_selector_unifies() {
     assert_eq!(
         rsass(
@@ -2140,7 +2035,6 @@ fn t122_test_nested_extender_with_child_selector_unifies() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/123_test_nested_extender_with_early_child_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t123_test_nested_extender_with_early_child_selector() {
     assert_eq!(
         rsass(
@@ -2160,7 +2054,6 @@ fn t123_test_nested_extender_with_early_child_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/124_test_nested_extender_with_early_child_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t124_test_nested_extender_with_early_child_selector() {
     assert_eq!(
         rsass(
@@ -2216,7 +2109,6 @@ fn t126_test_nested_extender_with_early_child_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/127_test_nested_extender_with_early_child_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t127_test_nested_extender_with_early_child_selector() {
     assert_eq!(
         rsass(
@@ -2234,7 +2126,6 @@ fn t127_test_nested_extender_with_early_child_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/128_test_nested_extender_with_sibling_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t128_test_nested_extender_with_sibling_selector() {
     assert_eq!(
         rsass(
@@ -2288,7 +2179,6 @@ fn t130_test_nested_extender_with_hacky_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/131_test_nested_extender_merges_with_same_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t131_test_nested_extender_merges_with_same_selector() {
     assert_eq!(
         rsass(
@@ -2307,7 +2197,6 @@ fn t131_test_nested_extender_merges_with_same_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/132_test_nested_extender_with_child_selector_merges_with_same_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t132_test_nested_extender_with_child_selector_merges_with_same_selector() {
     assert_eq!(
         rsass(
@@ -2397,7 +2286,6 @@ fn t136_test_combinator_unification_for_hacky_combinators() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/137_test_combinator_unification_for_hacky_combinators.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t137_test_combinator_unification_for_hacky_combinators() {
     assert_eq!(
         rsass(
@@ -2415,7 +2303,6 @@ fn t137_test_combinator_unification_for_hacky_combinators() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/138_test_combinator_unification_for_hacky_combinators.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t138_test_combinator_unification_for_hacky_combinators() {
     assert_eq!(
         rsass(
@@ -2451,7 +2338,6 @@ fn t139_test_combinator_unification_for_hacky_combinators() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/140_test_combinator_unification_double_tilde.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t140_test_combinator_unification_double_tilde() {
     assert_eq!(
         rsass(
@@ -2469,7 +2355,6 @@ fn t140_test_combinator_unification_double_tilde() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/141_test_combinator_unification_double_tilde.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t141_test_combinator_unification_double_tilde() {
     assert_eq!(
         rsass(
@@ -2739,7 +2624,6 @@ fn t155_test_combinator_unification_angle_sibling() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/156_test_combinator_unification_double_angle.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t156_test_combinator_unification_double_angle() {
     assert_eq!(
         rsass(
@@ -2757,7 +2641,6 @@ fn t156_test_combinator_unification_double_angle() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/157_test_combinator_unification_double_angle.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t157_test_combinator_unification_double_angle() {
     assert_eq!(
         rsass(
@@ -2775,7 +2658,6 @@ fn t157_test_combinator_unification_double_angle() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/158_test_combinator_unification_double_angle.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t158_test_combinator_unification_double_angle() {
     assert_eq!(
         rsass(
@@ -2793,7 +2675,6 @@ fn t158_test_combinator_unification_double_angle() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/159_test_combinator_unification_double_angle.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t159_test_combinator_unification_double_angle() {
     assert_eq!(
         rsass(
@@ -2811,7 +2692,6 @@ fn t159_test_combinator_unification_double_angle() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/160_test_combinator_unification_double_plus.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t160_test_combinator_unification_double_plus() {
     assert_eq!(
         rsass(
@@ -2829,7 +2709,6 @@ fn t160_test_combinator_unification_double_plus() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/161_test_combinator_unification_double_plus.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t161_test_combinator_unification_double_plus() {
     assert_eq!(
         rsass(
@@ -2847,7 +2726,6 @@ fn t161_test_combinator_unification_double_plus() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/162_test_combinator_unification_double_plus.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t162_test_combinator_unification_double_plus() {
     assert_eq!(
         rsass(
@@ -2865,7 +2743,6 @@ fn t162_test_combinator_unification_double_plus() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/163_test_combinator_unification_double_plus.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t163_test_combinator_unification_double_plus() {
     assert_eq!(
         rsass(
@@ -2901,7 +2778,6 @@ fn t164_test_combinator_unification_angle_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/165_test_combinator_unification_angle_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t165_test_combinator_unification_angle_space() {
     assert_eq!(
         rsass(
@@ -2919,7 +2795,6 @@ fn t165_test_combinator_unification_angle_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/166_test_combinator_unification_angle_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t166_test_combinator_unification_angle_space() {
     assert_eq!(
         rsass(
@@ -2937,7 +2812,6 @@ fn t166_test_combinator_unification_angle_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/167_test_combinator_unification_angle_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t167_test_combinator_unification_angle_space() {
     assert_eq!(
         rsass(
@@ -2973,7 +2847,6 @@ fn t168_test_combinator_unification_angle_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/169_test_combinator_unification_angle_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t169_test_combinator_unification_angle_space() {
     assert_eq!(
         rsass(
@@ -2991,7 +2864,6 @@ fn t169_test_combinator_unification_angle_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/170_test_combinator_unification_plus_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t170_test_combinator_unification_plus_space() {
     assert_eq!(
         rsass(
@@ -3009,7 +2881,6 @@ fn t170_test_combinator_unification_plus_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/171_test_combinator_unification_plus_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t171_test_combinator_unification_plus_space() {
     assert_eq!(
         rsass(
@@ -3027,7 +2898,6 @@ fn t171_test_combinator_unification_plus_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/172_test_combinator_unification_plus_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t172_test_combinator_unification_plus_space() {
     assert_eq!(
         rsass(
@@ -3045,7 +2915,6 @@ fn t172_test_combinator_unification_plus_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/173_test_combinator_unification_plus_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t173_test_combinator_unification_plus_space() {
     assert_eq!(
         rsass(
@@ -3063,7 +2932,6 @@ fn t173_test_combinator_unification_plus_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/174_test_combinator_unification_plus_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t174_test_combinator_unification_plus_space() {
     assert_eq!(
         rsass(
@@ -3081,7 +2949,6 @@ fn t174_test_combinator_unification_plus_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/175_test_combinator_unification_plus_space.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t175_test_combinator_unification_plus_space() {
     assert_eq!(
         rsass(
@@ -3099,7 +2966,6 @@ fn t175_test_combinator_unification_plus_space() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/176_test_combinator_unification_nested.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t176_test_combinator_unification_nested() {
     assert_eq!(
         rsass(
@@ -3158,7 +3024,6 @@ fn t178_test_combinator_unification_with_newlines() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/179_test_extend_self_loop.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t179_test_extend_self_loop() {
     assert_eq!(
         rsass(
@@ -3175,7 +3040,6 @@ fn t179_test_extend_self_loop() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/180_test_basic_extend_loop.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t180_test_basic_extend_loop() {
     assert_eq!(
         rsass(
@@ -3196,7 +3060,6 @@ fn t180_test_basic_extend_loop() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/181_test_three_level_extend_loop.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t181_test_three_level_extend_loop() {
     assert_eq!(
         rsass(
@@ -3221,7 +3084,6 @@ fn t181_test_three_level_extend_loop() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/182_test_nested_extend_loop.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t182_test_nested_extend_loop() {
     assert_eq!(
         rsass(
@@ -3244,7 +3106,6 @@ fn t182_test_nested_extend_loop() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/183_test_multiple_extender_merges_with_superset_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t183_test_multiple_extender_merges_with_superset_selector() {
     assert_eq!(
         rsass(
@@ -3262,7 +3123,6 @@ fn t183_test_multiple_extender_merges_with_superset_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/184_test_control_flow_if.hrx"
 #[test]
-#[ignore] // wrong result
 fn t184_test_control_flow_if() {
     assert_eq!(
         rsass(
@@ -3291,7 +3151,6 @@ fn t184_test_control_flow_if() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/185_test_control_flow_for.hrx"
 #[test]
-#[ignore] // wrong result
 fn t185_test_control_flow_for() {
     assert_eq!(
         rsass(
@@ -3321,7 +3180,6 @@ fn t185_test_control_flow_for() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/186_test_control_flow_while.hrx"
 #[test]
-#[ignore] // wrong result
 fn t186_test_control_flow_while() {
     assert_eq!(
         rsass(
@@ -3353,7 +3211,6 @@ fn t186_test_control_flow_while() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/187_test_basic_placeholder_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t187_test_basic_placeholder_selector() {
     assert_eq!(
         rsass(
@@ -3371,7 +3228,6 @@ fn t187_test_basic_placeholder_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/188_test_unused_placeholder_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t188_test_unused_placeholder_selector() {
     assert_eq!(
         rsass(
@@ -3390,7 +3246,6 @@ fn t188_test_unused_placeholder_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/189_test_placeholder_descendant_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t189_test_placeholder_descendant_selector() {
     assert_eq!(
         rsass(
@@ -3408,7 +3263,6 @@ fn t189_test_placeholder_descendant_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/190_test_semi_placeholder_selector.hrx"
 #[test]
-#[ignore] // wrong result
 fn t190_test_semi_placeholder_selector() {
     assert_eq!(
         rsass(
@@ -3429,7 +3283,6 @@ fn t190_test_semi_placeholder_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/191_test_placeholder_selector_with_multiple_extenders.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t191_test_placeholder_selector_with_multiple_extenders() {
     assert_eq!(
         rsass(
@@ -3448,7 +3301,6 @@ fn t191_test_placeholder_selector_with_multiple_extenders() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/192_test_placeholder_interpolation.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t192_test_placeholder_interpolation() {
     assert_eq!(
         rsass(
@@ -3486,7 +3338,6 @@ fn t193_test_media_in_placeholder_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/194_test_extend_within_media.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t194_test_extend_within_media() {
     assert_eq!(
         rsass(
@@ -3508,7 +3359,6 @@ fn t194_test_extend_within_media() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/195_test_extend_within_unknown_directive.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t195_test_extend_within_unknown_directive() {
     assert_eq!(
         rsass(
@@ -3576,7 +3426,6 @@ fn t197_test_extend_within_disparate_media() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/198_test_extend_within_disparate_unknown_directive.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t198_test_extend_within_disparate_unknown_directive() {
     assert_eq!(
         rsass(
@@ -3622,7 +3471,6 @@ fn t199_test_extend_within_disparate_nested_directives() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/206_test_extend_succeeds_when_one_extension_fails_but_others_dont.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t206_test_extend_succeeds_when_one_extension_fails_but_others_dont() {
     assert_eq!(
         rsass(
@@ -3644,7 +3492,6 @@ fn t206_test_extend_succeeds_when_one_extension_fails_but_others_dont() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/207_test_optional_extend_succeeds_when_extendee_doesnt_exist.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t207_test_optional_extend_succeeds_when_extendee_doesnt_exist() {
     assert_eq!(
         rsass(
@@ -3658,7 +3505,6 @@ fn t207_test_optional_extend_succeeds_when_extendee_doesnt_exist() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/208_test_optional_extend_succeeds_when_extension_fails.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t208_test_optional_extend_succeeds_when_extension_fails() {
     assert_eq!(
         rsass(
@@ -3695,7 +3541,6 @@ fn t209_test_pseudo_element_superselector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/210_test_pseudo_element_superselector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t210_test_pseudo_element_superselector() {
     assert_eq!(
         rsass(
@@ -3790,7 +3635,6 @@ fn t214_test_pseudo_element_superselector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/215_test_multiple_source_redundancy_elimination.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t215_test_multiple_source_redundancy_elimination() {
     assert_eq!(
         rsass(
@@ -3819,7 +3663,6 @@ fn t215_test_multiple_source_redundancy_elimination() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/216_test_nested_sibling_extend.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t216_test_nested_sibling_extend() {
     assert_eq!(
         rsass(
@@ -3872,7 +3715,6 @@ fn t217_test_parent_and_sibling_extend() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/218_test_nested_extend_specificity.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t218_test_nested_extend_specificity() {
     assert_eq!(
         rsass(
@@ -3894,7 +3736,6 @@ fn t218_test_nested_extend_specificity() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/219_test_nested_double_extend_optimization.hrx"
 #[test]
-#[ignore] // wrong result
 fn t219_test_nested_double_extend_optimization() {
     assert_eq!(
         rsass(
@@ -3949,7 +3790,6 @@ fn t220_test_extend_in_double_nested_media_query() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/221_test_partially_failed_extend.hrx"
 #[test]
-#[ignore] // wrong result
 fn t221_test_partially_failed_extend() {
     assert_eq!(
         rsass(
@@ -3971,7 +3811,6 @@ fn t221_test_partially_failed_extend() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/222_test_newline_near_combinator.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t222_test_newline_near_combinator() {
     assert_eq!(
         rsass(
@@ -4016,7 +3855,6 @@ fn t223_test_duplicated_selector_with_newlines() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/224_test_nested_selector_with_child_selector_hack_extendee.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t224_test_nested_selector_with_child_selector_hack_extendee() {
     assert_eq!(
         rsass(
@@ -4034,7 +3872,6 @@ fn t224_test_nested_selector_with_child_selector_hack_extendee() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/225_test_nested_selector_with_child_selector_hack_extender.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t225_test_nested_selector_with_child_selector_hack_extender() {
     assert_eq!(
         rsass(
@@ -4052,7 +3889,6 @@ fn t225_test_nested_selector_with_child_selector_hack_extender() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/226_test_nested_selector_with_child_selector_hack_extender_and_extendee.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t226_test_nested_selector_with_child_selector_hack_extender_and_extendee()
 {
     assert_eq!(
@@ -4109,7 +3945,6 @@ fn t228_test_nested_with_child_selector_hack_extender_extendee_newline() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/229_test_extended_parent_and_child_redundancy_elimination.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t229_test_extended_parent_and_child_redundancy_elimination() {
     assert_eq!(
         rsass(
@@ -4130,7 +3965,6 @@ fn t229_test_extended_parent_and_child_redundancy_elimination() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/230_test_extend_redundancy_elimination_when_it_would_reduce_specificity.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t230_test_extend_redundancy_elimination_when_it_would_reduce_specificity()
 {
     assert_eq!(
@@ -4168,7 +4002,6 @@ fn t231_test_extend_redundancy_elimination_when_it_would_preserve_specificity(
 
 // From "sass-spec/spec/non_conformant/extend-tests/232_test_extend_redundancy_elimination_never_eliminates_base_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t232_test_extend_redundancy_elimination_never_eliminates_base_selector() {
     assert_eq!(
         rsass(
@@ -4186,7 +4019,6 @@ fn t232_test_extend_redundancy_elimination_never_eliminates_base_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/233_test_extend_cross_branch_redundancy_elimination.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t233_test_extend_cross_branch_redundancy_elimination() {
     assert_eq!(
         rsass(
@@ -4205,7 +4037,6 @@ fn t233_test_extend_cross_branch_redundancy_elimination() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/234_test_extend_cross_branch_redundancy_elimination.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t234_test_extend_cross_branch_redundancy_elimination() {
     assert_eq!(
         rsass(
@@ -4225,7 +4056,6 @@ fn t234_test_extend_cross_branch_redundancy_elimination() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/235_extend_with_universal_selector.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t235_extend_with_universal_selector() {
     assert_eq!(
         rsass(
@@ -4251,7 +4081,6 @@ fn t235_extend_with_universal_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/236_extend_with_universal_selector_empty_namespace.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t236_extend_with_universal_selector_empty_namespace() {
     assert_eq!(
         rsass(
@@ -4270,7 +4099,6 @@ fn t236_extend_with_universal_selector_empty_namespace() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/237_extend_with_universal_selector_different_namespace.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t237_extend_with_universal_selector_different_namespace() {
     assert_eq!(
         rsass(
@@ -4329,7 +4157,6 @@ fn t238_unify_root_pseudoelement() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/compound-unification-in-not.hrx"
 #[test]
-#[ignore] // unexepected error
 fn compound_unification_in_not() {
     assert_eq!(
         rsass(
@@ -4402,7 +4229,6 @@ fn escaped_selector() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/extend-extender.hrx"
 #[test]
-#[ignore] // unexepected error
 fn extend_extender() {
     assert_eq!(
         rsass(
@@ -4423,7 +4249,6 @@ fn extend_extender() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/extend-loop.hrx"
 #[test]
-#[ignore] // unexepected error
 fn extend_loop() {
     assert_eq!(
         rsass(
@@ -4541,7 +4366,6 @@ fn extend_loop() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/extend-result-of-extend.hrx"
 #[test]
-#[ignore] // unexepected error
 fn extend_result_of_extend() {
     assert_eq!(
         rsass(
@@ -4581,7 +4405,6 @@ mod fake_pseudo_element_order;
 
 // From "sass-spec/spec/non_conformant/extend-tests/issue_146.hrx"
 #[test]
-#[ignore] // wrong result
 fn issue_146() {
     assert_eq!(
         rsass(
@@ -4609,7 +4432,6 @@ fn issue_146() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/nested-compound-unification.hrx"
 #[test]
-#[ignore] // unexepected error
 fn nested_compound_unification() {
     assert_eq!(
         rsass(
@@ -4649,7 +4471,6 @@ fn not_into_not_not() {
 
 // From "sass-spec/spec/non_conformant/extend-tests/selector_list.hrx"
 #[test]
-#[ignore] // unexepected error
 fn selector_list() {
     assert_eq!(
         rsass(
@@ -6,7 +6,6 @@ mod error;
 
 // From "sass-spec/spec/non_conformant/mixin/content/arguments/none.hrx"
 #[test]
-#[ignore] // unexepected error
 fn none() {
     assert_eq!(
         rsass(
@@ -82,7 +81,6 @@ fn none() {
 
 // From "sass-spec/spec/non_conformant/mixin/content/arguments/passing.hrx"
 #[test]
-#[ignore] // unexepected error
 fn passing() {
     assert_eq!(
         rsass(
@@ -295,7 +293,6 @@ fn receiving() {
 
 // From "sass-spec/spec/non_conformant/mixin/content/arguments/scope.hrx"
 #[test]
-#[ignore] // unexepected error
 fn scope() {
     assert_eq!(
         rsass(
@@ -340,7 +337,6 @@ fn scope() {
 
 // From "sass-spec/spec/non_conformant/mixin/content/arguments/weird_syntax.hrx"
 #[test]
-#[ignore] // unexepected error
 fn weird_syntax() {
     assert_eq!(
         rsass(
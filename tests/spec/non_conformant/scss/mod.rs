@@ -437,7 +437,6 @@ fn comment_after_if_directive() {
 
 // From "sass-spec/spec/non_conformant/scss/composed-args.hrx"
 #[test]
-#[ignore] // wrong result
 fn composed_args() {
     assert_eq!(
         rsass(
@@ -1946,7 +1945,6 @@ mod media;
 
 // From "sass-spec/spec/non_conformant/scss/mixin-content-selectors.hrx"
 #[test]
-#[ignore] // wrong result
 fn mixin_content_selectors() {
     assert_eq!(
         rsass(
@@ -2191,7 +2189,6 @@ fn negation() {
 
 // From "sass-spec/spec/non_conformant/scss/nested-extend.hrx"
 #[test]
-#[ignore] // wrong result
 fn nested_extend() {
     assert_eq!(
         rsass(
@@ -2582,7 +2579,6 @@ fn percentages() {
 
 // From "sass-spec/spec/non_conformant/scss/placeholder-with-media.hrx"
 #[test]
-#[ignore] // wrong result
 fn placeholder_with_media() {
     assert_eq!(
         rsass(
@@ -2609,7 +2605,6 @@ fn placeholder_with_media() {
 
 // From "sass-spec/spec/non_conformant/scss/placeholder.hrx"
 #[test]
-#[ignore] // wrong result
 fn placeholder() {
     assert_eq!(
         rsass(
@@ -2924,7 +2919,6 @@ fn several_namespace_properties() {
 
 // From "sass-spec/spec/non_conformant/scss/simple-inheritance.hrx"
 #[test]
-#[ignore] // wrong result
 fn simple_inheritance() {
     assert_eq!(
         rsass(
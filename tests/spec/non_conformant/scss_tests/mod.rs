@@ -1251,7 +1251,6 @@ fn t066_test_mixin_var_args_act_like_list() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/067_test_mixin_splat_args.hrx"
 #[test]
-#[ignore] // wrong result
 fn t067_test_mixin_splat_args() {
     assert_eq!(
         rsass(
@@ -1279,7 +1278,6 @@ fn t067_test_mixin_splat_args() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/068_test_mixin_splat_expression.hrx"
 #[test]
-#[ignore] // wrong result
 fn t068_test_mixin_splat_expression() {
     assert_eq!(
         rsass(
@@ -1306,7 +1304,6 @@ fn t068_test_mixin_splat_expression() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/069_test_mixin_splat_args_with_var_args.hrx"
 #[test]
-#[ignore] // wrong result
 fn t069_test_mixin_splat_args_with_var_args() {
     assert_eq!(
         rsass(
@@ -1330,7 +1327,6 @@ fn t069_test_mixin_splat_args_with_var_args() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/070_test_mixin_splat_args_with_var_args_and_normal_args.hrx"
 #[test]
-#[ignore] // wrong result
 fn t070_test_mixin_splat_args_with_var_args_and_normal_args() {
     assert_eq!(
         rsass(
@@ -1380,7 +1376,6 @@ fn t071_test_mixin_splat_args_with_var_args_preserves_separator() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/072_test_mixin_var_and_splat_args_pass_through_keywords.hrx"
 #[test]
-#[ignore] // wrong result
 fn t072_test_mixin_var_and_splat_args_pass_through_keywords() {
     assert_eq!(
         rsass(
@@ -1409,7 +1404,6 @@ fn t072_test_mixin_var_and_splat_args_pass_through_keywords() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/078_test_mixin_list_of_pairs_splat_treated_as_list.hrx"
 #[test]
-#[ignore] // wrong result
 fn t078_test_mixin_list_of_pairs_splat_treated_as_list() {
     assert_eq!(
         rsass(
@@ -1496,7 +1490,6 @@ fn t085_test_function_var_args_act_like_list() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/086_test_function_splat_args.hrx"
 #[test]
-#[ignore] // wrong result
 fn t086_test_function_splat_args() {
     assert_eq!(
         rsass(
@@ -1518,7 +1511,6 @@ fn t086_test_function_splat_args() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/087_test_function_splat_expression.hrx"
 #[test]
-#[ignore] // wrong result
 fn t087_test_function_splat_expression() {
     assert_eq!(
         rsass(
@@ -1539,7 +1531,6 @@ fn t087_test_function_splat_expression() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/088_test_function_splat_args_with_var_args.hrx"
 #[test]
-#[ignore] // wrong result
 fn t088_test_function_splat_args_with_var_args() {
     assert_eq!(
         rsass(
@@ -1561,7 +1552,6 @@ fn t088_test_function_splat_args_with_var_args() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/089_test_function_splat_args_with_var_args_and_normal_args.hrx"
 #[test]
-#[ignore] // wrong result
 fn t089_test_function_splat_args_with_var_args_and_normal_args() {
     assert_eq!(
         rsass(
@@ -1605,7 +1595,6 @@ fn t090_test_function_splat_args_with_var_args_preserves_separator() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/091_test_function_var_and_splat_args_pass_through_keywords.hrx"
 #[test]
-#[ignore] // wrong result
 fn t091_test_function_var_and_splat_args_pass_through_keywords() {
     assert_eq!(
         rsass(
@@ -1630,7 +1619,6 @@ fn t091_test_function_var_and_splat_args_pass_through_keywords() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/098_test_function_list_of_pairs_splat_treated_as_list.hrx"
 #[test]
-#[ignore] // wrong result
 fn t098_test_function_list_of_pairs_splat_treated_as_list() {
     assert_eq!(
         rsass(
@@ -1653,7 +1641,6 @@ fn t098_test_function_list_of_pairs_splat_treated_as_list() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/103_test_function_var_args_passed_to_native.hrx"
 #[test]
-#[ignore] // unexepected error
 fn t103_test_function_var_args_passed_to_native() {
     assert_eq!(
         rsass(
@@ -2465,7 +2452,6 @@ fn t187_test_multiline_var() {
 
 // From "sass-spec/spec/non_conformant/scss-tests/188_test_mixin_content.hrx"
 #[test]
-#[ignore] // wrong result
 fn t188_test_mixin_content() {
     assert_eq!(
         rsass(
@@ -5,10 +5,9 @@ use super::rsass;
 // From "sass-spec/spec/values/colors/alpha_hex/initial_digit.hrx"
 #[test]
 fn initial_digit() {
-    let format = rsass::output::Format {
-        style: rsass::output::Style::Expanded,
-        precision: 10,
-    };
+    let format = rsass::output::Format::default()
+        .with_style(rsass::output::Style::Expanded)
+        .with_precision(10);
     assert_eq!(
         crate::rsass_fmt(
             format,
@@ -49,10 +48,9 @@ fn initial_digit() {
 // From "sass-spec/spec/values/colors/alpha_hex/initial_letter.hrx"
 #[test]
 fn initial_letter() {
-    let format = rsass::output::Format {
-        style: rsass::output::Style::Expanded,
-        precision: 10,
-    };
+    let format = rsass::output::Format::default()
+        .with_style(rsass::output::Style::Expanded)
+        .with_precision(10);
     assert_eq!(
         crate::rsass_fmt(
             format,
@@ -0,0 +1,142 @@
+//! Tests for the scoping of variable declarations in flow control
+//! blocks, rules, mixins and functions.
+mod common;
+use common::check;
+
+#[test]
+fn flow_at_root_assigns_global() {
+    check(
+        "$x: 1;\
+         \n@if true {$x: 2;}\
+         \n@each $i in 1 2 {$x: $x + $i;}\
+         \n@for $i from 1 through 2 {$x: $x * $i;}\
+         \n$n: 0;\
+         \n@while $n < 3 {$n: $n + 1;}\
+         \na {x: $x; n: $n}\n",
+        "a {\n  x: 10;\n  n: 3;\n}\n",
+    )
+}
+
+#[test]
+fn new_variable_in_flow_is_local() {
+    check(
+        "@if true {$y: 1;}\
+         \n@each $i in 1 2 {$z: $i;}\
+         \na {y: variable-exists(y); z: variable-exists(z); \
+         i: variable-exists(i)}\n",
+        "a {\n  y: false;\n  z: false;\n  i: false;\n}\n",
+    )
+}
+
+#[test]
+fn mixin_in_flow_is_local() {
+    check(
+        "@if true {@mixin m {x: y}}\
+         \n@if true {@function f() {@return 1}}\
+         \na {m: mixin-exists(m); f: function-exists(f)}\n",
+        "a {\n  m: false;\n  f: false;\n}\n",
+    )
+}
+
+#[test]
+fn flow_without_declarations() {
+    check(
+        "$x: 1;\
+         \n@mixin inc {$x: $x + 1 !global;}\
+         \n@while $x < 3 {@include inc;}\
+         \na {\
+         \n  $y: 1;\
+         \n  @if true {b {y: $y}}\
+         \n  x: $x;\
+         \n}\n",
+        "a {\n  x: 3;\n}\na b {\n  y: 1;\n}\n",
+    )
+}
+
+#[test]
+fn flow_in_rule_assigns_local() {
+    check(
+        "$x: 1;\
+         \na {\
+         \n  $y: 1;\
+         \n  @each $i in 1 2 3 {$y: $y + $i; $x: $i;}\
+         \n  y: $y;\
+         \n  x: $x;\
+         \n}\
+         \nb {x: $x}\n",
+        "a {\n  y: 7;\n  x: 1;\n}\n\nb {\n  x: 1;\n}\n",
+    )
+}
+
+#[test]
+fn rule_shadows_global() {
+    check(
+        "$x: 1;\na {$x: 2; x: $x}\nb {x: $x}\n",
+        "a {\n  x: 2;\n}\n\nb {\n  x: 1;\n}\n",
+    )
+}
+
+#[test]
+fn global_flag() {
+    check(
+        "$x: 1;\
+         \na {\
+         \n  $x: 2 !global;\
+         \n  @if true {$y: 3 !global;}\
+         \n}\
+         \nb {x: $x; y: $y}\n",
+        "b {\n  x: 2;\n  y: 3;\n}\n",
+    )
+}
+
+#[test]
+fn mixin_does_not_assign_caller_variable() {
+    check(
+        "@mixin m {$x: mixin; @if true {$x: flow;}}\
+         \na {\
+         \n  $x: caller;\
+         \n  @include m;\
+         \n  x: $x;\
+         \n}\n",
+        "a {\n  x: caller;\n}\n",
+    )
+}
+
+#[test]
+fn content_assigns_caller_variable() {
+    check(
+        "@mixin m {@content}\
+         \na {\
+         \n  $x: caller;\
+         \n  @include m {$x: content;}\
+         \n  x: $x;\
+         \n}\n",
+        "a {\n  x: content;\n}\n",
+    )
+}
+
+#[test]
+fn flow_in_function() {
+    check(
+        "$r: global;\
+         \n@function f() {\
+         \n  $r: 0;\
+         \n  @for $i from 1 through 3 {$r: $r + $i;}\
+         \n  @if true {$r: $r * 2;}\
+         \n  @return $r;\
+         \n}\
+         \na {x: f(); r: $r}\n",
+        "a {\n  x: 12;\n  r: global;\n}\n",
+    )
+}
+
+#[test]
+fn flags_in_any_order() {
+    check(
+        "$a: 1 !global !default;\
+         \n$a: 2 !default !global;\
+         \n$b: 3 !default !default;\
+         \nc {a: $a; b: $b}\n",
+        "c {\n  a: 1;\n  b: 3;\n}\n",
+    )
+}